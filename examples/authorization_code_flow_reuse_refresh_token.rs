@@ -19,7 +19,9 @@ async fn main() {
             .expect("failed to build Spotify client");
 
     let user_client = spotify_client
-        .authorization_code_client_with_refresh_token("a refresh token from a previous session")
+        .authorization_code_client_with_refresh_token("a refresh token from a previous session", |_new_refresh_token| {
+            // save the new refresh token in place of the previous one
+        })
         .await
         .expect("failed to build authorization code client");
 
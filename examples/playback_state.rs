@@ -39,7 +39,7 @@ async fn main() {
 
     // optionally, if you have a valid refresh token (with the correct scope), you may use it as such:
     // let user_client = spotify_client
-    //     .authorization_code_client_with_refresh_token("refresh token")
+    //     .authorization_code_client_with_refresh_token("refresh token", |_new_refresh_token| {})
     //     .await
     //     .unwrap();
 
@@ -0,0 +1,42 @@
+use dotenvy::dotenv;
+use ferrispot::{
+    client::SpotifyClientBuilder,
+    model::{id::Id, track::Track},
+    prelude::*,
+};
+
+#[tokio::main]
+async fn main() {
+    dotenv().ok();
+    env_logger::init();
+
+    let spotify_client =
+        SpotifyClientBuilder::new(std::env::var("CLIENT_ID").expect("Spotify client ID not in environment"))
+            .client_secret(std::env::var("CLIENT_SECRET").expect("Spotify client secret not in environment"))
+            .build_async()
+            .await
+            .expect("failed to build Spotify client");
+
+    let playlist = spotify_client
+        .playlist(Id::from_bare("37i9dQZF1DXcBWIGoYBM5M").unwrap())
+        .send_async()
+        .await
+        .unwrap();
+
+    println!(
+        "{} by {} ({} followers)",
+        playlist.name(),
+        playlist.owner().display_name().unwrap_or("unknown"),
+        playlist.followers().total
+    );
+
+    // the playlist contains only the first page of its tracks. the rest of the pages can be retrieved with
+    // .next_page_async()
+    for item in playlist.tracks().take_items() {
+        match item.take_track() {
+            Some(Track::Full(track)) => println!("{} - {}", track.name(), track.artists().first().unwrap().name()),
+            Some(Track::Local(track)) => println!("{} (local)", track.name()),
+            _ => println!("unavailable track"),
+        }
+    }
+}
@@ -43,9 +43,11 @@ async fn main() {
     // at this point you may save the token in some manner
     // imagine at this point some time passes and you're in an entirely new process
 
-    // you can get an AuthorizationCodeClient from the Spotify client directly by reusing the previous refresh token
+    // you can get an AuthorizationCodeClient from the Spotify client directly by reusing the previous refresh token.
+    // Spotify may rotate the refresh token whenever the access token is refreshed, including right away when the client
+    // is created, so the callback should save the new refresh token in place of the previous one
     let user_client = spotify_client
-        .authorization_code_client_with_refresh_token(&refresh_token)
+        .authorization_code_client_with_refresh_token(&refresh_token, |_new_refresh_token| {})
         .await
         .expect("failed to create authorization code client");
 
@@ -73,6 +73,19 @@
 //! # }
 //! ```
 //!
+//! If the application client ID and secret are in the `SPOTIFY_CLIENT_ID` and `SPOTIFY_CLIENT_SECRET` environment
+//! variables, the client may be built directly with [from_env_async](SpotifyClient::from_env_async) or
+//! [from_env_sync](SpotifyClient::from_env_sync).
+//!
+//! ```no_run
+//! # use ferrispot::client::SpotifyClient;
+//! # async fn foo() {
+//! let spotify_client = SpotifyClient::from_env_async()
+//!     .await
+//!     .expect("failed to build Spotify client");
+//! # }
+//! ```
+//!
 //! ## Authorization code flow with optional PKCE
 //!
 //! See the module-level documentation for the [authorization code module](authorization_code).
@@ -84,37 +97,62 @@
 //! endpoints.
 
 pub mod authorization_code;
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod deserialization;
 pub mod implicit_grant;
+pub mod rate_limit;
 pub mod request_builder;
 
 pub(crate) mod object;
+#[cfg(any(
+    feature = "sync",
+    all(
+        feature = "async",
+        any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+    )
+))]
+mod playback_poller;
 pub(crate) mod private;
+#[cfg(feature = "redirect-server")]
+pub(crate) mod redirect_server;
 pub(crate) mod scoped;
 pub(crate) mod unscoped;
 
-use std::sync::{Arc, RwLock};
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
 
 use base64::Engine;
-use const_format::concatcp;
-use log::debug;
+use log::{debug, warn};
 use reqwest::{
-    header::{self, HeaderMap},
-    IntoUrl, Method, StatusCode,
+    header::{self, HeaderMap, HeaderValue},
+    IntoUrl, Method, StatusCode, Url,
 };
 use serde::Deserialize;
 
-use self::implicit_grant::ImplicitGrantUserClientBuilder;
+#[cfg(feature = "cache")]
+use self::cache::TrackCache;
+use self::{
+    deserialization::DeserializationMode,
+    implicit_grant::ImplicitGrantUserClientBuilder,
+    private::{BaseUrls, RateLimitPolicy},
+    rate_limit::{DefaultRateLimitSleeper, RateLimitBackoff, RateLimiter},
+};
 #[cfg(feature = "async")]
 use self::{
     authorization_code::{AsyncAuthorizationCodeUserClient, AsyncAuthorizationCodeUserClientBuilder},
     implicit_grant::AsyncImplicitGrantUserClientBuilder,
     private::AsyncClient,
+    rate_limit::AsyncRateLimitSleeper,
 };
 #[cfg(feature = "sync")]
 use self::{
     authorization_code::{SyncAuthorizationCodeUserClient, SyncAuthorizationCodeUserClientBuilder},
     implicit_grant::SyncImplicitGrantUserClientBuilder,
     private::SyncClient,
+    rate_limit::SyncRateLimitSleeper,
 };
 pub use self::{scoped::ScopedClient, unscoped::UnscopedClient};
 use crate::{
@@ -142,34 +180,71 @@ pub type SyncSpotifyClientWithSecret = SpotifyClientWithSecret<SyncClient>;
 
 const RANDOM_STATE_LENGTH: usize = 16;
 const PKCE_VERIFIER_LENGTH: usize = 128; // maximum Spotify allows
+const ACCESS_TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+// some user agents and proxies refuse URLs longer than this
+const AUTHORIZE_URL_WARNING_LENGTH: usize = 2000;
 const CLIENT_CREDENTIALS_TOKEN_REQUEST_FORM: &[(&str, &str)] = &[("grant_type", "client_credentials")];
+const CLIENT_ID_ENV_VAR: &str = "SPOTIFY_CLIENT_ID";
+const CLIENT_SECRET_ENV_VAR: &str = "SPOTIFY_CLIENT_SECRET";
+// printed in place of secrets, such as access tokens, in debug output and logs
+const REDACTED: &str = "***";
 
 const API_BASE_URL: &str = "https://api.spotify.com/v1/";
 
-// unscoped endpoints
-const API_TRACKS_ENDPOINT: &str = concatcp!(API_BASE_URL, "tracks");
-const API_SEARCH_ENDPOINT: &str = concatcp!(API_BASE_URL, "search");
-
-// scoped endpoints
-const API_USER_PROFILE_ENDPOINT: &str = concatcp!(API_BASE_URL, "users");
-const API_CURRENT_USER_PROFILE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me");
-const API_PLAYBACK_STATE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player");
-const API_CURRENTLY_PLAYING_ITEM_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/currently-playing");
-const API_PLAYER_PLAY_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/play");
-const API_PLAYER_PAUSE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/pause");
-const API_PLAYER_REPEAT_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/repeat");
-const API_PLAYER_SHUFFLE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/shuffle");
-const API_PLAYER_VOLUME_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/volume");
-const API_PLAYER_NEXT_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/next");
-const API_PLAYER_PREVIOUS_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/previous");
-const API_PLAYER_SEEK_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/seek");
-const API_PLAYER_QUEUE_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/queue");
-const API_PLAYER_DEVICES_ENDPOINT: &str = concatcp!(API_BASE_URL, "me/player/devices");
-
-// accounts
+// unscoped endpoints, relative to the API base URL
+const API_TRACKS_ENDPOINT: &str = "tracks";
+const API_SEARCH_ENDPOINT: &str = "search";
+const API_ALBUMS_ENDPOINT: &str = "albums";
+const API_ARTISTS_ENDPOINT: &str = "artists";
+const API_EPISODES_ENDPOINT: &str = "episodes";
+const API_PLAYLISTS_ENDPOINT: &str = "playlists";
+const API_SHOWS_ENDPOINT: &str = "shows";
+const API_AUDIO_FEATURES_ENDPOINT: &str = "audio-features";
+const API_AUDIO_ANALYSIS_ENDPOINT: &str = "audio-analysis";
+const API_RECOMMENDATIONS_ENDPOINT: &str = "recommendations";
+const API_AVAILABLE_GENRE_SEEDS_ENDPOINT: &str = "recommendations/available-genre-seeds";
+const API_CATEGORIES_ENDPOINT: &str = "browse/categories";
+const API_NEW_RELEASES_ENDPOINT: &str = "browse/new-releases";
+const API_MARKETS_ENDPOINT: &str = "markets";
+
+// scoped endpoints, relative to the API base URL
+const API_USER_PROFILE_ENDPOINT: &str = "users";
+const API_CURRENT_USER_PROFILE_ENDPOINT: &str = "me";
+const API_FOLLOWING_ENDPOINT: &str = "me/following";
+const API_FOLLOWING_CONTAINS_ENDPOINT: &str = "me/following/contains";
+const API_SAVED_ALBUMS_ENDPOINT: &str = "me/albums";
+const API_SAVED_ALBUMS_CONTAINS_ENDPOINT: &str = "me/albums/contains";
+const API_SAVED_TRACKS_ENDPOINT: &str = "me/tracks";
+const API_SAVED_TRACKS_CONTAINS_ENDPOINT: &str = "me/tracks/contains";
+const API_SAVED_EPISODES_ENDPOINT: &str = "me/episodes";
+const API_SAVED_EPISODES_CONTAINS_ENDPOINT: &str = "me/episodes/contains";
+const API_SAVED_SHOWS_ENDPOINT: &str = "me/shows";
+const API_SAVED_SHOWS_CONTAINS_ENDPOINT: &str = "me/shows/contains";
+const API_CURRENT_USER_PLAYLISTS_ENDPOINT: &str = "me/playlists";
+const API_PLAYBACK_STATE_ENDPOINT: &str = "me/player";
+const API_CURRENTLY_PLAYING_ITEM_ENDPOINT: &str = "me/player/currently-playing";
+const API_PLAYER_PLAY_ENDPOINT: &str = "me/player/play";
+const API_PLAYER_PAUSE_ENDPOINT: &str = "me/player/pause";
+const API_PLAYER_REPEAT_ENDPOINT: &str = "me/player/repeat";
+const API_PLAYER_SHUFFLE_ENDPOINT: &str = "me/player/shuffle";
+const API_PLAYER_VOLUME_ENDPOINT: &str = "me/player/volume";
+const API_PLAYER_NEXT_ENDPOINT: &str = "me/player/next";
+const API_PLAYER_PREVIOUS_ENDPOINT: &str = "me/player/previous";
+const API_PLAYER_SEEK_ENDPOINT: &str = "me/player/seek";
+const API_PLAYER_QUEUE_ENDPOINT: &str = "me/player/queue";
+const API_PLAYER_DEVICES_ENDPOINT: &str = "me/player/devices";
+const API_RECENTLY_PLAYED_ENDPOINT: &str = "me/player/recently-played";
+const API_TOP_ARTISTS_ENDPOINT: &str = "me/top/artists";
+const API_TOP_TRACKS_ENDPOINT: &str = "me/top/tracks";
+
+// unofficial lyrics endpoint
+#[cfg(feature = "lyrics")]
+const SPCLIENT_LYRICS_ENDPOINT: &str = "https://spclient.wg.spotify.com/color-lyrics/v2/track";
+
+// accounts, relative to the accounts base URL
 const ACCOUNTS_BASE_URL: &str = "https://accounts.spotify.com/";
-const ACCOUNTS_AUTHORIZE_ENDPOINT: &str = concatcp!(ACCOUNTS_BASE_URL, "authorize");
-const ACCOUNTS_API_TOKEN_ENDPOINT: &str = concatcp!(ACCOUNTS_BASE_URL, "api/token");
+const ACCOUNTS_AUTHORIZE_ENDPOINT: &str = "authorize";
+const ACCOUNTS_API_TOKEN_ENDPOINT: &str = "api/token";
 
 /// Clients that have automatically refreshable access tokens implement this trait. The asynchronous
 /// [SpotifyClientWithSecret](SpotifyClientWithSecret) and
@@ -244,40 +319,129 @@ where
     http_client: C,
 }
 
-#[derive(Debug)]
 struct SpotifyClientWithSecretRef {
     client_id: String,
     // client_secret: String,
     access_token: RwLock<String>,
+    access_token_expires_at: RwLock<Instant>,
 }
 
 /// Builder for [SpotifyClient](SpotifyClient).
 #[derive(Debug, Clone)]
 pub struct SpotifyClientBuilder {
     client_id: String,
+    locale: Option<HeaderValue>,
+    deserialization_mode: DeserializationMode,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    rate_limit_policy: RateLimitPolicy,
+    timeout: Option<Duration>,
+    base_urls: BaseUrls,
+    #[cfg(feature = "async")]
+    async_rate_limit_sleeper: Arc<dyn AsyncRateLimitSleeper>,
+    #[cfg(feature = "sync")]
+    sync_rate_limit_sleeper: Arc<dyn SyncRateLimitSleeper>,
+    #[cfg(feature = "async")]
+    async_http_client: Option<reqwest::Client>,
+    #[cfg(feature = "sync")]
+    sync_http_client: Option<reqwest::blocking::Client>,
+    #[cfg(feature = "cache")]
+    track_cache: Option<Arc<TrackCache>>,
 }
 
 /// Builder for [SpotifyClientWithSecret](SpotifyClientWithSecret). New instances are acquired through the
 /// [`client_secret`-function](SpotifyClientBuilder::client_secret) in [SpotifyClientBuilder].
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct SpotifyClientWithSecretBuilder {
     client_id: String,
     client_secret: String,
+    locale: Option<HeaderValue>,
+    deserialization_mode: DeserializationMode,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    rate_limit_policy: RateLimitPolicy,
+    timeout: Option<Duration>,
+    base_urls: BaseUrls,
+    #[cfg(feature = "async")]
+    async_rate_limit_sleeper: Arc<dyn AsyncRateLimitSleeper>,
+    #[cfg(feature = "sync")]
+    sync_rate_limit_sleeper: Arc<dyn SyncRateLimitSleeper>,
+    #[cfg(feature = "async")]
+    async_http_client: Option<reqwest::Client>,
+    #[cfg(feature = "sync")]
+    sync_http_client: Option<reqwest::blocking::Client>,
+    #[cfg(feature = "cache")]
+    track_cache: Option<Arc<TrackCache>>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 struct ClientTokenResponse {
     access_token: String,
 
-    // these fields are in the response but the library doesn't need them. keep them here for logging purposes
-    #[allow(dead_code)]
-    token_type: String,
-    #[allow(dead_code)]
     expires_in: u32,
+
+    // this field is in the response but the library doesn't need it. keep it here for logging purposes
+    token_type: String,
+}
+
+impl std::fmt::Debug for SpotifyClientWithSecretRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpotifyClientWithSecretRef")
+            .field("client_id", &self.client_id)
+            .field("access_token", &REDACTED)
+            .field("access_token_expires_at", &self.access_token_expires_at)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for SpotifyClientWithSecretBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpotifyClientWithSecretBuilder")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &REDACTED)
+            .field("locale", &self.locale)
+            .field("deserialization_mode", &self.deserialization_mode)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("rate_limit_policy", &self.rate_limit_policy)
+            .field("timeout", &self.timeout)
+            .field("base_urls", &self.base_urls)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Debug for ClientTokenResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientTokenResponse")
+            .field("access_token", &REDACTED)
+            .field("expires_in", &self.expires_in)
+            .field("token_type", &self.token_type)
+            .finish()
+    }
 }
 
 #[cfg(feature = "async")]
 impl AsyncSpotifyClient {
+    /// Read the application client ID and secret from the `SPOTIFY_CLIENT_ID` and `SPOTIFY_CLIENT_SECRET` environment
+    /// variables, and return an asynchronous [client credentials flow client](SpotifyClientWithSecret) built with them.
+    ///
+    /// Returns a [MissingEnvironmentVariable](Error::MissingEnvironmentVariable)-error naming the variable if either
+    /// variable is not set. To read the credentials from other variables, use
+    /// [from_env_vars_async](Self::from_env_vars_async). To configure the client further, use a
+    /// [SpotifyClientBuilder] instead.
+    pub async fn from_env_async() -> Result<AsyncSpotifyClientWithSecret> {
+        Self::from_env_vars_async(CLIENT_ID_ENV_VAR, CLIENT_SECRET_ENV_VAR).await
+    }
+
+    /// Read the application client ID and secret from the given environment variables, and return an asynchronous
+    /// [client credentials flow client](SpotifyClientWithSecret) built with them. See
+    /// [from_env_async](Self::from_env_async).
+    pub async fn from_env_vars_async(
+        client_id_var: &str,
+        client_secret_var: &str,
+    ) -> Result<AsyncSpotifyClientWithSecret> {
+        builder_from_env_vars(client_id_var, client_secret_var)?
+            .build_async()
+            .await
+    }
+
     /// Returns a new builder for an [ImplicitGrantUserClient](implicit_grant::ImplicitGrantUserClient).
     ///
     /// # Note
@@ -313,17 +477,24 @@ impl AsyncSpotifyClient {
     ///
     /// The refresh token will be used to retrieve a new access token before the client is returned. PKCE is required
     /// for strong authentication when the client secret cannot be securely stored in the environment.
-    pub async fn authorization_code_client_with_refresh_token_and_pkce<S>(
+    ///
+    /// The given callback is invoked with the new refresh token whenever Spotify issues one, including when the refresh
+    /// token is rotated already when the client is created. See the [`on_token_refresh`-function in the
+    /// builder](authorization_code::AuthorizationCodeUserClientBuilder::on_token_refresh).
+    pub async fn authorization_code_client_with_refresh_token_and_pkce<S, F>(
         &self,
         refresh_token: S,
+        on_token_refresh: F,
     ) -> Result<AsyncAuthorizationCodeUserClient>
     where
         S: Into<String>,
+        F: Fn(&str) + Send + Sync + 'static,
     {
         AsyncAuthorizationCodeUserClient::new_with_refresh_token(
             self.http_client.clone(),
             refresh_token.into(),
             Some(self.inner.client_id.clone()),
+            on_token_refresh,
         )
         .await
     }
@@ -331,6 +502,24 @@ impl AsyncSpotifyClient {
 
 #[cfg(feature = "sync")]
 impl SyncSpotifyClient {
+    /// Read the application client ID and secret from the `SPOTIFY_CLIENT_ID` and `SPOTIFY_CLIENT_SECRET` environment
+    /// variables, and return a synchronous [client credentials flow client](SpotifyClientWithSecret) built with them.
+    ///
+    /// Returns a [MissingEnvironmentVariable](Error::MissingEnvironmentVariable)-error naming the variable if either
+    /// variable is not set. To read the credentials from other variables, use
+    /// [from_env_vars_sync](Self::from_env_vars_sync). To configure the client further, use a [SpotifyClientBuilder]
+    /// instead.
+    pub fn from_env_sync() -> Result<SyncSpotifyClientWithSecret> {
+        Self::from_env_vars_sync(CLIENT_ID_ENV_VAR, CLIENT_SECRET_ENV_VAR)
+    }
+
+    /// Read the application client ID and secret from the given environment variables, and return a synchronous
+    /// [client credentials flow client](SpotifyClientWithSecret) built with them. See
+    /// [from_env_sync](Self::from_env_sync).
+    pub fn from_env_vars_sync(client_id_var: &str, client_secret_var: &str) -> Result<SyncSpotifyClientWithSecret> {
+        builder_from_env_vars(client_id_var, client_secret_var)?.build_sync()
+    }
+
     /// Returns a new builder for an [ImplicitGrantUserClient](implicit_grant::ImplicitGrantUserClient).
     ///
     /// # Note
@@ -366,17 +555,24 @@ impl SyncSpotifyClient {
     ///
     /// The refresh token will be used to retrieve a new access token before the client is returned. PKCE is required
     /// for strong authentication when the client secret cannot be securely stored in the environment.
-    pub fn authorization_code_client_with_refresh_token_and_pkce<S>(
+    ///
+    /// The given callback is invoked with the new refresh token whenever Spotify issues one, including when the refresh
+    /// token is rotated already when the client is created. See the [`on_token_refresh`-function in the
+    /// builder](authorization_code::AuthorizationCodeUserClientBuilder::on_token_refresh).
+    pub fn authorization_code_client_with_refresh_token_and_pkce<S, F>(
         &self,
         refresh_token: S,
+        on_token_refresh: F,
     ) -> Result<SyncAuthorizationCodeUserClient>
     where
         S: Into<String>,
+        F: Fn(&str) + Send + Sync + 'static,
     {
         SyncAuthorizationCodeUserClient::new_with_refresh_token(
             self.http_client.clone(),
             refresh_token.into(),
             Some(self.inner.client_id.clone()),
+            on_token_refresh,
         )
     }
 }
@@ -400,15 +596,26 @@ impl AsyncSpotifyClientWithSecret {
     /// uses an existing refresh token.
     ///
     /// The refresh token will be used to retrieve a new access token before the client is returned.
-    pub async fn authorization_code_client_with_refresh_token<S>(
+    ///
+    /// The given callback is invoked with the new refresh token whenever Spotify issues one, including when the refresh
+    /// token is rotated already when the client is created. See the [`on_token_refresh`-function in the
+    /// builder](authorization_code::AuthorizationCodeUserClientBuilder::on_token_refresh).
+    pub async fn authorization_code_client_with_refresh_token<S, F>(
         &self,
         refresh_token: S,
+        on_token_refresh: F,
     ) -> Result<AsyncAuthorizationCodeUserClient>
     where
         S: Into<String>,
+        F: Fn(&str) + Send + Sync + 'static,
     {
-        AsyncAuthorizationCodeUserClient::new_with_refresh_token(self.http_client.clone(), refresh_token.into(), None)
-            .await
+        AsyncAuthorizationCodeUserClient::new_with_refresh_token(
+            self.http_client.clone(),
+            refresh_token.into(),
+            None,
+            on_token_refresh,
+        )
+        .await
     }
 }
 
@@ -431,14 +638,25 @@ impl SyncSpotifyClientWithSecret {
     /// uses an existing refresh token.
     ///
     /// The refresh token will be used to retrieve a new access token before the client is returned.
-    pub fn authorization_code_client_with_refresh_token<S>(
+    ///
+    /// The given callback is invoked with the new refresh token whenever Spotify issues one, including when the refresh
+    /// token is rotated already when the client is created. See the [`on_token_refresh`-function in the
+    /// builder](authorization_code::AuthorizationCodeUserClientBuilder::on_token_refresh).
+    pub fn authorization_code_client_with_refresh_token<S, F>(
         &self,
         refresh_token: S,
+        on_token_refresh: F,
     ) -> Result<SyncAuthorizationCodeUserClient>
     where
         S: Into<String>,
+        F: Fn(&str) + Send + Sync + 'static,
     {
-        SyncAuthorizationCodeUserClient::new_with_refresh_token(self.http_client.clone(), refresh_token.into(), None)
+        SyncAuthorizationCodeUserClient::new_with_refresh_token(
+            self.http_client.clone(),
+            refresh_token.into(),
+            None,
+            on_token_refresh,
+        )
     }
 }
 
@@ -450,6 +668,200 @@ impl SpotifyClientBuilder {
     {
         Self {
             client_id: client_id.into(),
+            locale: None,
+            deserialization_mode: DeserializationMode::default(),
+            rate_limiter: None,
+            rate_limit_policy: RateLimitPolicy::default(),
+            timeout: None,
+            base_urls: BaseUrls::default(),
+            #[cfg(feature = "async")]
+            async_rate_limit_sleeper: Arc::new(DefaultRateLimitSleeper),
+            #[cfg(feature = "sync")]
+            sync_rate_limit_sleeper: Arc::new(DefaultRateLimitSleeper),
+            #[cfg(feature = "async")]
+            async_http_client: None,
+            #[cfg(feature = "sync")]
+            sync_http_client: None,
+            #[cfg(feature = "cache")]
+            track_cache: None,
+        }
+    }
+
+    /// Set the default locale for all requests sent by the client, for example `HeaderValue::from_static("es-ES")`.
+    /// The locale is sent as the `Accept-Language` header, and Spotify uses it to localize the names and descriptions
+    /// of certain items, such as categories and playlists. The header may be overridden in individual requests with
+    /// the [`header`-function in the request builders](crate::client::request_builder::BaseRequestBuilder::header).
+    pub fn locale(self, locale: HeaderValue) -> Self {
+        Self {
+            locale: Some(locale),
+            ..self
+        }
+    }
+
+    /// Set how strictly the client deserializes Spotify's responses. Defaults to
+    /// [Lenient](DeserializationMode::Lenient). See the [deserialization module](deserialization) for more information.
+    pub fn deserialization_mode(self, deserialization_mode: DeserializationMode) -> Self {
+        Self {
+            deserialization_mode,
+            ..self
+        }
+    }
+
+    /// Set a rate limiter shared with other clients. When any client sharing the rate limiter gets rate limited, every
+    /// client sharing it waits until the rate limit has passed. By default, clients only react to their own rate limits.
+    /// See the [rate_limit module](rate_limit#sharing-rate-limits-between-clients) for more information.
+    pub fn rate_limiter(self, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            rate_limiter: Some(rate_limiter),
+            ..self
+        }
+    }
+
+    /// Set how many times a rate limited request is retried before giving up. When a request gets rate limited once
+    /// more after having been retried this many times, it fails with [RateLimit](Error::RateLimit). By default,
+    /// requests are retried until they succeed.
+    pub fn max_rate_limit_retries(self, max_retries: u32) -> Self {
+        Self {
+            rate_limit_policy: RateLimitPolicy {
+                max_retries: Some(max_retries),
+                ..self.rate_limit_policy
+            },
+            ..self
+        }
+    }
+
+    /// Set the longest rate limit the client waits out. When Spotify asks the client to wait longer than this before
+    /// retrying a request, the request fails immediately with [RateLimit](Error::RateLimit). By default, every rate
+    /// limit is waited out.
+    pub fn max_rate_limit_wait(self, max_wait: Duration) -> Self {
+        Self {
+            rate_limit_policy: RateLimitPolicy {
+                max_wait: Some(max_wait),
+                ..self.rate_limit_policy
+            },
+            ..self
+        }
+    }
+
+    /// Set how long a request waits when it gets rate limited multiple times in a row. By default, a request waits
+    /// exactly the time Spotify asks for every time. See [RateLimitBackoff] for the alternatives.
+    pub fn rate_limit_backoff(self, backoff: RateLimitBackoff) -> Self {
+        Self {
+            rate_limit_policy: RateLimitPolicy {
+                backoff,
+                ..self.rate_limit_policy
+            },
+            ..self
+        }
+    }
+
+    /// Set a timeout for every request the client sends. The timeout covers the entire request, from connecting until
+    /// the response body has been read. A request that times out fails with [Error::Timeout]. By default, asynchronous
+    /// clients never time out and synchronous clients time out after 30 seconds.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Set the base URL the client sends API requests to, for example `http://localhost:8080/` to test against a mock
+    /// server. Every endpoint's path is resolved relative to it. Defaults to `https://api.spotify.com/v1/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given URL is not a valid absolute URL.
+    pub fn api_base_url<S>(self, api_base_url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            base_urls: BaseUrls {
+                api: parse_base_url(api_base_url.into()),
+                ..self.base_urls
+            },
+            ..self
+        }
+    }
+
+    /// Set the base URL of the accounts service the client requests access tokens from and directs users to authorize
+    /// the application in. Defaults to `https://accounts.spotify.com/`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given URL is not a valid absolute URL.
+    pub fn accounts_base_url<S>(self, accounts_base_url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            base_urls: BaseUrls {
+                accounts: parse_base_url(accounts_base_url.into()),
+                ..self.base_urls
+            },
+            ..self
+        }
+    }
+
+    /// Set the sleeper asynchronous clients use to wait out API rate limits. Defaults to
+    /// [DefaultRateLimitSleeper](rate_limit::DefaultRateLimitSleeper). See the [rate_limit module](rate_limit) for
+    /// more information.
+    #[cfg(feature = "async")]
+    pub fn rate_limit_sleeper_async<S>(self, rate_limit_sleeper: S) -> Self
+    where
+        S: AsyncRateLimitSleeper + 'static,
+    {
+        Self {
+            async_rate_limit_sleeper: Arc::new(rate_limit_sleeper),
+            ..self
+        }
+    }
+
+    /// Set the sleeper synchronous clients use to wait out API rate limits. Defaults to
+    /// [DefaultRateLimitSleeper](rate_limit::DefaultRateLimitSleeper). See the [rate_limit module](rate_limit) for
+    /// more information.
+    #[cfg(feature = "sync")]
+    pub fn rate_limit_sleeper_sync<S>(self, rate_limit_sleeper: S) -> Self
+    where
+        S: SyncRateLimitSleeper + 'static,
+    {
+        Self {
+            sync_rate_limit_sleeper: Arc::new(rate_limit_sleeper),
+            ..self
+        }
+    }
+
+    /// Set the HTTP client asynchronous clients use to send requests, for example to route requests through a proxy or
+    /// to trust custom root certificates. By default, a new HTTP client is built for each Spotify client.
+    ///
+    /// The Spotify client adds its own headers, such as the authorization and locale headers, to every request, so the
+    /// given HTTP client doesn't need to be configured with them.
+    #[cfg(feature = "async")]
+    pub fn http_client_async(self, http_client: reqwest::Client) -> Self {
+        Self {
+            async_http_client: Some(http_client),
+            ..self
+        }
+    }
+
+    /// Set the HTTP client synchronous clients use to send requests. See the
+    /// [`http_client_async`-function](Self::http_client_async) for more information.
+    #[cfg(feature = "sync")]
+    pub fn http_client_sync(self, http_client: reqwest::blocking::Client) -> Self {
+        Self {
+            sync_http_client: Some(http_client),
+            ..self
+        }
+    }
+
+    /// Set a cache for catalog tracks retrieved with the [`track`](UnscopedClient::track) and
+    /// [`tracks`](UnscopedClient::tracks) functions. By default, clients don't cache anything. See the [cache
+    /// module](cache) for more information.
+    #[cfg(feature = "cache")]
+    pub fn track_cache(self, track_cache: Arc<TrackCache>) -> Self {
+        Self {
+            track_cache: Some(track_cache),
+            ..self
         }
     }
 
@@ -461,22 +873,72 @@ impl SpotifyClientBuilder {
         SpotifyClientWithSecretBuilder {
             client_id: self.client_id,
             client_secret: client_secret.into(),
+            locale: self.locale,
+            deserialization_mode: self.deserialization_mode,
+            rate_limiter: self.rate_limiter,
+            rate_limit_policy: self.rate_limit_policy,
+            timeout: self.timeout,
+            base_urls: self.base_urls,
+            #[cfg(feature = "async")]
+            async_rate_limit_sleeper: self.async_rate_limit_sleeper,
+            #[cfg(feature = "sync")]
+            sync_rate_limit_sleeper: self.sync_rate_limit_sleeper,
+            #[cfg(feature = "async")]
+            async_http_client: self.async_http_client,
+            #[cfg(feature = "sync")]
+            sync_http_client: self.sync_http_client,
+            #[cfg(feature = "cache")]
+            track_cache: self.track_cache,
         }
     }
 
     /// Finalize the builder and return an asynchronous Spotify client.
     #[cfg(feature = "async")]
     pub fn build_async(self) -> AsyncSpotifyClient {
-        self.build_client()
+        let http_client = AsyncClient::new(
+            self.async_http_client.clone(),
+            self.get_default_headers(),
+            self.async_rate_limit_sleeper.clone(),
+        )
+        .with_deserialization_mode(self.deserialization_mode)
+        .with_rate_limiter(self.rate_limiter.clone())
+        .with_rate_limit_policy(self.rate_limit_policy)
+        .with_timeout(self.timeout)
+        .with_base_urls(self.base_urls.clone());
+        #[cfg(feature = "cache")]
+        let http_client = http_client.with_track_cache(self.track_cache.clone());
+        self.build_client(http_client)
     }
 
     /// Finalize the builder and return a synchronous Spotify client.
     #[cfg(feature = "sync")]
     pub fn build_sync(self) -> SyncSpotifyClient {
-        self.build_client()
+        let http_client = SyncClient::new(
+            self.sync_http_client.clone(),
+            self.get_default_headers(),
+            self.sync_rate_limit_sleeper.clone(),
+        )
+        .with_deserialization_mode(self.deserialization_mode)
+        .with_rate_limiter(self.rate_limiter.clone())
+        .with_rate_limit_policy(self.rate_limit_policy)
+        .with_timeout(self.timeout)
+        .with_base_urls(self.base_urls.clone());
+        #[cfg(feature = "cache")]
+        let http_client = http_client.with_track_cache(self.track_cache.clone());
+        self.build_client(http_client)
     }
 
-    fn build_client<C>(self) -> SpotifyClient<C>
+    fn get_default_headers(&self) -> HeaderMap {
+        let mut default_headers = header::HeaderMap::new();
+
+        if let Some(locale) = &self.locale {
+            default_headers.insert(header::ACCEPT_LANGUAGE, locale.clone());
+        }
+
+        default_headers
+    }
+
+    fn build_client<C>(self, http_client: C) -> SpotifyClient<C>
     where
         C: private::HttpClient + Clone,
     {
@@ -484,12 +946,181 @@ impl SpotifyClientBuilder {
             inner: Arc::new(SpotifyClientRef {
                 client_id: self.client_id,
             }),
-            http_client: C::new(),
+            http_client,
         }
     }
 }
 
 impl SpotifyClientWithSecretBuilder {
+    /// Set the default locale for all requests sent by the client, for example `HeaderValue::from_static("es-ES")`.
+    /// See the [`locale`-function in SpotifyClientBuilder](SpotifyClientBuilder::locale) for more information.
+    pub fn locale(self, locale: HeaderValue) -> Self {
+        Self {
+            locale: Some(locale),
+            ..self
+        }
+    }
+
+    /// Set how strictly the client deserializes Spotify's responses. See the [`deserialization_mode`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::deserialization_mode) for more information.
+    pub fn deserialization_mode(self, deserialization_mode: DeserializationMode) -> Self {
+        Self {
+            deserialization_mode,
+            ..self
+        }
+    }
+
+    /// Set a rate limiter shared with other clients. See the [`rate_limiter`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::rate_limiter) for more information.
+    pub fn rate_limiter(self, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            rate_limiter: Some(rate_limiter),
+            ..self
+        }
+    }
+
+    /// Set how many times a rate limited request is retried before giving up. See the [`max_rate_limit_retries`-function
+    /// in SpotifyClientBuilder](SpotifyClientBuilder::max_rate_limit_retries) for more information.
+    pub fn max_rate_limit_retries(self, max_retries: u32) -> Self {
+        Self {
+            rate_limit_policy: RateLimitPolicy {
+                max_retries: Some(max_retries),
+                ..self.rate_limit_policy
+            },
+            ..self
+        }
+    }
+
+    /// Set the longest rate limit the client waits out. See the [`max_rate_limit_wait`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::max_rate_limit_wait) for more information.
+    pub fn max_rate_limit_wait(self, max_wait: Duration) -> Self {
+        Self {
+            rate_limit_policy: RateLimitPolicy {
+                max_wait: Some(max_wait),
+                ..self.rate_limit_policy
+            },
+            ..self
+        }
+    }
+
+    /// Set how long a request waits when it gets rate limited multiple times in a row. See the
+    /// [`rate_limit_backoff`-function in SpotifyClientBuilder](SpotifyClientBuilder::rate_limit_backoff) for more
+    /// information.
+    pub fn rate_limit_backoff(self, backoff: RateLimitBackoff) -> Self {
+        Self {
+            rate_limit_policy: RateLimitPolicy {
+                backoff,
+                ..self.rate_limit_policy
+            },
+            ..self
+        }
+    }
+
+    /// Set a timeout for every request the client sends. See the [`timeout`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::timeout) for more information.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Set the base URL the client sends API requests to. See the [`api_base_url`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::api_base_url) for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given URL is not a valid absolute URL.
+    pub fn api_base_url<S>(self, api_base_url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            base_urls: BaseUrls {
+                api: parse_base_url(api_base_url.into()),
+                ..self.base_urls
+            },
+            ..self
+        }
+    }
+
+    /// Set the base URL of the accounts service. See the [`accounts_base_url`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::accounts_base_url) for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given URL is not a valid absolute URL.
+    pub fn accounts_base_url<S>(self, accounts_base_url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            base_urls: BaseUrls {
+                accounts: parse_base_url(accounts_base_url.into()),
+                ..self.base_urls
+            },
+            ..self
+        }
+    }
+
+    /// Set the sleeper asynchronous clients use to wait out API rate limits. Defaults to
+    /// [DefaultRateLimitSleeper](rate_limit::DefaultRateLimitSleeper). See the [rate_limit module](rate_limit) for
+    /// more information.
+    #[cfg(feature = "async")]
+    pub fn rate_limit_sleeper_async<S>(self, rate_limit_sleeper: S) -> Self
+    where
+        S: AsyncRateLimitSleeper + 'static,
+    {
+        Self {
+            async_rate_limit_sleeper: Arc::new(rate_limit_sleeper),
+            ..self
+        }
+    }
+
+    /// Set the sleeper synchronous clients use to wait out API rate limits. Defaults to
+    /// [DefaultRateLimitSleeper](rate_limit::DefaultRateLimitSleeper). See the [rate_limit module](rate_limit) for
+    /// more information.
+    #[cfg(feature = "sync")]
+    pub fn rate_limit_sleeper_sync<S>(self, rate_limit_sleeper: S) -> Self
+    where
+        S: SyncRateLimitSleeper + 'static,
+    {
+        Self {
+            sync_rate_limit_sleeper: Arc::new(rate_limit_sleeper),
+            ..self
+        }
+    }
+
+    /// Set the HTTP client asynchronous clients use to send requests. See the [`http_client_async`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::http_client_async) for more information.
+    #[cfg(feature = "async")]
+    pub fn http_client_async(self, http_client: reqwest::Client) -> Self {
+        Self {
+            async_http_client: Some(http_client),
+            ..self
+        }
+    }
+
+    /// Set the HTTP client synchronous clients use to send requests. See the [`http_client_async`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::http_client_async) for more information.
+    #[cfg(feature = "sync")]
+    pub fn http_client_sync(self, http_client: reqwest::blocking::Client) -> Self {
+        Self {
+            sync_http_client: Some(http_client),
+            ..self
+        }
+    }
+
+    /// Set a cache for catalog tracks. See the [`track_cache`-function in
+    /// SpotifyClientBuilder](SpotifyClientBuilder::track_cache) for more information.
+    #[cfg(feature = "cache")]
+    pub fn track_cache(self, track_cache: Arc<TrackCache>) -> Self {
+        Self {
+            track_cache: Some(track_cache),
+            ..self
+        }
+    }
+
     fn get_default_headers(&self) -> HeaderMap {
         let mut default_headers = header::HeaderMap::new();
 
@@ -508,6 +1139,10 @@ impl SpotifyClientWithSecretBuilder {
                 ),
         );
 
+        if let Some(locale) = &self.locale {
+            default_headers.insert(header::ACCEPT_LANGUAGE, locale.clone());
+        }
+
         default_headers
     }
 
@@ -522,6 +1157,7 @@ impl SpotifyClientWithSecretBuilder {
                 client_id: self.client_id,
                 // client_secret: self.client_secret,
                 access_token: RwLock::new(token_response.access_token),
+                access_token_expires_at: RwLock::new(access_token_expiry_instant(token_response.expires_in)),
             }),
             http_client,
         }
@@ -535,13 +1171,18 @@ impl SpotifyClientWithSecretBuilder {
     pub async fn build_async(self) -> Result<AsyncSpotifyClientWithSecret> {
         debug!("Requesting access token for client credentials flow");
 
-        let http_client = AsyncClient(
-            reqwest::Client::builder()
-                .default_headers(self.get_default_headers())
-                .build()
-                // this can only fail due to a system error or system misconfiguration
-                .expect("failed to build HTTP client: system error or system misconfiguration"),
-        );
+        let http_client = AsyncClient::new(
+            self.async_http_client.clone(),
+            self.get_default_headers(),
+            self.async_rate_limit_sleeper.clone(),
+        )
+        .with_deserialization_mode(self.deserialization_mode)
+        .with_rate_limiter(self.rate_limiter.clone())
+        .with_rate_limit_policy(self.rate_limit_policy)
+        .with_timeout(self.timeout)
+        .with_base_urls(self.base_urls.clone());
+        #[cfg(feature = "cache")]
+        let http_client = http_client.with_track_cache(self.track_cache.clone());
 
         let response = http_client
             .post(ACCOUNTS_API_TOKEN_ENDPOINT)
@@ -564,13 +1205,18 @@ impl SpotifyClientWithSecretBuilder {
     pub fn build_sync(self) -> Result<SyncSpotifyClientWithSecret> {
         debug!("Requesting access token for client credentials flow");
 
-        let http_client = SyncClient(
-            reqwest::blocking::Client::builder()
-                .default_headers(self.get_default_headers())
-                .build()
-                // this can only fail due to a system error or system misconfiguration
-                .expect("failed to build blocking HTTP client: system error or system misconfiguration"),
-        );
+        let http_client = SyncClient::new(
+            self.sync_http_client.clone(),
+            self.get_default_headers(),
+            self.sync_rate_limit_sleeper.clone(),
+        )
+        .with_deserialization_mode(self.deserialization_mode)
+        .with_rate_limiter(self.rate_limiter.clone())
+        .with_rate_limit_policy(self.rate_limit_policy)
+        .with_timeout(self.timeout)
+        .with_base_urls(self.base_urls.clone());
+        #[cfg(feature = "cache")]
+        let http_client = http_client.with_track_cache(self.track_cache.clone());
 
         let response = http_client
             .post(ACCOUNTS_API_TOKEN_ENDPOINT)
@@ -586,13 +1232,44 @@ impl SpotifyClientWithSecretBuilder {
 
 impl<C> crate::private::Sealed for SpotifyClientWithSecret<C> where C: private::HttpClient + Clone {}
 
+impl<C> private::TrackCacheAccess for SpotifyClientWithSecret<C>
+where
+    C: private::HttpClient + Clone,
+{
+    #[cfg(feature = "cache")]
+    fn track_cache(&self) -> Option<&TrackCache> {
+        self.http_client.track_cache()
+    }
+}
+
 impl<C> SpotifyClientWithSecret<C>
 where
     C: private::HttpClient + Clone,
 {
+    /// Returns whether the current access token has expired. An expired access token is refreshed automatically when
+    /// the next request fails due to it, or it may be refreshed ahead of time with the `refresh_access_token` function.
+    pub fn is_access_token_expired(&self) -> bool {
+        self.access_token_expires_in().is_none()
+    }
+
+    /// Returns how long the current access token is still valid for, or `None` if it has already expired.
+    pub fn access_token_expires_in(&self) -> Option<Duration> {
+        self.inner
+            .access_token_expires_at
+            .read()
+            .expect("access token expiry rwlock poisoned")
+            .checked_duration_since(Instant::now())
+            .filter(|expires_in| !expires_in.is_zero())
+    }
+
     fn save_access_token(&self, token_response: ClientTokenResponse) {
         debug!("Got token response for client credentials flow: {:?}", token_response);
         *self.inner.access_token.write().expect("access token rwlock poisoned") = token_response.access_token;
+        *self
+            .inner
+            .access_token_expires_at
+            .write()
+            .expect("access token expiry rwlock poisoned") = access_token_expiry_instant(token_response.expires_in);
     }
 }
 
@@ -605,6 +1282,26 @@ impl private::BuildHttpRequestAsync for AsyncSpotifyClientWithSecret {
         let access_token = self.inner.access_token.read().expect("access token rwlock poisoned");
         self.http_client.request(method, url).bearer_auth(access_token.as_str())
     }
+
+    fn api_base_url(&self) -> &Url {
+        self.http_client.api_base_url()
+    }
+
+    fn rate_limit_sleeper(&self) -> &dyn AsyncRateLimitSleeper {
+        self.http_client.rate_limit_sleeper()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.http_client.rate_limiter()
+    }
+
+    fn rate_limit_policy(&self) -> RateLimitPolicy {
+        self.http_client.rate_limit_policy()
+    }
+
+    fn deserialization_mode(&self) -> DeserializationMode {
+        self.http_client.deserialization_mode()
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -616,6 +1313,26 @@ impl private::BuildHttpRequestSync for SyncSpotifyClientWithSecret {
         let access_token = self.inner.access_token.read().expect("access token rwlock poisoned");
         self.http_client.request(method, url).bearer_auth(access_token.as_str())
     }
+
+    fn api_base_url(&self) -> &Url {
+        self.http_client.api_base_url()
+    }
+
+    fn rate_limit_sleeper(&self) -> &dyn SyncRateLimitSleeper {
+        self.http_client.rate_limit_sleeper()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.http_client.rate_limiter()
+    }
+
+    fn rate_limit_policy(&self) -> RateLimitPolicy {
+        self.http_client.rate_limit_policy()
+    }
+
+    fn deserialization_mode(&self) -> DeserializationMode {
+        self.http_client.deserialization_mode()
+    }
 }
 
 #[cfg(feature = "async")]
@@ -696,6 +1413,29 @@ fn build_authorization_header(client_id: &str, client_secret: &str) -> String {
     )
 }
 
+/// Parses a base URL that endpoint paths can be resolved relative to. A missing trailing slash is added, since without
+/// it the last segment of the base URL's path would be replaced instead of appended to.
+fn parse_base_url(mut base_url: String) -> Url {
+    if !base_url.ends_with('/') {
+        base_url.push('/');
+    }
+
+    match Url::parse(&base_url) {
+        Ok(url) if !url.cannot_be_a_base() => url,
+        _ => panic!("invalid base URL: {base_url}"),
+    }
+}
+
+/// Returns a client credentials flow client builder with the client ID and secret read from the given environment
+/// variables.
+fn builder_from_env_vars(client_id_var: &str, client_secret_var: &str) -> Result<SpotifyClientWithSecretBuilder> {
+    Ok(SpotifyClientBuilder::new(read_env_var(client_id_var)?).client_secret(read_env_var(client_secret_var)?))
+}
+
+fn read_env_var(name: &str) -> Result<String> {
+    std::env::var(name).map_err(|_| Error::MissingEnvironmentVariable(name.to_owned()))
+}
+
 /// Takes a response for an authentication request and if its status is 400, parses its body as an authentication error.
 /// On success returns the given response without modifying it.
 #[cfg(feature = "async")]
@@ -724,33 +1464,32 @@ fn extract_authentication_error_sync(response: reqwest::blocking::Response) -> R
     }
 }
 
-/// Sleep for the specified amount of time by blocking the current thread.
-#[cfg(feature = "sync")]
-fn rate_limit_sleep_sync(sleep_time: u64) -> Result<()> {
-    std::thread::sleep(std::time::Duration::from_secs(sleep_time));
-    Ok(())
+fn access_token_expiry_instant(expires_in: u32) -> Instant {
+    Instant::now() + Duration::from_secs(expires_in.into())
 }
 
-/// Return a rate limit error since no sleep utility has been enabled.
-#[cfg(all(feature = "async", not(feature = "tokio_sleep"), not(feature = "async_std_sleep")))]
-async fn rate_limit_sleep_async(sleep_time: u64) -> Result<()> {
-    Err(crate::error::Error::RateLimit(sleep_time))
-}
+/// Logs a warning if the given authorize URL is long enough that some user agents or proxies may refuse it.
+fn warn_if_authorize_url_too_long(authorize_url: &Url) {
+    let length = authorize_url.as_str().len();
 
-// sleeping with tokio takes precedence over async_std so if the user enables both features for some reason, they get
-// tokio sleep
-/// Sleep for the specified amount of time using tokio's sleep function.
-#[cfg(all(feature = "async", feature = "tokio_sleep"))]
-async fn rate_limit_sleep_async(sleep_time: u64) -> Result<()> {
-    tokio::time::sleep(std::time::Duration::from_secs(sleep_time)).await;
-    Ok(())
+    if length > AUTHORIZE_URL_WARNING_LENGTH {
+        warn!(
+            "The authorize URL is {length} characters long, which is over {AUTHORIZE_URL_WARNING_LENGTH} characters. \
+             Some user agents and proxies may refuse it"
+        );
+    }
 }
 
-/// Sleep for the specified amount of time using async_std's sleep function.
-#[cfg(all(feature = "async", feature = "async_std_sleep", not(feature = "tokio_sleep")))]
-async fn rate_limit_sleep_async(sleep_time: u64) -> Result<()> {
-    async_std::task::sleep(std::time::Duration::from_secs(sleep_time)).await;
-    Ok(())
+/// Appends the given extra query parameters to an authorize URL's query parameters, skipping the ones that would
+/// override a parameter the client already sets itself.
+fn push_extra_query_params<'a>(query_params: &mut Vec<(&'a str, &'a str)>, extra_query_params: &'a [(String, String)]) {
+    for (key, value) in extra_query_params {
+        if query_params.iter().any(|(existing, _)| existing == key) {
+            warn!("Ignoring extra authorize URL query parameter {key}: the client already sets it");
+        } else {
+            query_params.push((key, value));
+        }
+    }
 }
 
 fn map_client_authentication_error(err: Error) -> Error {
@@ -760,3 +1499,254 @@ fn map_client_authentication_error(err: Error) -> Error {
         err
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "async")]
+    use crate::test_support::serve_json_responses;
+
+    #[test]
+    fn missing_environment_variable_is_named() {
+        let result = builder_from_env_vars("FERRISPOT_TEST_UNSET_CLIENT_ID", "FERRISPOT_TEST_UNSET_CLIENT_SECRET");
+
+        assert!(matches!(
+            result,
+            Err(Error::MissingEnvironmentVariable(name)) if name == "FERRISPOT_TEST_UNSET_CLIENT_ID"
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn given_http_client_sends_default_headers() {
+        let client = SpotifyClientBuilder::new("client ID")
+            .locale(HeaderValue::from_static("fi-FI"))
+            .http_client_async(reqwest::Client::new())
+            .build_async();
+        let request = client
+            .http_client
+            .request(Method::GET, "https://api.spotify.com/v1/me")
+            .build()
+            .unwrap();
+
+        assert_eq!(request.headers().get(header::ACCEPT_LANGUAGE).unwrap(), "fi-FI");
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn client_credentials_header_is_only_sent_to_accounts_service() {
+        let builder = SpotifyClientBuilder::new("client ID")
+            .client_secret("client secret")
+            .http_client_async(reqwest::Client::new());
+        let http_client = AsyncClient::new(
+            builder.async_http_client.clone(),
+            builder.get_default_headers(),
+            builder.async_rate_limit_sleeper.clone(),
+        );
+
+        let api_request = http_client
+            .request(Method::GET, "https://api.spotify.com/v1/me")
+            .bearer_auth("access token")
+            .build()
+            .unwrap();
+        let token_request = http_client.post(ACCOUNTS_API_TOKEN_ENDPOINT).build().unwrap();
+
+        assert_eq!(
+            api_request
+                .headers()
+                .get_all(header::AUTHORIZATION)
+                .iter()
+                .collect::<Vec<_>>(),
+            ["Bearer access token"]
+        );
+        assert_eq!(
+            token_request.headers().get(header::AUTHORIZATION).unwrap(),
+            &build_authorization_header("client ID", "client secret")
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn timed_out_request_fails_with_timeout() {
+        // the listener accepts connections but never responds to them
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let client = SpotifyClientBuilder::new("client ID")
+            .http_client_async(reqwest::Client::builder().no_proxy().build().unwrap())
+            .timeout(Duration::from_millis(100))
+            .build_async();
+        let result = client
+            .http_client
+            .request(Method::GET, url)
+            .send()
+            .await
+            .map_err(Error::from);
+
+        assert!(matches!(result, Err(Error::Timeout(_))));
+    }
+
+    #[test]
+    fn debug_output_redacts_secrets() {
+        let builder = SpotifyClientBuilder::new("client ID").client_secret("hunter2");
+        let client_ref = SpotifyClientWithSecretRef {
+            client_id: "client ID".to_owned(),
+            access_token: RwLock::new("hunter2".to_owned()),
+            access_token_expires_at: RwLock::new(Instant::now()),
+        };
+
+        for debug in [format!("{builder:?}"), format!("{client_ref:?}")] {
+            assert!(!debug.contains("hunter2"), "{debug}");
+            assert!(debug.contains(r#""***""#), "{debug}");
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn access_token_expiry_is_tracked() {
+        let build_client = |expires_in| {
+            let builder = SpotifyClientBuilder::new("client ID").client_secret("client secret");
+            let http_client = AsyncClient::new(
+                None,
+                builder.get_default_headers(),
+                builder.async_rate_limit_sleeper.clone(),
+            );
+            let token_response = ClientTokenResponse {
+                access_token: "access token".to_owned(),
+                expires_in,
+                token_type: "Bearer".to_owned(),
+            };
+
+            builder.build_client(token_response, http_client)
+        };
+
+        let client = build_client(3600);
+        assert!(!client.is_access_token_expired());
+        assert!(client.access_token_expires_in().unwrap() <= Duration::from_secs(3600));
+
+        let client = build_client(0);
+        assert!(client.is_access_token_expired());
+        assert_eq!(client.access_token_expires_in(), None);
+    }
+
+    #[test]
+    fn base_url_gets_trailing_slash() {
+        assert_eq!(
+            parse_base_url("http://localhost:8080/v1".to_owned()).as_str(),
+            "http://localhost:8080/v1/"
+        );
+        assert_eq!(
+            parse_base_url("http://localhost:8080".to_owned()).as_str(),
+            "http://localhost:8080/"
+        );
+    }
+
+    #[cfg(feature = "async")]
+    const TOKEN_RESPONSE: &str = r#"{"access_token":"access token","token_type":"Bearer","expires_in":3600}"#;
+    #[cfg(feature = "async")]
+    const MARKETS_RESPONSE: &str = r#"{"markets":["FI"]}"#;
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn requests_are_sent_to_custom_base_urls() {
+        use crate::{client::request_builder::AsyncRequestBuilder, model::CountryCode};
+
+        let (url, requests) = serve_json_responses(&[TOKEN_RESPONSE, MARKETS_RESPONSE]);
+        let client = SpotifyClientBuilder::new("client ID")
+            .client_secret("client secret")
+            .http_client_async(reqwest::Client::builder().no_proxy().build().unwrap())
+            .api_base_url(format!("{url}v1"))
+            .accounts_base_url(format!("{url}accounts/"))
+            .build_async()
+            .await
+            .unwrap();
+        let markets = client.available_markets().send_async().await.unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(markets, [CountryCode::FI]);
+        assert!(requests[0].starts_with("POST /accounts/api/token "));
+        assert!(requests[1].starts_with("GET /v1/markets"));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn locale_is_sent_unless_overridden() {
+        use crate::client::request_builder::{AsyncRequestBuilder, BaseRequestBuilder};
+
+        let (url, requests) = serve_json_responses(&[TOKEN_RESPONSE, MARKETS_RESPONSE, MARKETS_RESPONSE]);
+        let client = SpotifyClientBuilder::new("client ID")
+            .client_secret("client secret")
+            .locale(HeaderValue::from_static("fi-FI"))
+            .http_client_async(reqwest::Client::builder().no_proxy().build().unwrap())
+            .api_base_url(url.as_str())
+            .accounts_base_url(url.as_str())
+            .build_async()
+            .await
+            .unwrap();
+
+        client.available_markets().send_async().await.unwrap();
+        client
+            .available_markets()
+            .header(header::ACCEPT_LANGUAGE, HeaderValue::from_static("sv-SE"))
+            .send_async()
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[1].contains("accept-language: fi-FI\r\n"), "{}", requests[1]);
+        assert!(requests[2].contains("accept-language: sv-SE\r\n"), "{}", requests[2]);
+        assert!(!requests[2].contains("fi-FI"), "{}", requests[2]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn restored_client_reports_rotated_refresh_token() {
+        const ROTATED_TOKEN_RESPONSE: &str =
+            r#"{"access_token":"access token","token_type":"Bearer","expires_in":3600,"refresh_token":"rotated"}"#;
+        const ROTATED_AGAIN_TOKEN_RESPONSE: &str =
+            r#"{"access_token":"access token","token_type":"Bearer","expires_in":3600,"refresh_token":"re-rotated"}"#;
+
+        let (url, requests) =
+            serve_json_responses(&[TOKEN_RESPONSE, ROTATED_TOKEN_RESPONSE, ROTATED_AGAIN_TOKEN_RESPONSE]);
+        let client = SpotifyClientBuilder::new("client ID")
+            .client_secret("client secret")
+            .http_client_async(reqwest::Client::builder().no_proxy().build().unwrap())
+            .accounts_base_url(url.as_str())
+            .build_async()
+            .await
+            .unwrap();
+
+        let refresh_tokens = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let callback_refresh_tokens = Arc::clone(&refresh_tokens);
+        let user_client = client
+            .authorization_code_client_with_refresh_token("stored refresh token", move |refresh_token: &str| {
+                callback_refresh_tokens.lock().unwrap().push(refresh_token.to_owned())
+            })
+            .await
+            .unwrap();
+
+        // the refresh that creates the client may already rotate the stored refresh token
+        assert_eq!(*refresh_tokens.lock().unwrap(), ["rotated"]);
+        assert_eq!(user_client.get_refresh_token(), "rotated");
+
+        user_client.refresh_access_token().await.unwrap();
+        assert_eq!(*refresh_tokens.lock().unwrap(), ["rotated", "re-rotated"]);
+        assert_eq!(user_client.get_refresh_token(), "re-rotated");
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[1].starts_with("POST /api/token "), "{}", requests[1]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn authorize_url_uses_custom_accounts_base_url() {
+        let authorize_url = SpotifyClientBuilder::new("client ID")
+            .accounts_base_url("http://localhost:8080")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .build()
+            .get_authorize_url();
+
+        assert!(authorize_url.starts_with("http://localhost:8080/authorize?"));
+    }
+}
@@ -57,6 +57,11 @@
 //!     .finalize(code, state)
 //!     .await
 //!     .expect("failed to finalize authorization code flow client");
+//!
+//! // the user may not have granted every requested scope
+//! if !user_client.granted_scopes().contains(&Scope::UserReadPlaybackState) {
+//!     println!("cannot read the playback state");
+//! }
 //! # }
 //! ```
 //!
@@ -91,7 +96,10 @@
 //! // to the documentation above
 //! # }
 
-use std::sync::{Arc, RwLock};
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
 
 use base64::Engine;
 use log::debug;
@@ -101,16 +109,18 @@ use serde::Deserialize;
 use sha2::Digest;
 
 use super::{
-    private, ACCOUNTS_API_TOKEN_ENDPOINT, ACCOUNTS_AUTHORIZE_ENDPOINT, PKCE_VERIFIER_LENGTH, RANDOM_STATE_LENGTH,
+    access_token_expiry_instant, deserialization::DeserializationMode, private, push_extra_query_params,
+    rate_limit::RateLimiter, warn_if_authorize_url_too_long, ACCESS_TOKEN_REFRESH_MARGIN, ACCOUNTS_API_TOKEN_ENDPOINT,
+    ACCOUNTS_AUTHORIZE_ENDPOINT, PKCE_VERIFIER_LENGTH, RANDOM_STATE_LENGTH, REDACTED,
 };
 #[cfg(feature = "async")]
-use super::{private::AsyncClient, AccessTokenRefreshAsync};
+use super::{private::AsyncClient, rate_limit::AsyncRateLimitSleeper, AccessTokenRefreshAsync};
 #[cfg(feature = "sync")]
-use super::{private::SyncClient, AccessTokenRefreshSync};
+use super::{private::SyncClient, rate_limit::SyncRateLimitSleeper, AccessTokenRefreshSync};
 use crate::{
     error::{Error, Result},
     model::error::AuthenticationErrorKind,
-    scope::ToScopesString,
+    scope::{parse_scopes, Scope, ToScopesString},
 };
 
 /// Type alias for an asynchronous authorization code user client. See
@@ -164,11 +174,13 @@ where
     http_client: C,
 }
 
-#[derive(Debug)]
 struct AuthorizationCodeUserClientRef {
     access_token: RwLock<String>,
+    access_token_expires_at: RwLock<Instant>,
     refresh_token: RwLock<String>,
+    granted_scopes: RwLock<Vec<Scope>>,
     client_id: Option<String>,
+    on_token_refresh: Option<TokenRefreshCallback>,
 }
 
 /// An incomplete authorization code user client.
@@ -186,7 +198,9 @@ where
     state: String,
     scopes: Option<String>,
     show_dialog: bool,
+    extra_query_params: Vec<(String, String)>,
     pkce_verifier: Option<String>,
+    on_token_refresh: Option<TokenRefreshCallback>,
 
     http_client: C,
 }
@@ -201,39 +215,91 @@ where
     redirect_uri: String,
     scopes: Option<String>,
     show_dialog: bool,
+    extra_query_params: Vec<(String, String)>,
     pkce_verifier: Option<String>,
+    on_token_refresh: Option<TokenRefreshCallback>,
 
     http_client: C,
 }
 
-#[derive(Debug, Deserialize)]
+/// The `code` and `state` query parameters from a successful authorization callback.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct AuthorizationCallback {
+    pub code: String,
+    pub state: String,
+}
+
+/// A callback invoked with a new refresh token whenever the client receives one.
+#[derive(Clone)]
+struct TokenRefreshCallback(Arc<dyn Fn(&str) + Send + Sync>);
+
+impl std::fmt::Debug for TokenRefreshCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TokenRefreshCallback")
+    }
+}
+
+#[derive(Deserialize)]
 struct AuthorizeUserTokenResponse {
     access_token: String,
     refresh_token: String,
+    expires_in: u32,
 
-    // these fields are in the response but the library doesn't need them. keep them here for logging purposes
-    #[allow(dead_code)]
     scope: Option<String>,
-    #[allow(dead_code)]
-    expires_in: u32,
-    #[allow(dead_code)]
+
+    // this field is in the response but the library doesn't need it. keep it here for logging purposes
     token_type: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 struct RefreshUserTokenResponse {
     access_token: String,
     refresh_token: Option<String>,
+    expires_in: u32,
 
-    // these fields are in the response but the library doesn't need them. keep them here for logging purposes
-    #[allow(dead_code)]
     scope: Option<String>,
-    #[allow(dead_code)]
-    expires_in: u32,
-    #[allow(dead_code)]
+
+    // this field is in the response but the library doesn't need it. keep it here for logging purposes
     token_type: String,
 }
 
+impl std::fmt::Debug for AuthorizationCodeUserClientRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthorizationCodeUserClientRef")
+            .field("access_token", &REDACTED)
+            .field("access_token_expires_at", &self.access_token_expires_at)
+            .field("refresh_token", &REDACTED)
+            .field("granted_scopes", &self.granted_scopes)
+            .field("client_id", &self.client_id)
+            .field("on_token_refresh", &self.on_token_refresh)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for AuthorizeUserTokenResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthorizeUserTokenResponse")
+            .field("access_token", &REDACTED)
+            .field("refresh_token", &REDACTED)
+            .field("expires_in", &self.expires_in)
+            .field("scope", &self.scope)
+            .field("token_type", &self.token_type)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for RefreshUserTokenResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshUserTokenResponse")
+            .field("access_token", &REDACTED)
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| REDACTED))
+            .field("expires_in", &self.expires_in)
+            .field("scope", &self.scope)
+            .field("token_type", &self.token_type)
+            .finish()
+    }
+}
+
 impl<C> AuthorizationCodeUserClient<C>
 where
     C: private::HttpClient + Clone,
@@ -242,6 +308,7 @@ where
         token_response: RefreshUserTokenResponse,
         refresh_token: String,
         client_id: Option<String>,
+        on_token_refresh: TokenRefreshCallback,
         http_client: C,
     ) -> Self {
         debug!(
@@ -249,13 +316,25 @@ where
             token_response
         );
 
-        let refresh_token = token_response.refresh_token.unwrap_or(refresh_token);
+        // the refresh token may already be rotated by the refresh that creates the client
+        let refresh_token = match token_response.refresh_token {
+            Some(refresh_token) => {
+                (on_token_refresh.0)(&refresh_token);
+                refresh_token
+            }
+
+            None => refresh_token,
+        };
+        let granted_scopes = token_response.scope.as_deref().map(parse_scopes).unwrap_or_default();
 
         Self {
             inner: Arc::new(AuthorizationCodeUserClientRef {
                 access_token: RwLock::new(token_response.access_token),
+                access_token_expires_at: RwLock::new(access_token_expiry_instant(token_response.expires_in)),
                 refresh_token: RwLock::new(refresh_token),
+                granted_scopes: RwLock::new(granted_scopes),
                 client_id,
+                on_token_refresh: Some(on_token_refresh),
             }),
             http_client,
         }
@@ -276,6 +355,70 @@ where
             .to_owned()
     }
 
+    /// Returns the scopes the user has granted to the application.
+    ///
+    /// The granted scopes are known as soon as the client has been finalized or created from a refresh token, and they
+    /// may differ from the scopes originally requested; the user may decline some scopes and Spotify may grant some
+    /// scopes automatically. Spotify may also change the granted scopes when the access token is refreshed. Scopes this
+    /// library doesn't know of are omitted.
+    ///
+    /// This function returns an owned Vec by cloning the internal granted scopes.
+    pub fn granted_scopes(&self) -> Vec<Scope> {
+        self.inner
+            .granted_scopes
+            .read()
+            .expect("granted scopes rwlock poisoned")
+            .to_owned()
+    }
+
+    /// Returns [MissingScope](Error::MissingScope) if the given scope isn't one of the [granted
+    /// scopes](Self::granted_scopes).
+    ///
+    /// Scoped endpoints can be made to do this check before sending their request with the [`check_scope`-function in
+    /// the request builders](crate::client::request_builder::BaseRequestBuilder::check_scope).
+    pub fn ensure_scope(&self, scope: Scope) -> Result<()> {
+        if self
+            .inner
+            .granted_scopes
+            .read()
+            .expect("granted scopes rwlock poisoned")
+            .contains(&scope)
+        {
+            Ok(())
+        } else {
+            Err(Error::MissingScope)
+        }
+    }
+
+    /// Returns whether the current access token has expired. An expired access token is refreshed automatically when
+    /// the next request fails due to it, or it may be refreshed ahead of time with the `refresh_access_token` function.
+    pub fn is_access_token_expired(&self) -> bool {
+        self.access_token_expires_in().is_none()
+    }
+
+    /// Returns how long the current access token is still valid for, or `None` if it has already expired.
+    pub fn access_token_expires_in(&self) -> Option<Duration> {
+        self.inner
+            .access_token_expires_at
+            .read()
+            .expect("access token expiry rwlock poisoned")
+            .checked_duration_since(Instant::now())
+            .filter(|expires_in| !expires_in.is_zero())
+    }
+
+    /// Returns the instant when the current access token should be refreshed, i.e. when it expires minus a small safety
+    /// margin. This is useful for scheduling refreshing the access token ahead of time with the `refresh_access_token`
+    /// function instead of reacting to it expiring.
+    ///
+    /// Returns `None` if the instant cannot be represented, which generally shouldn't happen.
+    pub fn next_refresh_instant(&self) -> Option<Instant> {
+        self.inner
+            .access_token_expires_at
+            .read()
+            .expect("access token expiry rwlock poisoned")
+            .checked_sub(ACCESS_TOKEN_REFRESH_MARGIN)
+    }
+
     fn update_access_and_refresh_tokens(&self, token_response: RefreshUserTokenResponse) {
         debug!(
             "Got token response for refreshing authorization code flow tokens: {:?}",
@@ -283,24 +426,45 @@ where
         );
 
         *self.inner.access_token.write().expect("access token rwlock poisoned") = token_response.access_token;
+        *self
+            .inner
+            .access_token_expires_at
+            .write()
+            .expect("access token expiry rwlock poisoned") = access_token_expiry_instant(token_response.expires_in);
 
         if let Some(refresh_token) = token_response.refresh_token {
-            *self.inner.refresh_token.write().expect("refresh token rwlock poisoned") = refresh_token;
+            *self.inner.refresh_token.write().expect("refresh token rwlock poisoned") = refresh_token.clone();
+
+            if let Some(on_token_refresh) = &self.inner.on_token_refresh {
+                (on_token_refresh.0)(&refresh_token);
+            }
+        }
+
+        if let Some(scope) = token_response.scope {
+            *self
+                .inner
+                .granted_scopes
+                .write()
+                .expect("granted scopes rwlock poisoned") = parse_scopes(&scope);
         }
     }
 }
 
 #[cfg(feature = "async")]
 impl AsyncAuthorizationCodeUserClient {
-    pub(crate) async fn new_with_refresh_token(
+    pub(crate) async fn new_with_refresh_token<F>(
         http_client: AsyncClient,
         refresh_token: String,
         client_id: Option<String>,
-    ) -> Result<Self> {
+        on_token_refresh: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
         debug!(
-            "Attempting to create new authorization code flow client with existng refresh token: {} and client ID \
-             (for PKCE): {:?}",
-            refresh_token, client_id
+            "Attempting to create new authorization code flow client with existing refresh token and client ID (for \
+             PKCE): {:?}",
+            client_id
         );
 
         let response = http_client
@@ -319,6 +483,7 @@ impl AsyncAuthorizationCodeUserClient {
             token_response,
             refresh_token,
             client_id,
+            TokenRefreshCallback(Arc::new(on_token_refresh)),
             http_client,
         ))
     }
@@ -326,15 +491,19 @@ impl AsyncAuthorizationCodeUserClient {
 
 #[cfg(feature = "sync")]
 impl SyncAuthorizationCodeUserClient {
-    pub(crate) fn new_with_refresh_token(
+    pub(crate) fn new_with_refresh_token<F>(
         http_client: SyncClient,
         refresh_token: String,
         client_id: Option<String>,
-    ) -> Result<Self> {
+        on_token_refresh: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
         debug!(
-            "Attempting to create new authorization code flow client with existng refresh token: {} and client ID \
-             (for PKCE): {:?}",
-            refresh_token, client_id
+            "Attempting to create new authorization code flow client with existing refresh token and client ID (for \
+             PKCE): {:?}",
+            client_id
         );
 
         let response = http_client
@@ -349,6 +518,7 @@ impl SyncAuthorizationCodeUserClient {
             token_response,
             refresh_token,
             client_id,
+            TokenRefreshCallback(Arc::new(on_token_refresh)),
             http_client,
         ))
     }
@@ -364,6 +534,9 @@ where
     /// query in the callback will contain a `code` parameter and a `state` parameter, which should be passed to the
     /// [`finalize`-function](IncompleteAuthorizationCodeUserClient::finalize) in order to complete the client and get
     /// an [AuthorizationCodeUserClient].
+    ///
+    /// The URL grows with every requested scope. Spotify accepts the URL even with every scope requested, but some user
+    /// agents and proxies refuse very long URLs, so a warning is logged if the URL is over 2000 characters long.
     pub fn get_authorize_url(&self) -> String {
         let mut query_params = vec![
             ("response_type", "code"),
@@ -373,35 +546,33 @@ where
             ("show_dialog", if self.show_dialog { "true" } else { "false" }),
         ];
 
-        if let Some(scopes) = &self.scopes {
-            query_params.push(("scope", scopes.as_str()));
+        // an empty list of scopes would produce an empty scope parameter, which is the same as not requesting any
+        // scopes in the first place
+        if let Some(scopes) = self.scopes.as_deref().filter(|scopes| !scopes.is_empty()) {
+            query_params.push(("scope", scopes));
         }
 
-        let authorize_url = if let Some(pkce_verifier) = self.pkce_verifier.as_deref() {
+        let pkce_challenge = self.pkce_verifier.as_deref().map(|pkce_verifier| {
             let mut hasher = sha2::Sha256::new();
             hasher.update(pkce_verifier);
             let pkce_challenge = hasher.finalize();
             let pkce_challenge = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(pkce_challenge);
 
-            debug!(
-                "Using PKCE extension with verifier: {} and challenge: {}",
-                pkce_verifier, pkce_challenge
-            );
+            debug!("Using PKCE extension with challenge: {}", pkce_challenge);
 
-            query_params.extend([("code_challenge_method", "S256"), ("code_challenge", &pkce_challenge)]);
+            pkce_challenge
+        });
 
-            // parsing the URL fails only if the base URL is invalid, not the parameters. if this method fails, there's
-            // a bug in the library
+        if let Some(pkce_challenge) = pkce_challenge.as_deref() {
+            query_params.extend([("code_challenge_method", "S256"), ("code_challenge", pkce_challenge)]);
+        }
 
-            // while both these branches end the same way, this one borrows the pkce_challenge string in query_params so
-            // the URL must be built before the string falls out of scope
-            Url::parse_with_params(ACCOUNTS_AUTHORIZE_ENDPOINT, &query_params)
-                .expect("failed to build authorize URL: invalid base URL (this is likely a bug)")
-        } else {
-            Url::parse_with_params(ACCOUNTS_AUTHORIZE_ENDPOINT, &query_params)
-                .expect("failed to build authorize URL: invalid base URL (this is likely a bug)")
-        };
+        push_extra_query_params(&mut query_params, &self.extra_query_params);
 
+        let mut authorize_url = self.http_client.accounts_url(ACCOUNTS_AUTHORIZE_ENDPOINT);
+        authorize_url.query_pairs_mut().extend_pairs(&query_params);
+
+        warn_if_authorize_url_too_long(&authorize_url);
         authorize_url.into()
     }
 
@@ -411,8 +582,8 @@ where
         state: &str,
     ) -> Result<Vec<(&'a str, &'a str)>> {
         debug!(
-            "Attempting to finalize authorization code flow user client with code: {} and state: {}",
-            code, state
+            "Attempting to finalize authorization code flow user client with state: {}",
+            state
         );
 
         if state != self.state {
@@ -444,13 +615,45 @@ where
             // token. if the PKCE verifier is used, include the client ID
             inner: Arc::new(AuthorizationCodeUserClientRef {
                 access_token: RwLock::new(token_response.access_token),
+                access_token_expires_at: RwLock::new(access_token_expiry_instant(token_response.expires_in)),
                 refresh_token: RwLock::new(token_response.refresh_token),
+                granted_scopes: RwLock::new(token_response.scope.as_deref().map(parse_scopes).unwrap_or_default()),
                 client_id: self.pkce_verifier.and(Some(self.client_id)),
+                on_token_refresh: self.on_token_refresh,
             }),
         }
     }
 }
 
+impl AuthorizationCallback {
+    /// Parses the callback parameters from the query of the URL the user was redirected to. An `error` parameter in the
+    /// query means the user denied the authorization.
+    pub(crate) fn from_url(url: &Url) -> Result<Self> {
+        let mut code = None;
+        let mut state = None;
+
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "error" => return Err(Error::AuthorizationDenied(value.into_owned())),
+                "code" => code = Some(value.into_owned()),
+                "state" => state = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        match (code, state) {
+            (Some(code), Some(state)) => Ok(AuthorizationCallback { code, state }),
+            _ => Err(Error::InvalidAuthorizationCode),
+        }
+    }
+
+    /// Parses the callback parameters from the full URL the user was redirected to.
+    fn from_redirect_url(redirect_url: &str) -> Result<Self> {
+        let url = Url::parse(redirect_url).map_err(|_| Error::InvalidAuthorizationCode)?;
+        Self::from_url(&url)
+    }
+}
+
 #[cfg(feature = "async")]
 impl AsyncIncompleteAuthorizationCodeUserClient {
     /// Finalize this client with a code and a state from the callback URL query the user was redirected to after they
@@ -459,6 +662,9 @@ impl AsyncIncompleteAuthorizationCodeUserClient {
     /// This function will use the authorization code to request an access and a refresh token from Spotify. If the
     /// originally generated state does not match the `state` parameter, the function will return an
     /// [AuthorizationCodeStateMismatch-error](Error::AuthorizationCodeStateMismatch).
+    ///
+    /// The scopes the user actually granted are available from the finalized client's
+    /// [`granted_scopes`-function](AuthorizationCodeUserClient::granted_scopes) right away.
     pub async fn finalize(self, code: &str, state: &str) -> Result<AsyncAuthorizationCodeUserClient> {
         let token_request_form = self.build_authorization_code_token_request_form(code, state)?;
         let response = self
@@ -476,6 +682,44 @@ impl AsyncIncompleteAuthorizationCodeUserClient {
 
         Ok(self.build_client(token_response))
     }
+
+    /// Finalize this client with the full URL the user was redirected to after they approved the application, such as
+    /// `http://localhost/callback?code=...&state=...`. The code and the state are extracted from the URL's query and
+    /// the client is [finalized](Self::finalize) with them.
+    ///
+    /// If the user denied the authorization, an [AuthorizationDenied-error](Error::AuthorizationDenied) is returned. If
+    /// the URL can't be parsed or it doesn't contain both the code and the state, an
+    /// [InvalidAuthorizationCode-error](Error::InvalidAuthorizationCode) is returned.
+    pub async fn finalize_from_redirect_url(self, redirect_url: &str) -> Result<AsyncAuthorizationCodeUserClient> {
+        let callback = AuthorizationCallback::from_redirect_url(redirect_url)?;
+        self.finalize(&callback.code, &callback.state).await
+    }
+
+    /// Finalize this client by opening the [authorization URL](IncompleteAuthorizationCodeUserClient::get_authorize_url)
+    /// in the user's browser and receiving the callback with a local HTTP server listening on the given port. The
+    /// client's redirect URI should point to the server, for example `http://127.0.0.1:8888/callback`.
+    ///
+    /// The callback's state is validated like in the [`finalize`-function](Self::finalize). If the user denies the
+    /// authorization, an [AuthorizationDenied-error](Error::AuthorizationDenied) is returned, and if the callback isn't
+    /// received within the given timeout, a [RedirectTimeout-error](Error::RedirectTimeout) is returned.
+    ///
+    /// The server is run in a separate thread, so this function doesn't depend on any particular async runtime.
+    #[cfg(feature = "redirect-server")]
+    pub async fn finalize_via_local_server(
+        self,
+        port: u16,
+        timeout: Duration,
+    ) -> Result<AsyncAuthorizationCodeUserClient> {
+        let callback = super::redirect_server::receive_callback_async(
+            self.get_authorize_url(),
+            self.redirect_uri.clone(),
+            port,
+            timeout,
+        )
+        .await?;
+
+        self.finalize(&callback.code, &callback.state).await
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -486,6 +730,9 @@ impl SyncIncompleteAuthorizationCodeUserClient {
     /// This function will use the authorization code to request an access and a refresh token from Spotify. If the
     /// originally generated state does not match the `state` parameter, the function will return an
     /// [AuthorizationCodeStateMismatch-error](Error::AuthorizationCodeStateMismatch).
+    ///
+    /// The scopes the user actually granted are available from the finalized client's
+    /// [`granted_scopes`-function](AuthorizationCodeUserClient::granted_scopes) right away.
     pub fn finalize(self, code: &str, state: &str) -> Result<SyncAuthorizationCodeUserClient> {
         let token_request_form = self.build_authorization_code_token_request_form(code, state)?;
         let response = self
@@ -499,6 +746,31 @@ impl SyncIncompleteAuthorizationCodeUserClient {
 
         Ok(self.build_client(token_response))
     }
+
+    /// Finalize this client with the full URL the user was redirected to after they approved the application, such as
+    /// `http://localhost/callback?code=...&state=...`.
+    ///
+    /// See [the async version](AsyncIncompleteAuthorizationCodeUserClient::finalize_from_redirect_url) for details.
+    pub fn finalize_from_redirect_url(self, redirect_url: &str) -> Result<SyncAuthorizationCodeUserClient> {
+        let callback = AuthorizationCallback::from_redirect_url(redirect_url)?;
+        self.finalize(&callback.code, &callback.state)
+    }
+
+    /// Finalize this client by opening the [authorization URL](IncompleteAuthorizationCodeUserClient::get_authorize_url)
+    /// in the user's browser and receiving the callback with a local HTTP server listening on the given port. The
+    /// client's redirect URI should point to the server, for example `http://127.0.0.1:8888/callback`.
+    ///
+    /// The callback's state is validated like in the [`finalize`-function](Self::finalize). If the user denies the
+    /// authorization, an [AuthorizationDenied-error](Error::AuthorizationDenied) is returned, and if the callback isn't
+    /// received within the given timeout, a [RedirectTimeout-error](Error::RedirectTimeout) is returned.
+    ///
+    /// This function blocks until the callback is received or the timeout elapses.
+    #[cfg(feature = "redirect-server")]
+    pub fn finalize_via_local_server(self, port: u16, timeout: Duration) -> Result<SyncAuthorizationCodeUserClient> {
+        let callback =
+            super::redirect_server::receive_callback(&self.get_authorize_url(), &self.redirect_uri, port, timeout)?;
+        self.finalize(&callback.code, &callback.state)
+    }
 }
 
 #[cfg(feature = "async")]
@@ -509,7 +781,9 @@ impl AsyncAuthorizationCodeUserClientBuilder {
             redirect_uri,
             scopes: None,
             show_dialog: false,
+            extra_query_params: Vec::new(),
             pkce_verifier: None,
+            on_token_refresh: None,
 
             http_client,
         }
@@ -524,7 +798,9 @@ impl SyncAuthorizationCodeUserClientBuilder {
             redirect_uri,
             scopes: None,
             show_dialog: false,
+            extra_query_params: Vec::new(),
             pkce_verifier: None,
+            on_token_refresh: None,
 
             http_client,
         }
@@ -582,6 +858,32 @@ where
         Self { show_dialog, ..self }
     }
 
+    /// Add an extra query parameter to the [authorize URL](IncompleteAuthorizationCodeUserClient::get_authorize_url).
+    /// This allows passing parameters Spotify supports that this builder doesn't know of. Parameters the client sets
+    /// itself, such as `state` or `scope`, cannot be overridden and are ignored with a warning.
+    pub fn extra_query_param<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.extra_query_params.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set a callback that is invoked with the new refresh token whenever Spotify issues one when the client's access
+    /// token is refreshed, either with the `refresh_access_token` function or automatically. Spotify may rotate the
+    /// refresh token at any refresh, so an application storing the refresh token should use this callback to save the
+    /// new one. Clients created from an existing refresh token are given the callback when they are created instead.
+    pub fn on_token_refresh<F>(self, on_token_refresh: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            on_token_refresh: Some(TokenRefreshCallback(Arc::new(on_token_refresh))),
+            ..self
+        }
+    }
+
     /// Finalize the builder and return an [IncompleteAuthorizationCodeUserClient].
     pub fn build(self) -> IncompleteAuthorizationCodeUserClient<C> {
         let state = rand::thread_rng()
@@ -595,8 +897,10 @@ where
             state,
             scopes: self.scopes,
             show_dialog: self.show_dialog,
+            extra_query_params: self.extra_query_params,
             client_id: self.client_id,
             pkce_verifier: self.pkce_verifier,
+            on_token_refresh: self.on_token_refresh,
 
             http_client: self.http_client,
         }
@@ -605,6 +909,16 @@ where
 
 impl<C> crate::private::Sealed for AuthorizationCodeUserClient<C> where C: private::HttpClient + Clone {}
 
+impl<C> private::TrackCacheAccess for AuthorizationCodeUserClient<C>
+where
+    C: private::HttpClient + Clone,
+{
+    #[cfg(feature = "cache")]
+    fn track_cache(&self) -> Option<&crate::client::cache::TrackCache> {
+        self.http_client.track_cache()
+    }
+}
+
 #[cfg(feature = "async")]
 impl private::BuildHttpRequestAsync for AsyncAuthorizationCodeUserClient {
     fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
@@ -614,6 +928,30 @@ impl private::BuildHttpRequestAsync for AsyncAuthorizationCodeUserClient {
         let access_token = self.inner.access_token.read().expect("access token rwlock poisoned");
         self.http_client.request(method, url).bearer_auth(access_token.as_str())
     }
+
+    fn api_base_url(&self) -> &Url {
+        self.http_client.api_base_url()
+    }
+
+    fn rate_limit_sleeper(&self) -> &dyn AsyncRateLimitSleeper {
+        self.http_client.rate_limit_sleeper()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.http_client.rate_limiter()
+    }
+
+    fn rate_limit_policy(&self) -> private::RateLimitPolicy {
+        self.http_client.rate_limit_policy()
+    }
+
+    fn deserialization_mode(&self) -> DeserializationMode {
+        self.http_client.deserialization_mode()
+    }
+
+    fn ensure_scope(&self, scope: Scope) -> Result<()> {
+        AuthorizationCodeUserClient::ensure_scope(self, scope)
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -625,6 +963,30 @@ impl private::BuildHttpRequestSync for SyncAuthorizationCodeUserClient {
         let access_token = self.inner.access_token.read().expect("access token rwlock poisoned");
         self.http_client.request(method, url).bearer_auth(access_token.as_str())
     }
+
+    fn api_base_url(&self) -> &Url {
+        self.http_client.api_base_url()
+    }
+
+    fn rate_limit_sleeper(&self) -> &dyn SyncRateLimitSleeper {
+        self.http_client.rate_limit_sleeper()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.http_client.rate_limiter()
+    }
+
+    fn rate_limit_policy(&self) -> private::RateLimitPolicy {
+        self.http_client.rate_limit_policy()
+    }
+
+    fn deserialization_mode(&self) -> DeserializationMode {
+        self.http_client.deserialization_mode()
+    }
+
+    fn ensure_scope(&self, scope: Scope) -> Result<()> {
+        AuthorizationCodeUserClient::ensure_scope(self, scope)
+    }
 }
 
 #[cfg(feature = "async")]
@@ -646,10 +1008,7 @@ impl super::AccessTokenRefreshAsync for AsyncAuthorizationCodeUserClient {
         // build and send the request this way to not hold the non-async RwLockReadGuard across await points
         let response = {
             let refresh_token = self.inner.refresh_token.read().expect("refresh token rwlock poisoned");
-            debug!(
-                "Attempting to refresh authorization code flow access token with refresh token: {}",
-                refresh_token
-            );
+            debug!("Attempting to refresh authorization code flow access token");
 
             // build the HTTP request straight from the client so it'll use the client credentials authorization header
             // instead of the access token
@@ -684,10 +1043,7 @@ impl super::AccessTokenRefreshAsync for AsyncAuthorizationCodeUserClient {
 impl super::AccessTokenRefreshSync for SyncAuthorizationCodeUserClient {
     fn refresh_access_token(&self) -> Result<()> {
         let refresh_token = self.inner.refresh_token.read().expect("refresh token rwlock poisoned");
-        debug!(
-            "Attempting to refresh authorization code flow access token with refresh token: {}",
-            refresh_token
-        );
+        debug!("Attempting to refresh authorization code flow access token");
 
         // build the HTTP request straight from the client so it'll use the client credentials authorization header
         // instead of the access token
@@ -753,3 +1109,190 @@ fn map_refresh_token_error(err: Error) -> Error {
         err
     }
 }
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use reqwest::header::HeaderMap;
+
+    use super::*;
+    use crate::client::rate_limit::DefaultRateLimitSleeper;
+
+    fn refresh_token_response(refresh_token: Option<&str>) -> RefreshUserTokenResponse {
+        RefreshUserTokenResponse {
+            access_token: "new access token".to_owned(),
+            refresh_token: refresh_token.map(str::to_owned),
+            expires_in: 3600,
+            scope: None,
+            token_type: "Bearer".to_owned(),
+        }
+    }
+
+    #[test]
+    fn token_refresh_callback_is_invoked_with_new_refresh_token() {
+        let refresh_tokens = Arc::new(Mutex::new(Vec::new()));
+        let callback_refresh_tokens = Arc::clone(&refresh_tokens);
+
+        let client = AuthorizationCodeUserClient {
+            inner: Arc::new(AuthorizationCodeUserClientRef {
+                access_token: RwLock::new("access token".to_owned()),
+                access_token_expires_at: RwLock::new(Instant::now()),
+                refresh_token: RwLock::new("refresh token".to_owned()),
+                granted_scopes: RwLock::new(Vec::new()),
+                client_id: None,
+                on_token_refresh: Some(TokenRefreshCallback(Arc::new(move |refresh_token: &str| {
+                    callback_refresh_tokens.lock().unwrap().push(refresh_token.to_owned())
+                }))),
+            }),
+            http_client: AsyncClient::new(None, HeaderMap::new(), Arc::new(DefaultRateLimitSleeper)),
+        };
+
+        client.update_access_and_refresh_tokens(refresh_token_response(None));
+        client.update_access_and_refresh_tokens(refresh_token_response(Some("new refresh token")));
+
+        assert_eq!(*refresh_tokens.lock().unwrap(), ["new refresh token"]);
+        assert_eq!(client.get_refresh_token(), "new refresh token");
+    }
+
+    #[test]
+    fn next_refresh_instant_is_before_access_token_expiry() {
+        let client = AuthorizationCodeUserClient {
+            inner: Arc::new(AuthorizationCodeUserClientRef {
+                access_token: RwLock::new("access token".to_owned()),
+                access_token_expires_at: RwLock::new(Instant::now()),
+                refresh_token: RwLock::new("refresh token".to_owned()),
+                granted_scopes: RwLock::new(Vec::new()),
+                client_id: None,
+                on_token_refresh: None,
+            }),
+            http_client: AsyncClient::new(None, HeaderMap::new(), Arc::new(DefaultRateLimitSleeper)),
+        };
+
+        let before = Instant::now();
+        client.update_access_and_refresh_tokens(refresh_token_response(None));
+        let after = Instant::now();
+
+        let expires_at = *client.inner.access_token_expires_at.read().unwrap();
+        let next_refresh = client.next_refresh_instant().unwrap();
+
+        assert_eq!(next_refresh, expires_at - ACCESS_TOKEN_REFRESH_MARGIN);
+        assert!(next_refresh >= before + Duration::from_secs(3600) - ACCESS_TOKEN_REFRESH_MARGIN);
+        assert!(next_refresh <= after + Duration::from_secs(3600) - ACCESS_TOKEN_REFRESH_MARGIN);
+    }
+
+    #[test]
+    fn debug_output_redacts_tokens() {
+        let client_ref = AuthorizationCodeUserClientRef {
+            access_token: RwLock::new("hunter2".to_owned()),
+            access_token_expires_at: RwLock::new(Instant::now()),
+            refresh_token: RwLock::new("hunter2".to_owned()),
+            granted_scopes: RwLock::new(Vec::new()),
+            client_id: None,
+            on_token_refresh: None,
+        };
+        let token_response = RefreshUserTokenResponse {
+            access_token: "hunter2".to_owned(),
+            refresh_token: Some("hunter2".to_owned()),
+            ..refresh_token_response(None)
+        };
+
+        for debug in [format!("{client_ref:?}"), format!("{token_response:?}")] {
+            assert!(!debug.contains("hunter2"), "{debug}");
+            assert!(debug.contains(r#"access_token: "***""#), "{debug}");
+        }
+    }
+
+    #[test]
+    fn granted_scopes_are_updated_when_refresh_response_includes_them() {
+        let client = AuthorizationCodeUserClient {
+            inner: Arc::new(AuthorizationCodeUserClientRef {
+                access_token: RwLock::new("access token".to_owned()),
+                access_token_expires_at: RwLock::new(Instant::now()),
+                refresh_token: RwLock::new("refresh token".to_owned()),
+                granted_scopes: RwLock::new(vec![Scope::UserReadEmail]),
+                client_id: None,
+                on_token_refresh: None,
+            }),
+            http_client: AsyncClient::new(None, HeaderMap::new(), Arc::new(DefaultRateLimitSleeper)),
+        };
+
+        client.update_access_and_refresh_tokens(refresh_token_response(None));
+        assert_eq!(client.granted_scopes(), [Scope::UserReadEmail]);
+        assert!(matches!(
+            client.ensure_scope(Scope::UserModifyPlaybackState),
+            Err(Error::MissingScope)
+        ));
+
+        client.update_access_and_refresh_tokens(RefreshUserTokenResponse {
+            scope: Some("user-modify-playback-state user-read-email".to_owned()),
+            ..refresh_token_response(None)
+        });
+        assert_eq!(
+            client.granted_scopes(),
+            [Scope::UserModifyPlaybackState, Scope::UserReadEmail]
+        );
+        assert!(client.ensure_scope(Scope::UserModifyPlaybackState).is_ok());
+    }
+
+    #[test]
+    fn callback_is_parsed_from_redirect_url() {
+        assert_eq!(
+            AuthorizationCallback::from_redirect_url("http://localhost/callback?code=abc&state=xyz").unwrap(),
+            AuthorizationCallback {
+                code: String::from("abc"),
+                state: String::from("xyz")
+            }
+        );
+
+        assert!(matches!(
+            AuthorizationCallback::from_redirect_url("http://localhost/callback?error=access_denied&state=xyz"),
+            Err(Error::AuthorizationDenied(error)) if error == "access_denied"
+        ));
+        assert!(matches!(
+            AuthorizationCallback::from_redirect_url("http://localhost/callback?code=abc"),
+            Err(Error::InvalidAuthorizationCode)
+        ));
+        assert!(matches!(
+            AuthorizationCallback::from_redirect_url("code=abc&state=xyz"),
+            Err(Error::InvalidAuthorizationCode)
+        ));
+    }
+
+    #[test]
+    fn authorize_url_omits_empty_scopes() {
+        let spotify_client = crate::client::SpotifyClientBuilder::new("client id").build_async();
+
+        let with_scopes = spotify_client
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .scopes([Scope::UserReadEmail, Scope::Streaming])
+            .build()
+            .get_authorize_url();
+        let without_scopes = spotify_client
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .scopes([])
+            .build()
+            .get_authorize_url();
+
+        assert!(with_scopes.contains("scope=user-read-email+streaming"));
+        assert!(!without_scopes.contains("scope="));
+    }
+
+    #[test]
+    fn authorize_url_includes_extra_query_params_without_overriding() {
+        let authorize_url = crate::client::SpotifyClientBuilder::new("client id")
+            .build_async()
+            .authorization_code_client_with_pkce("http://localhost/callback")
+            .extra_query_param("locale", "fi")
+            .extra_query_param("client_id", "other client id")
+            .build()
+            .get_authorize_url();
+        let authorize_url = Url::parse(&authorize_url).unwrap();
+
+        let query_params: Vec<_> = authorize_url.query_pairs().collect();
+        assert!(query_params.contains(&("locale".into(), "fi".into())));
+        assert!(query_params.contains(&("code_challenge_method".into(), "S256".into())));
+        assert_eq!(query_params.iter().filter(|(key, _)| key == "client_id").count(), 1);
+        assert!(query_params.contains(&("client_id".into(), "client id".into())));
+    }
+}
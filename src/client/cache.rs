@@ -0,0 +1,279 @@
+//! Contains the optional in-memory cache for catalog tracks.
+//!
+//! Track availability and [relinking](crate::model::track#track-equality-and-track-relinking) differ per market, so
+//! resolving the same tracks repeatedly for different markets is wasteful. A [TrackCache] stores the tracks retrieved
+//! with the [`track`](crate::client::UnscopedClient::track) and [`tracks`](crate::client::UnscopedClient::tracks)
+//! functions, keyed by the track's Spotify ID and the market the track was requested for. When a cache is given to the
+//! client builder with the [`track_cache`-function](super::SpotifyClientBuilder::track_cache), the functions consult
+//! the cache transparently before sending a request to Spotify. Clients don't cache anything by default. Tracks
+//! requested for the [market of the access token's user](crate::model::Market::FromToken) bypass the cache, since the
+//! market depends on the user.
+//!
+//! The cache is shared between the client and every client created from it, such as user clients. Keep a clone of the
+//! [Arc](std::sync::Arc) given to the client builder in order to retrieve the cache's
+//! [statistics](TrackCache::stats) or to [clear](TrackCache::clear) it.
+//!
+//! ```no_run
+//! # use std::{sync::Arc, time::Duration};
+//! # use ferrispot::client::{cache::TrackCache, SpotifyClientBuilder};
+//! let cache = Arc::new(TrackCache::new(Duration::from_secs(60 * 60), 1000));
+//! let client = SpotifyClientBuilder::new("...").track_cache(Arc::clone(&cache));
+//!
+//! // ...
+//!
+//! println!("Cache hits: {}, misses: {}", cache.stats().hits, cache.stats().misses);
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::model::{
+    id::{Id, IdTrait, TrackId},
+    track::{FullTrack, NonLocalTrackInformation},
+};
+
+/// The cache key; a track's Spotify ID and the market it was requested for, if any.
+type CacheKey = (String, Option<String>);
+
+/// An in-memory cache for catalog tracks with a time-to-live and a maximum size.
+///
+/// Entries older than the cache's time-to-live are considered expired and are not returned from the cache. When the
+/// cache is full, expired entries are removed first, and then the oldest entry.
+#[derive(Debug)]
+pub struct TrackCache {
+    ttl: Duration,
+    max_size: usize,
+    entries: Mutex<HashMap<CacheKey, CacheEntry>>,
+    insertions: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Statistics about how many tracks have been retrieved from a [TrackCache].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    /// How many tracks have been found in the cache.
+    pub hits: u64,
+    /// How many tracks were not found in the cache, or had expired.
+    pub misses: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    inserted: Instant,
+    // Instants may compare equal when inserted in quick succession, so use a counter to find the oldest entry
+    insertion: u64,
+    track: FullTrack,
+}
+
+impl TrackCache {
+    /// Return a new empty track cache. Cached tracks expire after `ttl`, and at most `max_size` tracks are stored at
+    /// once.
+    pub fn new(ttl: Duration, max_size: usize) -> Self {
+        Self {
+            ttl,
+            max_size,
+            entries: Mutex::new(HashMap::new()),
+            insertions: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Return the cache's hit and miss statistics.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Return the number of tracks currently stored in the cache, including expired tracks that haven't been removed
+    /// yet.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Return whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Remove every track from the cache. The cache's statistics are kept.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    /// Return a cached track for the given track ID and market, if it exists and hasn't expired.
+    pub(crate) fn get(&self, track: &Id<'_, TrackId>, market: Option<&str>) -> Option<FullTrack> {
+        let mut entries = self.entries();
+        let track = self.get_unexpired(&mut entries, track, market);
+        self.record(track.is_some());
+
+        track
+    }
+
+    /// Return cached tracks for all the given track IDs and market, in the same order, only if every track is cached.
+    pub(crate) fn get_all(&self, tracks: &[Id<'_, TrackId>], market: Option<&str>) -> Option<Vec<FullTrack>> {
+        let mut entries = self.entries();
+
+        // look up every track, even after a miss, so the statistics reflect every requested track
+        let found: Vec<_> = tracks
+            .iter()
+            .map(|track| {
+                let track = self.get_unexpired(&mut entries, track, market);
+                self.record(track.is_some());
+                track
+            })
+            .collect();
+
+        found.into_iter().collect()
+    }
+
+    /// Store a track in the cache for the given market. If the track was [relinked](crate::model::track) from another
+    /// track, it is stored under the original track's ID, since that's the ID it was requested with.
+    pub(crate) fn insert(&self, track: &FullTrack, market: Option<&str>) {
+        if self.max_size == 0 {
+            return;
+        }
+
+        let key = (track.original_id().as_str().to_owned(), market.map(str::to_owned));
+
+        let mut entries = self.entries();
+
+        if !entries.contains_key(&key) && entries.len() >= self.max_size {
+            let ttl = self.ttl;
+            entries.retain(|_, entry| entry.inserted.elapsed() < ttl);
+
+            if entries.len() >= self.max_size {
+                if let Some(oldest) = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.insertion)
+                    .map(|(key, _)| key.clone())
+                {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+
+        entries.insert(
+            key,
+            CacheEntry {
+                inserted: Instant::now(),
+                insertion: self.insertions.fetch_add(1, Ordering::Relaxed),
+                track: track.clone(),
+            },
+        );
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, CacheEntry>> {
+        // the entries are never left in an inconsistent state, so a poisoned lock is fine to use
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn get_unexpired(
+        &self,
+        entries: &mut HashMap<CacheKey, CacheEntry>,
+        track: &Id<'_, TrackId>,
+        market: Option<&str>,
+    ) -> Option<FullTrack> {
+        let key = (track.as_str().to_owned(), market.map(str::to_owned));
+
+        match entries.get(&key) {
+            Some(entry) if entry.inserted.elapsed() < self.ttl => Some(entry.track.clone()),
+
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+
+            None => None,
+        }
+    }
+
+    fn record(&self, hit: bool) {
+        if hit {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{id::IdFromBare, test_responses::SEVERAL_TRACKS_RESPONSE};
+
+    /// Returns the track with the given ID from the several tracks response.
+    fn track(id: &str) -> FullTrack {
+        let response: serde_json::Value = serde_json::from_str(SEVERAL_TRACKS_RESPONSE).unwrap();
+        let track = response["tracks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|track| track["id"] == id)
+            .unwrap();
+
+        serde_json::from_value(track.clone()).unwrap()
+    }
+
+    fn id(id: &str) -> Id<'_, TrackId> {
+        Id::from_bare(id).unwrap()
+    }
+
+    #[test]
+    fn cached_tracks_are_separated_by_market() {
+        let cache = TrackCache::new(Duration::from_secs(60), 10);
+        cache.insert(&track("2pDPOMX0kWA7kcPBcDCQBu"), Some("FI"));
+
+        assert!(cache.get(&id("2pDPOMX0kWA7kcPBcDCQBu"), Some("FI")).is_some());
+        assert!(cache.get(&id("2pDPOMX0kWA7kcPBcDCQBu"), Some("SE")).is_none());
+        assert!(cache.get(&id("2pDPOMX0kWA7kcPBcDCQBu"), None).is_none());
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 2 });
+    }
+
+    #[test]
+    fn all_tracks_have_to_be_cached() {
+        let cache = TrackCache::new(Duration::from_secs(60), 10);
+        cache.insert(&track("2pDPOMX0kWA7kcPBcDCQBu"), None);
+
+        let ids = [id("2pDPOMX0kWA7kcPBcDCQBu"), id("0c6xIDDpzE81m2q797ordA")];
+        assert!(cache.get_all(&ids, None).is_none());
+        assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1 });
+
+        cache.insert(&track("0c6xIDDpzE81m2q797ordA"), None);
+        let tracks = cache.get_all(&ids, None).unwrap();
+        assert_eq!(tracks[1].id().as_str(), "0c6xIDDpzE81m2q797ordA");
+    }
+
+    #[test]
+    fn expired_tracks_are_not_returned() {
+        let cache = TrackCache::new(Duration::ZERO, 10);
+        cache.insert(&track("2pDPOMX0kWA7kcPBcDCQBu"), None);
+
+        assert!(cache.get(&id("2pDPOMX0kWA7kcPBcDCQBu"), None).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn oldest_track_is_evicted_when_full() {
+        let cache = TrackCache::new(Duration::from_secs(60), 2);
+        cache.insert(&track("2pDPOMX0kWA7kcPBcDCQBu"), None);
+        cache.insert(&track("0c6xIDDpzE81m2q797ordA"), None);
+        cache.insert(&track("4iV5W9uYEdYUVa79Axb7Rh"), None);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&id("2pDPOMX0kWA7kcPBcDCQBu"), None).is_none());
+        assert!(cache.get(&id("4iV5W9uYEdYUVa79Axb7Rh"), None).is_some());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
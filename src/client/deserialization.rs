@@ -0,0 +1,559 @@
+//! Contains the [DeserializationMode] that controls how strictly Spotify's API responses are deserialized.
+//!
+//! By default, clients deserialize responses [leniently](DeserializationMode::Lenient): any fields in the response the
+//! library doesn't know about are ignored. In [strict](DeserializationMode::Strict) mode, a response that contains
+//! fields the library's model doesn't cover is rejected with an
+//! [UnexpectedResponseFields](crate::error::Error::UnexpectedResponseFields)-error that lists the unknown fields.
+//! Strict mode is useful in, for example, a CI pipeline to notice early when Spotify changes its API. The mode is set
+//! with the [`deserialization_mode`-function](super::SpotifyClientBuilder::deserialization_mode) in the client builder.
+//!
+//! The model leaves out certain redundant fields on purpose, such as the API links (`href`) and URIs (`uri`) of
+//! objects. Those fields are never considered unknown within the kinds of objects they're left out of.
+//!
+//! Unknown fields are detected while the response is deserialized, by recording the fields the model ignores. Most of
+//! the model's objects, such as tracks, albums, artists, playlists and users, are built from several flattened parts,
+//! and serde collects the fields of flattened objects without telling which of them were used. The fields of these
+//! objects, the pages they're listed in and the objects within them, such as images and followers, are therefore also
+//! checked against the fields the model knows for each kind of object.
+//!
+//! # Performance
+//!
+//! Strict mode is slightly slower than lenient mode, since the response body is parsed into a JSON value in full
+//! before it is deserialized, and the path to each ignored field is tracked during deserialization. The difference is
+//! rarely significant, but lenient mode is recommended for production use.
+//!
+//! ```no_run
+//! # use ferrispot::client::{deserialization::DeserializationMode, SpotifyClientBuilder};
+//! let client = SpotifyClientBuilder::new("...")
+//!     .deserialization_mode(DeserializationMode::Strict)
+//!     .build_async();
+//! ```
+
+use std::collections::BTreeSet;
+
+use serde::de::DeserializeOwned;
+use serde_ignored::Path;
+use serde_json::{Map, Value};
+
+use crate::error::{Error, Result};
+
+/// The fields of one kind of object in Spotify's responses.
+pub(crate) struct ObjectFields {
+    /// The fields the library's model covers.
+    pub(crate) known: &'static [&'static str],
+    /// The fields the library's model leaves out on purpose. They are never considered unknown in strict mode. The
+    /// model's tests check that every field the model strips from an object is listed here.
+    pub(crate) omitted: &'static [&'static str],
+}
+
+const ALBUM_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "album_type",
+        "artists",
+        "available_markets",
+        "copyrights",
+        "external_ids",
+        "external_urls",
+        "genres",
+        "id",
+        "images",
+        "label",
+        "name",
+        "popularity",
+        "release_date",
+        "release_date_precision",
+        "restrictions",
+        "tracks",
+        "type",
+    ],
+    omitted: &["href", "uri", "total_tracks", "album_group"],
+};
+
+const ARTIST_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "external_urls",
+        "followers",
+        "genres",
+        "id",
+        "images",
+        "name",
+        "popularity",
+        "type",
+    ],
+    omitted: &["href", "uri"],
+};
+
+const AUDIO_FEATURES_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "acousticness",
+        "danceability",
+        "duration_ms",
+        "energy",
+        "id",
+        "instrumentalness",
+        "key",
+        "liveness",
+        "loudness",
+        "mode",
+        "speechiness",
+        "tempo",
+        "time_signature",
+        "valence",
+    ],
+    omitted: &["type", "uri", "track_href", "analysis_url"],
+};
+
+const EPISODE_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "audio_preview_url",
+        "description",
+        "duration_ms",
+        "explicit",
+        "external_urls",
+        "html_description",
+        "id",
+        "images",
+        "is_externally_hosted",
+        "is_playable",
+        "languages",
+        "name",
+        "release_date",
+        "release_date_precision",
+        "restrictions",
+        "resume_point",
+        "show",
+        "type",
+    ],
+    // the deprecated language-field is superseded by the languages-field
+    omitted: &["href", "uri", "language"],
+};
+
+const PLAYLIST_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "collaborative",
+        "description",
+        "external_urls",
+        "followers",
+        "id",
+        "images",
+        "name",
+        "owner",
+        "primary_color",
+        "public",
+        "snapshot_id",
+        "tracks",
+        "type",
+    ],
+    omitted: &["href", "uri"],
+};
+
+const SHOW_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "available_markets",
+        "copyrights",
+        "description",
+        "episodes",
+        "explicit",
+        "external_urls",
+        "html_description",
+        "id",
+        "images",
+        "is_externally_hosted",
+        "languages",
+        "media_type",
+        "name",
+        "publisher",
+        "total_episodes",
+        "type",
+    ],
+    omitted: &["href", "uri"],
+};
+
+const TRACK_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "album",
+        "artists",
+        "available_markets",
+        "disc_number",
+        "duration_ms",
+        "explicit",
+        "external_ids",
+        "external_urls",
+        "id",
+        "is_local",
+        "is_playable",
+        "linked_from",
+        "name",
+        "popularity",
+        "preview_url",
+        "restrictions",
+        "track_number",
+        "type",
+    ],
+    omitted: &["href", "uri"],
+};
+
+const USER_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "country",
+        "display_name",
+        "email",
+        "explicit_content",
+        "external_urls",
+        "followers",
+        "id",
+        "images",
+        "product",
+        "type",
+    ],
+    omitted: &["href", "uri"],
+};
+
+const RECOMMENDATION_SEED_FIELDS: ObjectFields = ObjectFields {
+    known: &[
+        "afterFilteringSize",
+        "afterRelinkingSize",
+        "id",
+        "initialPoolSize",
+        "type",
+    ],
+    omitted: &["href"],
+};
+
+const PAGE_FIELDS: ObjectFields = ObjectFields {
+    known: &["cursors", "items", "limit", "next", "offset", "previous", "total"],
+    omitted: &["href"],
+};
+
+const COPYRIGHT_FIELDS: ObjectFields = ObjectFields {
+    known: &["text", "type"],
+    omitted: &[],
+};
+
+const CURSORS_FIELDS: ObjectFields = ObjectFields {
+    known: &["after", "before"],
+    omitted: &[],
+};
+
+const EXPLICIT_CONTENT_FIELDS: ObjectFields = ObjectFields {
+    known: &["filter_enabled", "filter_locked"],
+    omitted: &[],
+};
+
+const EXTERNAL_IDS_FIELDS: ObjectFields = ObjectFields {
+    known: &["ean", "isrc", "upc"],
+    omitted: &[],
+};
+
+const EXTERNAL_URLS_FIELDS: ObjectFields = ObjectFields {
+    known: &["spotify"],
+    omitted: &[],
+};
+
+const FOLLOWERS_FIELDS: ObjectFields = ObjectFields {
+    known: &["total"],
+    // the API documents a href-field for followers but says it's always null
+    omitted: &["href"],
+};
+
+const IMAGE_FIELDS: ObjectFields = ObjectFields {
+    known: &["height", "url", "width"],
+    omitted: &[],
+};
+
+const RESTRICTIONS_FIELDS: ObjectFields = ObjectFields {
+    known: &["reason"],
+    omitted: &[],
+};
+
+const RESUME_POINT_FIELDS: ObjectFields = ObjectFields {
+    known: &["fully_played", "resume_position_ms"],
+    omitted: &[],
+};
+
+/// How strictly Spotify's API responses are deserialized. See the [module-level docs](self) for more information.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DeserializationMode {
+    /// Fields in the response the library doesn't know about are ignored. This is the default.
+    #[default]
+    Lenient,
+    /// Responses that contain fields the library doesn't know about are rejected.
+    Strict,
+}
+
+/// Find out what kind of object the given object in a response is, and return its fields. Objects are recognised by
+/// their `type`-field, or if they don't have one, by the field they're in or by being a page of items. `field` is the
+/// name of the field the object is in, or the field of the array the object is in.
+pub(crate) fn object_fields(field: Option<&str>, object: &Map<String, Value>) -> Option<&'static ObjectFields> {
+    let fields = match object.get("type").and_then(Value::as_str) {
+        Some("album") => &ALBUM_FIELDS,
+        Some("artist") => &ARTIST_FIELDS,
+        Some("audio_features") => &AUDIO_FEATURES_FIELDS,
+        Some("episode") => &EPISODE_FIELDS,
+        Some("playlist") => &PLAYLIST_FIELDS,
+        Some("show") => &SHOW_FIELDS,
+        Some("track") => &TRACK_FIELDS,
+        Some("user") => &USER_FIELDS,
+        Some("ARTIST" | "GENRE" | "TRACK") => &RECOMMENDATION_SEED_FIELDS,
+        _ if object.contains_key("items") => &PAGE_FIELDS,
+
+        _ => match field? {
+            "copyrights" => &COPYRIGHT_FIELDS,
+            "cursors" => &CURSORS_FIELDS,
+            "explicit_content" => &EXPLICIT_CONTENT_FIELDS,
+            "external_ids" => &EXTERNAL_IDS_FIELDS,
+            "external_urls" => &EXTERNAL_URLS_FIELDS,
+            "followers" => &FOLLOWERS_FIELDS,
+            "images" => &IMAGE_FIELDS,
+            "restrictions" => &RESTRICTIONS_FIELDS,
+            "resume_point" => &RESUME_POINT_FIELDS,
+            // a playlist's tracks are only referenced in place of the page when the playlist is listed among others
+            "tracks" => &PAGE_FIELDS,
+            _ => return None,
+        },
+    };
+
+    Some(fields)
+}
+
+/// Deserialize a response body into a model object, failing if the body contains fields the model doesn't cover.
+pub(crate) fn deserialize_strict<T>(body: &str) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let response: Value = serde_json::from_str(body)?;
+    deserialize_value(&response, DeserializationMode::Strict)
+}
+
+/// Deserialize an already parsed response into a model object according to the given deserialization mode.
+pub(crate) fn deserialize_value<T>(response: &Value, deserialization_mode: DeserializationMode) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    match deserialization_mode {
+        DeserializationMode::Lenient => Ok(T::deserialize(response)?),
+        DeserializationMode::Strict => deserialize_tracking_unknown_fields(response),
+    }
+}
+
+/// Deserialize a model object from the given response, failing if the response contains fields the model doesn't
+/// cover.
+///
+/// The fields the model ignores are recorded during deserialization, and those other than the fields [omitted](
+/// ObjectFields::omitted) from the object they're in are unknown. Since serde doesn't tell which fields of flattened
+/// objects it ignored, the fields of every object whose kind is recognised are also checked against the object's
+/// [known fields](ObjectFields::known).
+fn deserialize_tracking_unknown_fields<T>(response: &Value) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let mut unknown_fields = BTreeSet::new();
+    let object = serde_ignored::deserialize(response, |path| {
+        if !is_omitted(response, &path) {
+            unknown_fields.insert(field_path(&path));
+        }
+    })?;
+
+    collect_unknown_fields(response, None, "", &mut unknown_fields);
+
+    if unknown_fields.is_empty() {
+        Ok(object)
+    } else {
+        Err(Error::UnexpectedResponseFields(unknown_fields.into_iter().collect()))
+    }
+}
+
+/// Check whether the ignored field at the given path is omitted from the object it's in.
+fn is_omitted(response: &Value, path: &Path) -> bool {
+    let Path::Map { parent, key } = path else {
+        return false;
+    };
+
+    match value_at(response, parent) {
+        Some((field, Value::Object(object))) => {
+            object_fields(field, object).is_some_and(|fields| fields.omitted.contains(&key.as_str()))
+        }
+
+        _ => false,
+    }
+}
+
+/// Return the value at the given path in a response, along with the name of the field it's in.
+fn value_at<'a>(response: &'a Value, path: &Path) -> Option<(Option<&'a str>, &'a Value)> {
+    match path {
+        Path::Root => Some((None, response)),
+        Path::Seq { parent, index } => {
+            let (field, value) = value_at(response, parent)?;
+            Some((field, value.get(index)?))
+        }
+        Path::Map { parent, key } => {
+            let (_, value) = value_at(response, parent)?;
+            let (key, value) = value.as_object()?.get_key_value(key)?;
+            Some((Some(key.as_str()), value))
+        }
+        Path::Some { parent } | Path::NewtypeStruct { parent } | Path::NewtypeVariant { parent } => {
+            value_at(response, parent)
+        }
+    }
+}
+
+/// Collect the paths to the fields in the given value that aren't known or omitted in the object they're in. Objects
+/// whose kind isn't recognised are left to serde to check.
+fn collect_unknown_fields(value: &Value, field: Option<&str>, path: &str, unknown_fields: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(object) => {
+            let fields = object_fields(field, object);
+
+            for (key, value) in object {
+                let field_path = if path.is_empty() {
+                    key.to_owned()
+                } else {
+                    format!("{path}.{key}")
+                };
+
+                match fields {
+                    Some(fields) if fields.omitted.contains(&key.as_str()) => {}
+                    Some(fields) if !fields.known.contains(&key.as_str()) => {
+                        unknown_fields.insert(field_path);
+                    }
+                    _ => collect_unknown_fields(value, Some(key), &field_path, unknown_fields),
+                }
+            }
+        }
+
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                collect_unknown_fields(item, field, &format!("{path}[{index}]"), unknown_fields);
+            }
+        }
+
+        _ => {}
+    }
+}
+
+/// Format a path to a field as `field.nested[index]`.
+fn field_path(path: &Path) -> String {
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, index } => format!("{}[{index}]", field_path(parent)),
+        Path::Map { parent, key } => match field_path(parent) {
+            parent if parent.is_empty() => key.to_owned(),
+            parent => format!("{parent}.{key}"),
+        },
+        Path::Some { parent } | Path::NewtypeStruct { parent } | Path::NewtypeVariant { parent } => field_path(parent),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        album::FullAlbum,
+        playback::PlaybackState,
+        playlist::CurrentUserPlaylists,
+        recommendations::Recommendations,
+        test_responses::{
+            CURRENT_USER_PLAYLISTS_RESPONSE, FULL_ALBUM_RESPONSE, FULL_TRACK_RESPONSE, PLAYBACK_STATE_RESPONSE,
+            PRIVATE_USER_RESPONSE, RELINKED_TRACK_RESPONSE,
+        },
+        track::FullTrack,
+        user::PrivateUser,
+    };
+
+    const RECOMMENDATIONS: &str = r#"{
+        "seeds": [
+            {
+                "initialPoolSize": 250,
+                "afterFilteringSize": 250,
+                "afterRelinkingSize": 250,
+                "id": "4NHQUGzhtTLFvgF5SZesLK",
+                "type": "ARTIST",
+                "href": "https://api.spotify.com/v1/artists/4NHQUGzhtTLFvgF5SZesLK"
+            },
+            {
+                "initialPoolSize": 250,
+                "afterFilteringSize": 0,
+                "afterRelinkingSize": 0,
+                "id": "classical",
+                "type": "GENRE",
+                "href": null
+            }
+        ],
+        "tracks": []
+    }"#;
+
+    #[test]
+    fn omitted_fields_are_not_unknown() {
+        assert!(deserialize_strict::<Recommendations>(RECOMMENDATIONS).is_ok());
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        let body = RECOMMENDATIONS
+            .replace(r#""id": "classical","#, r#""id": "classical", "new_field": 1,"#)
+            .replace(r#""tracks": []"#, r#""tracks": [], "total": 0"#);
+
+        match deserialize_strict::<Recommendations>(&body) {
+            Err(Error::UnexpectedResponseFields(mut fields)) => {
+                fields.sort();
+                assert_eq!(fields, ["seeds[1].new_field", "total"]);
+            }
+
+            other => panic!("expected unexpected response fields error, got {other:?}"),
+        }
+
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert!(deserialize_value::<Recommendations>(&body, DeserializationMode::Lenient).is_ok());
+        assert!(deserialize_value::<Recommendations>(&body, DeserializationMode::Strict).is_err());
+    }
+
+    #[test]
+    fn shared_responses_have_no_unknown_fields() {
+        deserialize_strict::<FullTrack>(FULL_TRACK_RESPONSE).unwrap();
+        deserialize_strict::<FullTrack>(RELINKED_TRACK_RESPONSE).unwrap();
+        deserialize_strict::<FullAlbum>(FULL_ALBUM_RESPONSE).unwrap();
+        deserialize_strict::<PrivateUser>(PRIVATE_USER_RESPONSE).unwrap();
+        deserialize_strict::<PlaybackState>(PLAYBACK_STATE_RESPONSE).unwrap();
+        deserialize_strict::<CurrentUserPlaylists>(CURRENT_USER_PLAYLISTS_RESPONSE).unwrap();
+    }
+
+    #[test]
+    fn unknown_fields_within_flattened_objects_are_rejected() {
+        let body = FULL_TRACK_RESPONSE
+            .replacen(
+                r#""album_type": "album","#,
+                r#""album_type": "album", "new_field": 1,"#,
+                1,
+            )
+            .replacen(
+                r#""name": "Band of Horses","#,
+                r#""name": "Band of Horses", "new_field": 1,"#,
+                1,
+            )
+            .replace(r#""popularity": 72,"#, r#""popularity": 72, "new_field": 1,"#);
+
+        match deserialize_strict::<FullTrack>(&body) {
+            Err(Error::UnexpectedResponseFields(fields)) => {
+                assert_eq!(fields, ["album.artists[0].new_field", "album.new_field", "new_field"]);
+            }
+
+            other => panic!("expected unexpected response fields error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn fields_are_only_omitted_from_their_own_objects() {
+        // pages have a link to their previous page and objects have URIs, but albums and external IDs don't
+        let body = FULL_TRACK_RESPONSE
+            .replace(r#""isrc": "USSUB0660010""#, r#""isrc": "USSUB0660010", "uri": null"#)
+            .replacen(r#""available_markets""#, r#""previous": null, "available_markets""#, 1);
+
+        match deserialize_strict::<FullTrack>(&body) {
+            Err(Error::UnexpectedResponseFields(fields)) => {
+                assert_eq!(fields, ["album.previous", "external_ids.uri"]);
+            }
+
+            other => panic!("expected unexpected response fields error, got {other:?}"),
+        }
+    }
+}
@@ -69,12 +69,15 @@ use rand::{distributions::Alphanumeric, Rng};
 use reqwest::{IntoUrl, Method, Url};
 
 #[cfg(feature = "async")]
-use super::private::AsyncClient;
+use super::{private::AsyncClient, rate_limit::AsyncRateLimitSleeper};
 #[cfg(feature = "sync")]
-use super::private::SyncClient;
+use super::{private::SyncClient, rate_limit::SyncRateLimitSleeper};
 use super::{
+    deserialization::DeserializationMode,
     private::{self, HttpClient},
-    SpotifyClientRef, ACCOUNTS_AUTHORIZE_ENDPOINT, RANDOM_STATE_LENGTH,
+    push_extra_query_params,
+    rate_limit::RateLimiter,
+    warn_if_authorize_url_too_long, SpotifyClientRef, ACCOUNTS_AUTHORIZE_ENDPOINT, RANDOM_STATE_LENGTH, REDACTED,
 };
 use crate::{
     error::{Error, Result},
@@ -130,11 +133,23 @@ where
     http_client: C,
 }
 
-#[derive(Debug)]
 struct ImplicitGrantUserClientRef {
     access_token: String,
 }
 
+impl std::fmt::Debug for ImplicitGrantUserClientRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImplicitGrantUserClientRef")
+            .field("access_token", &REDACTED)
+            .finish()
+    }
+}
+
+/// An incomplete implicit grant user client.
+///
+/// The client has been configured, and it has to be [finalized](IncompleteImplicitGrantUserClient::finalize) by
+/// directing the user to the [authorize URL](IncompleteImplicitGrantUserClient::get_authorize_url) and retrieving an
+/// access token and a state parameter from the redirect callback URL.
 #[derive(Debug, Clone)]
 pub struct IncompleteImplicitGrantUserClient<C>
 where
@@ -144,11 +159,13 @@ where
     state: String,
     scopes: Option<String>,
     show_dialog: bool,
+    extra_query_params: Vec<(String, String)>,
 
     spotify_client_ref: Arc<SpotifyClientRef>,
     http_client: C,
 }
 
+/// Builder for [ImplicitGrantUserClient].
 pub struct ImplicitGrantUserClientBuilder<C>
 where
     C: HttpClient + Clone,
@@ -156,6 +173,7 @@ where
     redirect_uri: String,
     scopes: Option<String>,
     show_dialog: bool,
+    extra_query_params: Vec<(String, String)>,
 
     spotify_client_ref: Arc<SpotifyClientRef>,
     http_client: C,
@@ -165,39 +183,52 @@ impl<C> IncompleteImplicitGrantUserClient<C>
 where
     C: HttpClient + Clone,
 {
+    /// Returns an authorization URL the user should be directed to in some manner.
+    ///
+    /// Once the user approves the application, they are redirected back to the application's callback URL. The URL
+    /// fragment in the callback will contain an `access_token` parameter and a `state` parameter, which should be
+    /// passed to the [`finalize`-function](IncompleteImplicitGrantUserClient::finalize) in order to complete the client
+    /// and get an [ImplicitGrantUserClient].
+    ///
+    /// The URL grows with every requested scope. Spotify accepts the URL even with every scope requested, but some user
+    /// agents and proxies refuse very long URLs, so a warning is logged if the URL is over 2000 characters long.
     pub fn get_authorize_url(&self) -> String {
         let mut query_params = vec![
             ("response_type", "token"),
             ("redirect_uri", self.redirect_uri.as_str()),
             ("client_id", self.spotify_client_ref.client_id.as_str()),
             ("state", self.state.as_str()),
+            ("show_dialog", if self.show_dialog { "true" } else { "false" }),
         ];
 
-        if let Some(scopes) = &self.scopes {
-            query_params.push(("scope", scopes.as_str()));
+        // an empty list of scopes would produce an empty scope parameter, which is the same as not requesting any
+        // scopes in the first place
+        if let Some(scopes) = self.scopes.as_deref().filter(|scopes| !scopes.is_empty()) {
+            query_params.push(("scope", scopes));
         }
 
-        if self.show_dialog {
-            // spotify's default for show_dialog is false if it's not specified
-            query_params.push(("show_dialog", "true"));
-        }
+        push_extra_query_params(&mut query_params, &self.extra_query_params);
 
-        // parsing the URL fails only if the base URL is invalid, not the parameters. if this method fails, there's a
-        // bug in the library
-        let authorize_url = Url::parse_with_params(ACCOUNTS_AUTHORIZE_ENDPOINT, &query_params)
-            .expect("failed to build authorize URL: invalid base URL (this is likely a bug)");
+        let mut authorize_url = self.http_client.accounts_url(ACCOUNTS_AUTHORIZE_ENDPOINT);
+        authorize_url.query_pairs_mut().extend_pairs(&query_params);
 
+        warn_if_authorize_url_too_long(&authorize_url);
         authorize_url.into()
     }
 
+    /// Finalize this client with an access token and a state from the callback URL the user was redirected to after
+    /// they approved the application.
+    ///
+    /// If the given state doesn't match the one the client generated, an
+    /// [AuthorizationCodeStateMismatch-error](Error::AuthorizationCodeStateMismatch) is returned.
     pub fn finalize<S>(self, access_token: S, state: &str) -> Result<ImplicitGrantUserClient<C>>
     where
         S: Into<String>,
     {
         let access_token = access_token.into();
         debug!(
-            "Attempting to finalize implicit grant flow user client with access_token: {} and state: {}",
-            access_token, state
+            "Attempting to finalize implicit grant flow user client with state: {}",
+            state
         );
 
         if state != self.state {
@@ -220,12 +251,19 @@ where
             redirect_uri,
             scopes: None,
             show_dialog: false,
+            extra_query_params: Vec::new(),
 
             spotify_client_ref,
             http_client,
         }
     }
 
+    /// Specify the [OAuth authorization scopes](crate::scope::Scope) that the user is asked to grant for the
+    /// application.
+    ///
+    /// Note that if you change the scopes you request, the new scopes will not be granted for users with existing
+    /// approvals for the application. You will have to force users to reapprove the application by setting
+    /// [`show_dialog`](ImplicitGrantUserClientBuilder::show_dialog) to true.
     pub fn scopes<T>(self, scopes: T) -> Self
     where
         T: ToScopesString,
@@ -236,10 +274,28 @@ where
         }
     }
 
+    /// Set whether or not to force the user to approve the application again, if they've already done so.
+    ///
+    /// If false (default), a user who has already approved the application is automatically redirected to the specified
+    /// redirect URL. If true, the user will not be automatically redirected and will have to approve the application
+    /// again.
     pub fn show_dialog(self, show_dialog: bool) -> Self {
         Self { show_dialog, ..self }
     }
 
+    /// Add an extra query parameter to the [authorize URL](IncompleteImplicitGrantUserClient::get_authorize_url).
+    /// This allows passing parameters Spotify supports that this builder doesn't know of. Parameters the client sets
+    /// itself, such as `state` or `scope`, cannot be overridden and are ignored with a warning.
+    pub fn extra_query_param<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.extra_query_params.push((key.into(), value.into()));
+        self
+    }
+
+    /// Finalize the builder and return an [IncompleteImplicitGrantUserClient].
     pub fn build(self) -> IncompleteImplicitGrantUserClient<C> {
         let state = rand::thread_rng()
             .sample_iter(&Alphanumeric)
@@ -252,6 +308,7 @@ where
             state,
             scopes: self.scopes,
             show_dialog: self.show_dialog,
+            extra_query_params: self.extra_query_params,
 
             spotify_client_ref: self.spotify_client_ref,
             http_client: self.http_client,
@@ -261,6 +318,16 @@ where
 
 impl<C> crate::private::Sealed for ImplicitGrantUserClient<C> where C: HttpClient + Clone {}
 
+impl<C> super::private::TrackCacheAccess for ImplicitGrantUserClient<C>
+where
+    C: HttpClient + Clone,
+{
+    #[cfg(feature = "cache")]
+    fn track_cache(&self) -> Option<&crate::client::cache::TrackCache> {
+        self.http_client.track_cache()
+    }
+}
+
 #[cfg(feature = "async")]
 impl private::BuildHttpRequestAsync for AsyncImplicitGrantUserClient {
     fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
@@ -271,6 +338,26 @@ impl private::BuildHttpRequestAsync for AsyncImplicitGrantUserClient {
             .request(method, url)
             .bearer_auth(self.inner.access_token.as_str())
     }
+
+    fn api_base_url(&self) -> &Url {
+        self.http_client.api_base_url()
+    }
+
+    fn rate_limit_sleeper(&self) -> &dyn AsyncRateLimitSleeper {
+        self.http_client.rate_limit_sleeper()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.http_client.rate_limiter()
+    }
+
+    fn rate_limit_policy(&self) -> private::RateLimitPolicy {
+        self.http_client.rate_limit_policy()
+    }
+
+    fn deserialization_mode(&self) -> DeserializationMode {
+        self.http_client.deserialization_mode()
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -283,6 +370,26 @@ impl private::BuildHttpRequestSync for SyncImplicitGrantUserClient {
             .request(method, url)
             .bearer_auth(self.inner.access_token.as_str())
     }
+
+    fn api_base_url(&self) -> &Url {
+        self.http_client.api_base_url()
+    }
+
+    fn rate_limit_sleeper(&self) -> &dyn SyncRateLimitSleeper {
+        self.http_client.rate_limit_sleeper()
+    }
+
+    fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.http_client.rate_limiter()
+    }
+
+    fn rate_limit_policy(&self) -> private::RateLimitPolicy {
+        self.http_client.rate_limit_policy()
+    }
+
+    fn deserialization_mode(&self) -> DeserializationMode {
+        self.http_client.deserialization_mode()
+    }
 }
 
 #[cfg(feature = "async")]
@@ -311,3 +418,30 @@ impl private::AccessTokenExpirySync for SyncImplicitGrantUserClient {
         Ok(private::AccessTokenExpiryResult::Inapplicable)
     }
 }
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use super::*;
+    use crate::{client::SpotifyClientBuilder, scope::Scope};
+
+    #[test]
+    fn authorize_url_has_the_same_parameters_as_authorization_code_flow() {
+        let authorize_url = SpotifyClientBuilder::new("client id")
+            .build_async()
+            .implicit_grant_client("http://localhost/callback")
+            .scopes([Scope::UserReadEmail, Scope::Streaming])
+            .show_dialog(true)
+            .extra_query_param("locale", "fi")
+            .extra_query_param("response_type", "code")
+            .build()
+            .get_authorize_url();
+        let authorize_url = Url::parse(&authorize_url).unwrap();
+
+        let query_params: Vec<_> = authorize_url.query_pairs().collect();
+        assert!(query_params.contains(&("response_type".into(), "token".into())));
+        assert!(!query_params.contains(&("response_type".into(), "code".into())));
+        assert!(query_params.contains(&("scope".into(), "user-read-email streaming".into())));
+        assert!(query_params.contains(&("show_dialog".into(), "true".into())));
+        assert!(query_params.contains(&("locale".into(), "fi".into())));
+    }
+}
@@ -1,12 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "lyrics")]
+use crate::model::lyrics::Lyrics;
 use crate::{
     client::request_builder::TryFromEmptyResponse,
     error::ConversionError,
     model::{
-        playback::Device,
+        album::{AlbumObject, AlbumTracks, FullAlbum},
+        artist::{ArtistObject, FullArtist},
+        audio::{AudioAnalysis, AudioFeatures},
+        episode::{EpisodeObject, FullEpisode},
+        playback::{Device, PlaybackQueue},
+        playlist::{CurrentUserPlaylists, FullPlaylist, PlaylistTracks},
+        show::{FullShow, PartialShow, ShowEpisodes, ShowObject},
         track::{FullTrack, TrackObject},
         user::{PublicUser, User},
+        CountryCode, CursorPage,
     },
 };
 
@@ -18,24 +27,88 @@ pub const SEEK_POSITION_QUERY: &str = "position_ms";
 pub const QUEUE_URI_QUERY: &str = "uri";
 
 pub const TRACKS_IDS_QUERY: &str = "ids";
+pub const ALBUMS_IDS_QUERY: &str = "ids";
+pub const ARTISTS_IDS_QUERY: &str = "ids";
+pub const EPISODES_IDS_QUERY: &str = "ids";
+pub const SHOWS_IDS_QUERY: &str = "ids";
+pub const AUDIO_FEATURES_IDS_QUERY: &str = "ids";
+pub const FOLLOWED_IDS_QUERY: &str = "ids";
+pub const FOLLOWED_TYPE_QUERY: &str = "type";
 pub const MARKET_QUERY: &str = "market";
+#[cfg(feature = "lyrics")]
+pub const LYRICS_FORMAT_QUERY: &str = "format";
 
 #[derive(Debug, Serialize)]
 pub struct PlayItemsBody {
     pub uris: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<PlayOffset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_ms: Option<u32>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct PlayContextBody {
     pub context_uri: String,
-    pub offset: PlayContextOffset,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<PlayOffset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_ms: Option<u32>,
 }
 
 #[derive(Debug, Serialize)]
-pub struct PlayContextOffset {
+pub struct TransferPlaybackBody {
+    pub device_ids: Vec<String>,
+    pub play: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PlaylistDetailsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collaborative: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AddPlaylistItemsBody {
+    pub uris: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<u32>,
-    // TODO: support URI offsets
-    pub uri: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RemovePlaylistItemsBody {
+    pub tracks: Vec<PlaylistItemUri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PlaylistItemUri {
+    pub uri: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ReorderPlaylistItemsBody {
+    pub range_start: u32,
+    pub insert_before: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
+}
+
+/// Where to start playback from in a context or a list of items. Only one of the fields may be set.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayOffset {
+    Position(u32),
+    Uri(String),
 }
 
 #[derive(Debug, Deserialize)]
@@ -48,15 +121,74 @@ pub struct TracksResponse {
     tracks: Vec<Option<TrackObject>>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AlbumsResponse {
+    albums: Vec<Option<AlbumObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArtistsResponse {
+    artists: Vec<Option<ArtistObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RelatedArtistsResponse {
+    artists: Vec<FullArtist>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EpisodesResponse {
+    episodes: Vec<Option<EpisodeObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ShowsResponse {
+    shows: Vec<Option<ShowObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FollowedArtistsResponse {
+    artists: CursorPage<FullArtist>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SnapshotIdResponse {
+    snapshot_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenreSeedsResponse {
+    genres: Vec<String>,
+}
+
+#[cfg(feature = "lyrics")]
+#[derive(Debug, Deserialize)]
+pub struct LyricsResponse {
+    lyrics: Lyrics,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarketsResponse {
+    markets: Vec<CountryCode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AudioFeaturesResponse {
+    audio_features: Vec<Option<AudioFeatures>>,
+}
+
 impl TracksResponse {
     pub fn full_tracks(self) -> std::result::Result<Vec<FullTrack>, ConversionError> {
-        self.tracks
-            .into_iter()
-            .filter_map(|obj| obj.map(FullTrack::try_from))
-            .collect::<std::result::Result<Vec<_>, ConversionError>>()
+        self.try_into()
     }
 }
 
+/// Returns a function that wraps an error converting an item in a response into an error that tells which item failed
+/// to convert.
+fn item_conversion_error(item: &'static str, index: usize) -> impl FnOnce(ConversionError) -> ConversionError {
+    move |err| ConversionError::with_source(format!("failed to convert {item} at index {index}").into(), err)
+}
+
 impl TryFrom<TracksResponse> for Vec<FullTrack> {
     type Error = ConversionError;
 
@@ -64,11 +196,115 @@ impl TryFrom<TracksResponse> for Vec<FullTrack> {
         value
             .tracks
             .into_iter()
-            .filter_map(|obj| obj.map(FullTrack::try_from))
+            .enumerate()
+            .filter_map(|(index, obj)| {
+                obj.map(|obj| FullTrack::try_from(obj).map_err(item_conversion_error("track", index)))
+            })
             .collect::<std::result::Result<Vec<_>, ConversionError>>()
     }
 }
 
+impl TryFrom<AlbumsResponse> for Vec<Option<FullAlbum>> {
+    type Error = ConversionError;
+
+    fn try_from(value: AlbumsResponse) -> Result<Self, Self::Error> {
+        value
+            .albums
+            .into_iter()
+            .enumerate()
+            .map(|(index, obj)| {
+                obj.map(|obj| FullAlbum::try_from(obj).map_err(item_conversion_error("album", index)))
+                    .transpose()
+            })
+            .collect::<std::result::Result<Vec<_>, ConversionError>>()
+    }
+}
+
+impl TryFrom<ArtistsResponse> for Vec<Option<FullArtist>> {
+    type Error = ConversionError;
+
+    fn try_from(value: ArtistsResponse) -> Result<Self, Self::Error> {
+        value
+            .artists
+            .into_iter()
+            .enumerate()
+            .map(|(index, obj)| {
+                obj.map(|obj| FullArtist::try_from(obj).map_err(item_conversion_error("artist", index)))
+                    .transpose()
+            })
+            .collect::<std::result::Result<Vec<_>, ConversionError>>()
+    }
+}
+
+impl TryFrom<EpisodesResponse> for Vec<Option<FullEpisode>> {
+    type Error = ConversionError;
+
+    fn try_from(value: EpisodesResponse) -> Result<Self, Self::Error> {
+        value
+            .episodes
+            .into_iter()
+            .enumerate()
+            .map(|(index, obj)| {
+                obj.map(|obj| FullEpisode::try_from(obj).map_err(item_conversion_error("episode", index)))
+                    .transpose()
+            })
+            .collect::<std::result::Result<Vec<_>, ConversionError>>()
+    }
+}
+
+impl From<ShowsResponse> for Vec<Option<PartialShow>> {
+    fn from(response: ShowsResponse) -> Self {
+        response
+            .shows
+            .into_iter()
+            .map(|obj| obj.map(PartialShow::from))
+            .collect()
+    }
+}
+
+impl From<AudioFeaturesResponse> for Vec<Option<AudioFeatures>> {
+    fn from(response: AudioFeaturesResponse) -> Self {
+        response.audio_features
+    }
+}
+
+impl From<RelatedArtistsResponse> for Vec<FullArtist> {
+    fn from(response: RelatedArtistsResponse) -> Self {
+        response.artists
+    }
+}
+
+impl From<FollowedArtistsResponse> for CursorPage<FullArtist> {
+    fn from(response: FollowedArtistsResponse) -> Self {
+        response.artists
+    }
+}
+
+impl From<GenreSeedsResponse> for Vec<String> {
+    fn from(response: GenreSeedsResponse) -> Self {
+        response.genres
+    }
+}
+
+#[cfg(feature = "lyrics")]
+impl From<LyricsResponse> for Lyrics {
+    fn from(response: LyricsResponse) -> Self {
+        response.lyrics
+    }
+}
+
+impl From<MarketsResponse> for Vec<CountryCode> {
+    fn from(response: MarketsResponse) -> Self {
+        response.markets
+    }
+}
+
+impl From<SnapshotIdResponse> for String {
+    fn from(response: SnapshotIdResponse) -> Self {
+        response.snapshot_id
+    }
+}
+
 impl From<DevicesResponse> for Vec<Device> {
     fn from(response: DevicesResponse) -> Self {
         response.devices
@@ -79,7 +315,102 @@ impl From<DevicesResponse> for Vec<Device> {
 // (can't have a blanket implementation for everything since specialisation isn't a thing yet)
 impl TryFromEmptyResponse for DevicesResponse {}
 impl TryFromEmptyResponse for TracksResponse {}
+impl TryFromEmptyResponse for AlbumsResponse {}
+impl TryFromEmptyResponse for FullAlbum {}
+impl TryFromEmptyResponse for AlbumTracks {}
+impl TryFromEmptyResponse for ArtistsResponse {}
+impl TryFromEmptyResponse for RelatedArtistsResponse {}
+impl TryFromEmptyResponse for FollowedArtistsResponse {}
+impl TryFromEmptyResponse for FullArtist {}
+impl TryFromEmptyResponse for EpisodesResponse {}
+impl TryFromEmptyResponse for FullEpisode {}
+impl TryFromEmptyResponse for ShowsResponse {}
+impl TryFromEmptyResponse for FullShow {}
+impl TryFromEmptyResponse for ShowEpisodes {}
+impl TryFromEmptyResponse for AudioFeaturesResponse {}
+impl TryFromEmptyResponse for AudioFeatures {}
+impl TryFromEmptyResponse for AudioAnalysis {}
+impl TryFromEmptyResponse for GenreSeedsResponse {}
+impl TryFromEmptyResponse for MarketsResponse {}
+#[cfg(feature = "lyrics")]
+impl TryFromEmptyResponse for LyricsResponse {}
+impl TryFromEmptyResponse for SnapshotIdResponse {}
 impl TryFromEmptyResponse for FullTrack {}
+impl TryFromEmptyResponse for FullPlaylist {}
+impl TryFromEmptyResponse for PlaylistTracks {}
+impl TryFromEmptyResponse for CurrentUserPlaylists {}
+impl TryFromEmptyResponse for PlaybackQueue {}
 impl TryFromEmptyResponse for TrackObject {}
 impl TryFromEmptyResponse for User {}
 impl TryFromEmptyResponse for PublicUser {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::artist::CommonArtistInformation;
+
+    #[test]
+    fn markets_response_is_unwrapped_into_country_codes() {
+        let response: MarketsResponse = serde_json::from_str(r#"{ "markets": ["AD", "FI", "XK"] }"#).unwrap();
+
+        assert_eq!(
+            Vec::<CountryCode>::from(response),
+            vec![CountryCode::AD, CountryCode::FI, CountryCode::XK]
+        );
+    }
+
+    #[test]
+    fn related_artists_response_is_unwrapped_into_artists() {
+        let response: RelatedArtistsResponse = serde_json::from_value(serde_json::json!({
+            "artists": [{
+                "external_urls": {},
+                "followers": { "href": null, "total": 1337 },
+                "genres": ["pop"],
+                "href": "https://api.spotify.com/v1/artists/0TnOYISbd1XYRBk9myaseg",
+                "id": "0TnOYISbd1XYRBk9myaseg",
+                "images": [],
+                "name": "Test artist",
+                "popularity": 80,
+                "type": "artist",
+                "uri": "spotify:artist:0TnOYISbd1XYRBk9myaseg"
+            }]
+        }))
+        .unwrap();
+
+        let artists = Vec::<FullArtist>::from(response);
+
+        assert_eq!(artists.len(), 1);
+        assert_eq!(artists[0].name(), "Test artist");
+    }
+
+    #[test]
+    fn failed_item_conversion_is_the_error_source() {
+        let response: TracksResponse = serde_json::from_value(serde_json::json!({
+            "tracks": [null, {
+                "artists": [],
+                "disc_number": 1,
+                "duration_ms": 180000,
+                "explicit": false,
+                "external_urls": {},
+                "id": null,
+                "is_local": true,
+                "name": "Local track",
+                "preview_url": null,
+                "track_number": 1,
+                "type": "track"
+            }]
+        }))
+        .unwrap();
+
+        let err = crate::error::Error::from(Vec::<FullTrack>::try_from(response).unwrap_err());
+
+        assert_eq!(
+            err.to_string(),
+            "object conversion failed: failed to convert track at index 1"
+        );
+        assert!(std::error::Error::source(&err)
+            .unwrap()
+            .to_string()
+            .starts_with("object conversion failed: attempt to convert non-full track object into full track"));
+    }
+}
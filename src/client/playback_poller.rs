@@ -0,0 +1,215 @@
+//! Polling the user's playback state for changes. The streams and iterators are returned by the
+//! [`playback_changes_async`](super::ScopedClient::playback_changes_async) and
+//! [`playback_changes_sync`](super::ScopedClient::playback_changes_sync) functions in
+//! [ScopedClient](super::ScopedClient).
+
+use std::time::Duration;
+
+#[cfg(all(
+    feature = "async",
+    any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+))]
+use futures_util::Stream;
+
+#[cfg(all(
+    feature = "async",
+    any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+))]
+use super::request_builder::AsyncRequestBuilder;
+#[cfg(feature = "sync")]
+use super::request_builder::SyncRequestBuilder;
+use super::scoped::ScopedClient;
+use crate::{error::Result, model::playback::PlaybackState};
+
+/// The poll interval is doubled for each consecutive poll where nothing is playing, up to 2^3 = 8 times the original
+/// interval.
+const MAX_IDLE_BACKOFF_EXPONENT: u32 = 3;
+
+/// Keeps track of the previously seen playback state and how long to wait between polls.
+#[derive(Debug)]
+struct PlaybackPoller {
+    poll_interval: Duration,
+    previous: Option<PlaybackState>,
+    idle_polls: u32,
+    polled: bool,
+}
+
+impl PlaybackPoller {
+    fn new(poll_interval: Duration) -> Self {
+        Self {
+            poll_interval,
+            previous: None,
+            idle_polls: 0,
+            polled: false,
+        }
+    }
+
+    /// Returns how long to wait before the next poll. The first poll is done immediately.
+    fn next_wait(&mut self) -> Option<Duration> {
+        if self.polled {
+            Some(self.poll_interval * (1 << self.idle_polls.min(MAX_IDLE_BACKOFF_EXPONENT)))
+        } else {
+            self.polled = true;
+            None
+        }
+    }
+
+    /// Record a polled playback state. Returns the state if it meaningfully differs from the previously recorded state.
+    fn observe(&mut self, state: Option<PlaybackState>) -> Option<PlaybackState> {
+        match state {
+            Some(state) => {
+                self.idle_polls = 0;
+
+                let changed = self
+                    .previous
+                    .as_ref()
+                    .is_none_or(|previous| state.meaningfully_differs_from(previous));
+
+                if changed {
+                    self.previous = Some(state.clone());
+                    Some(state)
+                } else {
+                    None
+                }
+            }
+
+            None => {
+                // forget the previous state so the same item is yielded again once playback resumes
+                self.idle_polls = self.idle_polls.saturating_add(1);
+                self.previous = None;
+                None
+            }
+        }
+    }
+}
+
+/// Returns a stream that polls the client's playback state and yields it whenever it meaningfully changes.
+#[cfg(all(
+    feature = "async",
+    any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+))]
+pub(crate) fn playback_changes_async<C>(client: C, poll_interval: Duration) -> impl Stream<Item = Result<PlaybackState>>
+where
+    C: ScopedClient + super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+{
+    futures_util::stream::unfold(
+        (client, PlaybackPoller::new(poll_interval)),
+        |(client, mut poller)| async move {
+            loop {
+                if let Some(wait) = poller.next_wait() {
+                    super::rate_limit::sleep_async(wait).await;
+                }
+
+                match client.playback_state().send_async().await {
+                    Ok(state) => {
+                        if let Some(changed) = poller.observe(state) {
+                            return Some((Ok(changed), (client, poller)));
+                        }
+                    }
+
+                    Err(err) => return Some((Err(err), (client, poller))),
+                }
+            }
+        },
+    )
+}
+
+/// Returns an iterator that polls the client's playback state and yields it whenever it meaningfully changes.
+#[cfg(feature = "sync")]
+pub(crate) fn playback_changes_sync<C>(
+    client: C,
+    poll_interval: Duration,
+) -> impl Iterator<Item = Result<PlaybackState>>
+where
+    C: ScopedClient + super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+{
+    let mut poller = PlaybackPoller::new(poll_interval);
+
+    std::iter::from_fn(move || loop {
+        if let Some(wait) = poller.next_wait() {
+            std::thread::sleep(wait);
+        }
+
+        match client.playback_state().send_sync() {
+            Ok(state) => {
+                if let Some(changed) = poller.observe(state) {
+                    return Some(Ok(changed));
+                }
+            }
+
+            Err(err) => return Some(Err(err)),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::model::test_responses::PLAYBACK_STATE_RESPONSE;
+
+    /// Returns the playback state response with the fields at the given JSON pointers replaced.
+    fn playback_state(changes: &[(&str, Value)]) -> PlaybackState {
+        let mut state: Value = serde_json::from_str(PLAYBACK_STATE_RESPONSE).unwrap();
+
+        for (pointer, value) in changes {
+            *state.pointer_mut(pointer).unwrap() = value.clone();
+        }
+
+        serde_json::from_value(state).unwrap()
+    }
+
+    #[test]
+    fn only_meaningful_changes_are_yielded() {
+        let mut poller = PlaybackPoller::new(Duration::from_secs(1));
+
+        let first = playback_state(&[]);
+        assert_eq!(poller.observe(Some(first.clone())), Some(first));
+
+        // only the progress changed
+        assert_eq!(
+            poller.observe(Some(playback_state(&[("/progress_ms", json!(5000))]))),
+            None
+        );
+
+        let paused = playback_state(&[("/progress_ms", json!(5000)), ("/is_playing", json!(false))]);
+        assert!(poller.observe(Some(paused)).is_some());
+
+        let other_device = playback_state(&[
+            ("/progress_ms", json!(5000)),
+            ("/is_playing", json!(false)),
+            ("/device/id", json!("other device")),
+        ]);
+        assert!(poller.observe(Some(other_device)).is_some());
+
+        let other_track = playback_state(&[
+            ("/is_playing", json!(false)),
+            ("/device/id", json!("other device")),
+            ("/item/id", json!("0c6xIDDpzE81m2q797ordA")),
+        ]);
+        assert!(poller.observe(Some(other_track)).is_some());
+    }
+
+    #[test]
+    fn polling_backs_off_while_nothing_is_playing() {
+        let interval = Duration::from_secs(1);
+        let mut poller = PlaybackPoller::new(interval);
+
+        assert_eq!(poller.next_wait(), None);
+        poller.observe(Some(playback_state(&[])));
+        assert_eq!(poller.next_wait(), Some(interval));
+
+        let waits: Vec<_> = (0..5)
+            .map(|_| {
+                poller.observe(None);
+                poller.next_wait().unwrap().as_secs()
+            })
+            .collect();
+        assert_eq!(waits, [2, 4, 8, 8, 8]);
+
+        // the same state is yielded again after playback resumes, and the interval is reset
+        assert!(poller.observe(Some(playback_state(&[]))).is_some());
+        assert_eq!(poller.next_wait(), Some(interval));
+    }
+}
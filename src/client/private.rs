@@ -1,57 +1,343 @@
 #[cfg(feature = "async")]
 mod async_client {
-    use std::ops::Deref;
+    use std::{sync::Arc, time::Duration};
+
+    use reqwest::{
+        header::{self, HeaderMap},
+        IntoUrl, Method, Url,
+    };
+
+    #[cfg(feature = "cache")]
+    use crate::client::cache::TrackCache;
+    use crate::client::{
+        deserialization::DeserializationMode,
+        private::{BaseUrls, RateLimitPolicy},
+        rate_limit::{AsyncRateLimitSleeper, RateLimiter},
+    };
 
     #[derive(Clone)]
-    pub struct AsyncClient(pub(crate) reqwest::Client);
+    pub struct AsyncClient {
+        http_client: reqwest::Client,
+        default_headers: HeaderMap,
+        timeout: Option<Duration>,
+        rate_limit_sleeper: Arc<dyn AsyncRateLimitSleeper>,
+        rate_limiter: Option<Arc<RateLimiter>>,
+        rate_limit_policy: RateLimitPolicy,
+        deserialization_mode: DeserializationMode,
+        base_urls: Arc<BaseUrls>,
+        #[cfg(feature = "cache")]
+        track_cache: Option<Arc<TrackCache>>,
+    }
 
-    impl super::HttpClient for AsyncClient {
-        fn new() -> Self {
-            Self(reqwest::Client::new())
+    impl AsyncClient {
+        /// Returns a new client using the given HTTP client, or a new default HTTP client if none is given. The default
+        /// headers are added to every request built with this client.
+        pub(crate) fn new(
+            http_client: Option<reqwest::Client>,
+            default_headers: HeaderMap,
+            rate_limit_sleeper: Arc<dyn AsyncRateLimitSleeper>,
+        ) -> Self {
+            Self {
+                http_client: http_client.unwrap_or_else(|| {
+                    reqwest::Client::builder()
+                        .build()
+                        // this can only fail due to a system error or system misconfiguration
+                        .expect("failed to build HTTP client: system error or system misconfiguration")
+                }),
+                default_headers,
+                timeout: None,
+                rate_limit_sleeper,
+                rate_limiter: None,
+                rate_limit_policy: RateLimitPolicy::default(),
+                deserialization_mode: DeserializationMode::default(),
+                base_urls: Arc::default(),
+                #[cfg(feature = "cache")]
+                track_cache: None,
+            }
+        }
+
+        #[cfg(feature = "cache")]
+        pub(crate) fn with_track_cache(self, track_cache: Option<Arc<TrackCache>>) -> Self {
+            Self { track_cache, ..self }
+        }
+
+        pub(crate) fn with_deserialization_mode(self, deserialization_mode: DeserializationMode) -> Self {
+            Self {
+                deserialization_mode,
+                ..self
+            }
+        }
+
+        pub(crate) fn with_rate_limiter(self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+            Self { rate_limiter, ..self }
+        }
+
+        pub(crate) fn with_rate_limit_policy(self, rate_limit_policy: RateLimitPolicy) -> Self {
+            Self {
+                rate_limit_policy,
+                ..self
+            }
+        }
+
+        pub(crate) fn with_timeout(self, timeout: Option<Duration>) -> Self {
+            Self { timeout, ..self }
+        }
+
+        pub(crate) fn with_base_urls(self, base_urls: BaseUrls) -> Self {
+            Self {
+                base_urls: Arc::new(base_urls),
+                ..self
+            }
+        }
+
+        pub(crate) fn rate_limit_sleeper(&self) -> &dyn AsyncRateLimitSleeper {
+            self.rate_limit_sleeper.as_ref()
+        }
+
+        pub(crate) fn rate_limiter(&self) -> Option<&RateLimiter> {
+            self.rate_limiter.as_deref()
+        }
+
+        pub(crate) fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+
+        pub(crate) fn deserialization_mode(&self) -> DeserializationMode {
+            self.deserialization_mode
+        }
+
+        pub(crate) fn api_base_url(&self) -> &Url {
+            &self.base_urls.api
+        }
+
+        /// Returns a new request builder with the default headers filled in, except for the client credentials
+        /// authorization header. API requests authorize themselves with an access token instead.
+        pub(crate) fn request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
+        where
+            U: IntoUrl,
+        {
+            let mut default_headers = self.default_headers.clone();
+            default_headers.remove(header::AUTHORIZATION);
+
+            self.with_request_timeout(self.http_client.request(method, url).headers(default_headers))
+        }
+
+        /// Returns a new POST request builder for the given accounts service endpoint with all the default headers
+        /// filled in, including the client credentials authorization header if the client has one.
+        pub(crate) fn post(&self, endpoint: &str) -> reqwest::RequestBuilder {
+            let url = super::HttpClient::accounts_url(self, endpoint);
+            self.with_request_timeout(self.http_client.post(url).headers(self.default_headers.clone()))
+        }
+
+        fn with_request_timeout(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+            match self.timeout {
+                Some(timeout) => request.timeout(timeout),
+                None => request,
+            }
         }
     }
 
-    impl Deref for AsyncClient {
-        type Target = reqwest::Client;
+    impl super::HttpClient for AsyncClient {
+        fn accounts_url(&self, endpoint: &str) -> Url {
+            super::resolve_endpoint(&self.base_urls.accounts, endpoint)
+        }
 
-        fn deref(&self) -> &Self::Target {
-            &self.0
+        #[cfg(feature = "cache")]
+        fn track_cache(&self) -> Option<&TrackCache> {
+            self.track_cache.as_deref()
         }
     }
 }
 
 #[cfg(feature = "sync")]
 mod sync_client {
-    use std::ops::Deref;
+    use std::{sync::Arc, time::Duration};
+
+    use reqwest::{
+        header::{self, HeaderMap},
+        IntoUrl, Method, Url,
+    };
+
+    #[cfg(feature = "cache")]
+    use crate::client::cache::TrackCache;
+    use crate::client::{
+        deserialization::DeserializationMode,
+        private::{BaseUrls, RateLimitPolicy},
+        rate_limit::{RateLimiter, SyncRateLimitSleeper},
+    };
 
     #[derive(Clone)]
-    pub struct SyncClient(pub(crate) reqwest::blocking::Client);
+    pub struct SyncClient {
+        http_client: reqwest::blocking::Client,
+        default_headers: HeaderMap,
+        timeout: Option<Duration>,
+        rate_limit_sleeper: Arc<dyn SyncRateLimitSleeper>,
+        rate_limiter: Option<Arc<RateLimiter>>,
+        rate_limit_policy: RateLimitPolicy,
+        deserialization_mode: DeserializationMode,
+        base_urls: Arc<BaseUrls>,
+        #[cfg(feature = "cache")]
+        track_cache: Option<Arc<TrackCache>>,
+    }
 
-    impl super::HttpClient for SyncClient {
-        fn new() -> Self {
-            Self(reqwest::blocking::Client::new())
+    impl SyncClient {
+        /// Returns a new client using the given HTTP client, or a new default HTTP client if none is given. The default
+        /// headers are added to every request built with this client.
+        pub(crate) fn new(
+            http_client: Option<reqwest::blocking::Client>,
+            default_headers: HeaderMap,
+            rate_limit_sleeper: Arc<dyn SyncRateLimitSleeper>,
+        ) -> Self {
+            Self {
+                http_client: http_client.unwrap_or_else(|| {
+                    reqwest::blocking::Client::builder()
+                        .build()
+                        // this can only fail due to a system error or system misconfiguration
+                        .expect("failed to build blocking HTTP client: system error or system misconfiguration")
+                }),
+                default_headers,
+                timeout: None,
+                rate_limit_sleeper,
+                rate_limiter: None,
+                rate_limit_policy: RateLimitPolicy::default(),
+                deserialization_mode: DeserializationMode::default(),
+                base_urls: Arc::default(),
+                #[cfg(feature = "cache")]
+                track_cache: None,
+            }
+        }
+
+        #[cfg(feature = "cache")]
+        pub(crate) fn with_track_cache(self, track_cache: Option<Arc<TrackCache>>) -> Self {
+            Self { track_cache, ..self }
+        }
+
+        pub(crate) fn with_deserialization_mode(self, deserialization_mode: DeserializationMode) -> Self {
+            Self {
+                deserialization_mode,
+                ..self
+            }
+        }
+
+        pub(crate) fn with_rate_limiter(self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+            Self { rate_limiter, ..self }
+        }
+
+        pub(crate) fn with_rate_limit_policy(self, rate_limit_policy: RateLimitPolicy) -> Self {
+            Self {
+                rate_limit_policy,
+                ..self
+            }
+        }
+
+        pub(crate) fn with_timeout(self, timeout: Option<Duration>) -> Self {
+            Self { timeout, ..self }
+        }
+
+        pub(crate) fn with_base_urls(self, base_urls: BaseUrls) -> Self {
+            Self {
+                base_urls: Arc::new(base_urls),
+                ..self
+            }
+        }
+
+        pub(crate) fn rate_limit_sleeper(&self) -> &dyn SyncRateLimitSleeper {
+            self.rate_limit_sleeper.as_ref()
+        }
+
+        pub(crate) fn rate_limiter(&self) -> Option<&RateLimiter> {
+            self.rate_limiter.as_deref()
+        }
+
+        pub(crate) fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+
+        pub(crate) fn deserialization_mode(&self) -> DeserializationMode {
+            self.deserialization_mode
+        }
+
+        pub(crate) fn api_base_url(&self) -> &Url {
+            &self.base_urls.api
+        }
+
+        /// Returns a new request builder with the default headers filled in, except for the client credentials
+        /// authorization header. API requests authorize themselves with an access token instead.
+        pub(crate) fn request<U>(&self, method: Method, url: U) -> reqwest::blocking::RequestBuilder
+        where
+            U: IntoUrl,
+        {
+            let mut default_headers = self.default_headers.clone();
+            default_headers.remove(header::AUTHORIZATION);
+
+            self.with_request_timeout(self.http_client.request(method, url).headers(default_headers))
+        }
+
+        /// Returns a new POST request builder for the given accounts service endpoint with all the default headers
+        /// filled in, including the client credentials authorization header if the client has one.
+        pub(crate) fn post(&self, endpoint: &str) -> reqwest::blocking::RequestBuilder {
+            let url = super::HttpClient::accounts_url(self, endpoint);
+            self.with_request_timeout(self.http_client.post(url).headers(self.default_headers.clone()))
+        }
+
+        fn with_request_timeout(
+            &self,
+            request: reqwest::blocking::RequestBuilder,
+        ) -> reqwest::blocking::RequestBuilder {
+            match self.timeout {
+                Some(timeout) => request.timeout(timeout),
+                None => request,
+            }
         }
     }
 
-    impl Deref for SyncClient {
-        type Target = reqwest::blocking::Client;
+    impl super::HttpClient for SyncClient {
+        fn accounts_url(&self, endpoint: &str) -> Url {
+            super::resolve_endpoint(&self.base_urls.accounts, endpoint)
+        }
 
-        fn deref(&self) -> &Self::Target {
-            &self.0
+        #[cfg(feature = "cache")]
+        fn track_cache(&self) -> Option<&TrackCache> {
+            self.track_cache.as_deref()
         }
     }
 }
 
-use reqwest::{IntoUrl, Method};
+use std::time::Duration;
+
+use reqwest::{IntoUrl, Method, Url};
 
 #[cfg(feature = "async")]
 pub use self::async_client::AsyncClient;
 #[cfg(feature = "sync")]
 pub use self::sync_client::SyncClient;
-use crate::error::Result;
+#[cfg(feature = "cache")]
+use super::cache::TrackCache;
+#[cfg(feature = "async")]
+use super::rate_limit::AsyncRateLimitSleeper;
+#[cfg(feature = "sync")]
+use super::rate_limit::SyncRateLimitSleeper;
+use super::{
+    deserialization::DeserializationMode,
+    rate_limit::{RateLimitBackoff, RateLimiter},
+    ACCOUNTS_BASE_URL, API_BASE_URL,
+};
+use crate::{error::Result, scope::Scope};
 
 pub trait HttpClient {
-    fn new() -> Self;
+    /// Returns the URL of the given accounts service endpoint.
+    fn accounts_url(&self, endpoint: &str) -> Url;
+
+    /// Returns the cache used for catalog tracks, if one is enabled.
+    #[cfg(feature = "cache")]
+    fn track_cache(&self) -> Option<&TrackCache>;
+}
+
+/// Every Spotify client implements this trait.
+pub trait TrackCacheAccess {
+    /// Returns the cache used for catalog tracks, if one is enabled.
+    #[cfg(feature = "cache")]
+    fn track_cache(&self) -> Option<&TrackCache>;
 }
 
 /// Every Spotify client implements this trait.
@@ -63,6 +349,27 @@ pub trait BuildHttpRequestAsync: crate::private::Sealed {
     fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
     where
         U: IntoUrl;
+
+    /// Returns the base URL API endpoints are resolved relative to.
+    fn api_base_url(&self) -> &Url;
+
+    /// Returns the sleeper used to wait out rate limits.
+    fn rate_limit_sleeper(&self) -> &dyn AsyncRateLimitSleeper;
+
+    /// Returns the rate limiter shared with other clients, if one is set.
+    fn rate_limiter(&self) -> Option<&RateLimiter>;
+
+    /// Returns the limits on retrying rate limited requests.
+    fn rate_limit_policy(&self) -> RateLimitPolicy;
+
+    /// Returns how strictly the client deserializes responses.
+    fn deserialization_mode(&self) -> DeserializationMode;
+
+    /// Returns an error if the client knows the given scope hasn't been granted to it. By default, every scope is
+    /// assumed to be granted.
+    fn ensure_scope(&self, _scope: Scope) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// Every Spotify client implements this trait.
@@ -74,6 +381,27 @@ pub trait BuildHttpRequestSync: crate::private::Sealed {
     fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::blocking::RequestBuilder
     where
         U: IntoUrl;
+
+    /// Returns the base URL API endpoints are resolved relative to.
+    fn api_base_url(&self) -> &Url;
+
+    /// Returns the sleeper used to wait out rate limits.
+    fn rate_limit_sleeper(&self) -> &dyn SyncRateLimitSleeper;
+
+    /// Returns the rate limiter shared with other clients, if one is set.
+    fn rate_limiter(&self) -> Option<&RateLimiter>;
+
+    /// Returns the limits on retrying rate limited requests.
+    fn rate_limit_policy(&self) -> RateLimitPolicy;
+
+    /// Returns how strictly the client deserializes responses.
+    fn deserialization_mode(&self) -> DeserializationMode;
+
+    /// Returns an error if the client knows the given scope hasn't been granted to it. By default, every scope is
+    /// assumed to be granted.
+    fn ensure_scope(&self, _scope: Scope) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// Every Spotify client implements this trait.
@@ -98,3 +426,53 @@ pub enum AccessTokenExpiryResult {
     /// Refreshing an access token is not applicable to this client
     Inapplicable,
 }
+
+/// Limits on how persistently a client retries rate limited requests. By default, requests are retried indefinitely
+/// and every rate limit is waited out.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RateLimitPolicy {
+    /// How many times a request is retried after being rate limited before giving up.
+    pub(crate) max_retries: Option<u32>,
+    /// The longest rate limit a request waits out.
+    pub(crate) max_wait: Option<Duration>,
+    /// How long a request waits when it is rate limited multiple times in a row.
+    pub(crate) backoff: RateLimitBackoff,
+}
+
+impl RateLimitPolicy {
+    /// Returns whether a request that has already been retried the given number of times may be retried again.
+    pub(crate) fn allows_retry(&self, retries: u32) -> bool {
+        self.max_retries.is_none_or(|max_retries| retries < max_retries)
+    }
+
+    /// Returns whether a rate limit of the given duration may be waited out.
+    pub(crate) fn allows_wait(&self, wait: Duration) -> bool {
+        self.max_wait.is_none_or(|max_wait| wait <= max_wait)
+    }
+}
+
+/// The base URLs the client's requests are sent to.
+#[derive(Debug, Clone)]
+pub struct BaseUrls {
+    pub(crate) api: Url,
+    pub(crate) accounts: Url,
+}
+
+impl Default for BaseUrls {
+    fn default() -> Self {
+        Self {
+            // parsing the URLs fails only if the constants are invalid. if this method fails, there's a bug in the
+            // library
+            api: Url::parse(API_BASE_URL).expect("failed to parse API base URL (this is likely a bug)"),
+            accounts: Url::parse(ACCOUNTS_BASE_URL).expect("failed to parse accounts base URL (this is likely a bug)"),
+        }
+    }
+}
+
+/// Resolves the given endpoint relative to the given base URL. An absolute URL, such as a page's next page URL, is
+/// returned as-is.
+pub(crate) fn resolve_endpoint(base_url: &Url, endpoint: &str) -> Url {
+    base_url
+        .join(endpoint)
+        .unwrap_or_else(|_| panic!("failed to build URL from base {base_url} and endpoint {endpoint}"))
+}
@@ -0,0 +1,273 @@
+//! Contains the traits used to wait out API rate limits, and the default implementation for them.
+//!
+//! When Spotify responds to a request with 429 Too Many Requests, the request builders wait the time Spotify asks for
+//! and then retry the request. By default, the wait is done with [DefaultRateLimitSleeper], which uses the sleep
+//! function selected with the crate's sleep features. A custom sleeper may be given to the client builder with the
+//! [`rate_limit_sleeper_async`-function](super::SpotifyClientBuilder::rate_limit_sleeper_async) or the
+//! [`rate_limit_sleeper_sync`-function](super::SpotifyClientBuilder::rate_limit_sleeper_sync), for example to record
+//! the requested waits in tests without actually sleeping.
+//!
+//! By default, a rate limited request is retried until it succeeds, no matter how many times or for how long it gets
+//! rate limited. The [`max_rate_limit_retries`-function](super::SpotifyClientBuilder::max_rate_limit_retries) and the
+//! [`max_rate_limit_wait`-function](super::SpotifyClientBuilder::max_rate_limit_wait) in the client builder limit how
+//! persistently requests are retried. Requests that exceed either limit fail with
+//! [RateLimit](crate::error::Error::RateLimit).
+//!
+//! By default, a rate limited request waits exactly the time Spotify asks for before it is retried. If the same request
+//! gets rate limited multiple times in a row, the
+//! [`rate_limit_backoff`-function](super::SpotifyClientBuilder::rate_limit_backoff) in the client builder may be used
+//! to have it back off exponentially with a random jitter instead. See [RateLimitBackoff] for more information.
+//!
+//! # Sharing rate limits between clients
+//!
+//! Spotify's rate limits apply to the whole application, so multiple clients of the same application share the same
+//! limit. By default, each client only reacts to the rate limits it hits itself, which may still cause other clients to
+//! send bursts of requests that get rate limited as well. A shared [RateLimiter] may be given to multiple client
+//! builders with the [`rate_limiter`-function](super::SpotifyClientBuilder::rate_limiter). When any client sharing the
+//! rate limiter gets rate limited, every client sharing it waits until the rate limit has passed before sending their
+//! next request. User clients built from a client share its rate limiter.
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use ferrispot::client::{rate_limit::RateLimiter, SpotifyClientBuilder};
+//! let rate_limiter = Arc::new(RateLimiter::new());
+//!
+//! let first_client = SpotifyClientBuilder::new("...")
+//!     .rate_limiter(Arc::clone(&rate_limiter))
+//!     .build_async();
+//! let second_client = SpotifyClientBuilder::new("...")
+//!     .rate_limiter(rate_limiter)
+//!     .build_async();
+//! ```
+
+use std::{
+    fmt::Debug,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use rand::Rng;
+
+use crate::error::Result;
+
+/// Waits out a rate limit asynchronously.
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncRateLimitSleeper: Debug + Send + Sync {
+    /// Wait for the given duration before the rate limited request is retried. Returning an error stops retrying the
+    /// request and returns the error from the request.
+    async fn sleep(&self, duration: Duration) -> Result<()>;
+}
+
+/// Waits out a rate limit synchronously.
+#[cfg(feature = "sync")]
+pub trait SyncRateLimitSleeper: Debug + Send + Sync {
+    /// Wait for the given duration before the rate limited request is retried. Returning an error stops retrying the
+    /// request and returns the error from the request.
+    fn sleep(&self, duration: Duration) -> Result<()>;
+}
+
+/// A rate limit shared between multiple clients. See the [module-level docs](self) for more information.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Return a new rate limiter that isn't paused.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pause every client sharing this rate limiter for the given duration. An existing longer pause is kept as-is.
+    pub(crate) fn pause(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut paused_until = self.paused_until.lock().expect("rate limiter mutex poisoned");
+
+        if paused_until.is_none_or(|paused_until| paused_until < until) {
+            *paused_until = Some(until);
+        }
+    }
+
+    /// Returns how long clients sharing this rate limiter still have to wait before sending requests, or `None` if the
+    /// rate limiter isn't paused.
+    pub(crate) fn remaining_pause(&self) -> Option<Duration> {
+        let paused_until = *self.paused_until.lock().expect("rate limiter mutex poisoned");
+        paused_until
+            .map(|paused_until| paused_until.saturating_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+/// How long a request waits when it gets rate limited multiple times in a row.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitBackoff {
+    /// Wait exactly the time Spotify asks for every time the request is rate limited. This is the default.
+    #[default]
+    RetryAfter,
+
+    /// Double the wait every time the same request is rate limited in a row, starting from the time Spotify asks for.
+    /// A random jitter of up to `max_jitter` is added to every wait so that requests rate limited at the same time
+    /// don't all retry at once.
+    Exponential {
+        /// The longest random jitter added to a wait.
+        max_jitter: Duration,
+        /// The longest a wait may grow to before the jitter is added. A wait is never shorter than the time Spotify
+        /// asks for, even if it is longer than this.
+        max_wait: Duration,
+    },
+}
+
+impl RateLimitBackoff {
+    /// Returns how long to wait before retrying a request that Spotify asked to wait for the given duration and that
+    /// has already been retried the given number of times after being rate limited.
+    pub(crate) fn wait(&self, retry_after: Duration, retries: u32) -> Duration {
+        match *self {
+            RateLimitBackoff::RetryAfter => retry_after,
+
+            RateLimitBackoff::Exponential { max_jitter, max_wait } => {
+                let backoff = retry_after
+                    .checked_mul(2u32.saturating_pow(retries))
+                    .unwrap_or(Duration::MAX)
+                    .min(max_wait.max(retry_after));
+                let jitter = rand::thread_rng().gen_range(Duration::ZERO..=max_jitter);
+
+                backoff.saturating_add(jitter)
+            }
+        }
+    }
+}
+
+/// The default rate limit sleeper.
+///
+/// Synchronously, it blocks the current thread for the wanted time. Asynchronously, it uses the sleep function selected
+/// with the crate's sleep features. In case none of the sleep features are enabled, it returns
+/// [RateLimit](crate::error::Error::RateLimit)-error instead of sleeping.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultRateLimitSleeper;
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncRateLimitSleeper for DefaultRateLimitSleeper {
+    async fn sleep(&self, duration: Duration) -> Result<()> {
+        rate_limit_sleep_async(duration).await
+    }
+}
+
+#[cfg(feature = "sync")]
+impl SyncRateLimitSleeper for DefaultRateLimitSleeper {
+    /// Sleep for the specified amount of time by blocking the current thread.
+    fn sleep(&self, duration: Duration) -> Result<()> {
+        std::thread::sleep(duration);
+        Ok(())
+    }
+}
+
+/// Return a rate limit error since no sleep utility has been enabled.
+#[cfg(all(
+    feature = "async",
+    not(feature = "tokio_sleep"),
+    not(feature = "async_std_sleep"),
+    not(feature = "smol_sleep")
+))]
+async fn rate_limit_sleep_async(duration: Duration) -> Result<()> {
+    Err(crate::error::Error::RateLimit(duration))
+}
+
+/// Sleep for the specified amount of time using the sleep function selected with the crate's sleep features.
+#[cfg(all(
+    feature = "async",
+    any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+))]
+async fn rate_limit_sleep_async(duration: Duration) -> Result<()> {
+    sleep_async(duration).await;
+    Ok(())
+}
+
+// if the user enables multiple sleep features for some reason, the sleep function is picked in the order: tokio,
+// async_std, smol
+/// Sleep for the specified amount of time using tokio's sleep function.
+#[cfg(all(feature = "async", feature = "tokio_sleep"))]
+pub(crate) async fn sleep_async(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Sleep for the specified amount of time using async_std's sleep function.
+#[cfg(all(feature = "async", feature = "async_std_sleep", not(feature = "tokio_sleep")))]
+pub(crate) async fn sleep_async(duration: Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+/// Sleep for the specified amount of time using smol's timer.
+#[cfg(all(
+    feature = "async",
+    feature = "smol_sleep",
+    not(feature = "tokio_sleep"),
+    not(feature = "async_std_sleep")
+))]
+pub(crate) async fn sleep_async(duration: Duration) {
+    async_io::Timer::after(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_keeps_longest_pause() {
+        let rate_limiter = RateLimiter::new();
+        assert_eq!(rate_limiter.remaining_pause(), None);
+
+        rate_limiter.pause(Duration::from_secs(10));
+        rate_limiter.pause(Duration::from_secs(2));
+
+        let remaining = rate_limiter.remaining_pause().unwrap();
+        assert!(remaining > Duration::from_secs(9) && remaining <= Duration::from_secs(10));
+    }
+
+    #[test]
+    fn retry_after_backoff_waits_exactly_retry_after() {
+        let backoff = RateLimitBackoff::default();
+
+        assert_eq!(backoff.wait(Duration::from_secs(3), 0), Duration::from_secs(3));
+        assert_eq!(backoff.wait(Duration::from_secs(3), 5), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn exponential_backoff_doubles_up_to_max_wait() {
+        let backoff = RateLimitBackoff::Exponential {
+            max_jitter: Duration::ZERO,
+            max_wait: Duration::from_secs(20),
+        };
+
+        assert_eq!(backoff.wait(Duration::from_secs(3), 0), Duration::from_secs(3));
+        assert_eq!(backoff.wait(Duration::from_secs(3), 1), Duration::from_secs(6));
+        assert_eq!(backoff.wait(Duration::from_secs(3), 2), Duration::from_secs(12));
+        assert_eq!(backoff.wait(Duration::from_secs(3), 3), Duration::from_secs(20));
+        assert_eq!(backoff.wait(Duration::from_secs(3), 100), Duration::from_secs(20));
+
+        // a wait is never shorter than what Spotify asks for
+        assert_eq!(backoff.wait(Duration::from_secs(30), 0), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn exponential_backoff_adds_bounded_jitter() {
+        let backoff = RateLimitBackoff::Exponential {
+            max_jitter: Duration::from_millis(500),
+            max_wait: Duration::from_secs(20),
+        };
+
+        for _ in 0..100 {
+            let wait = backoff.wait(Duration::from_secs(3), 1);
+            assert!(wait >= Duration::from_secs(6) && wait <= Duration::from_millis(6500));
+        }
+    }
+
+    #[test]
+    fn pause_expires() {
+        let rate_limiter = RateLimiter::new();
+        rate_limiter.pause(Duration::ZERO);
+
+        assert_eq!(rate_limiter.remaining_pause(), None);
+    }
+}
@@ -0,0 +1,216 @@
+//! A minimal local HTTP server used to receive the authorization code flow's callback request, so that command-line
+//! applications can finalize an
+//! [IncompleteAuthorizationCodeUserClient](super::authorization_code::IncompleteAuthorizationCodeUserClient) without
+//! hosting a web server of their own.
+
+use std::time::{Duration, Instant};
+#[cfg(feature = "async")]
+use std::{
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+};
+
+use log::{debug, warn};
+use reqwest::Url;
+use tiny_http::{Header, Response, Server};
+
+use super::authorization_code::AuthorizationCallback;
+use crate::error::{Error, Result};
+
+const SUCCESS_PAGE: &str = "<html><body><h1>Authorization complete</h1><p>You may close this window.</p></body></html>";
+const FAILURE_PAGE: &str = "<html><body><h1>Authorization failed</h1><p>You may close this window.</p></body></html>";
+
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct CallbackSlot {
+    result: Option<Result<AuthorizationCallback>>,
+    waker: Option<Waker>,
+}
+
+/// Starts a local server on the given port, opens the authorization URL in the user's browser and blocks until the
+/// callback to the redirect URI's path is received or the timeout elapses.
+pub(crate) fn receive_callback(
+    authorize_url: &str,
+    redirect_uri: &str,
+    port: u16,
+    timeout: Duration,
+) -> Result<AuthorizationCallback> {
+    let redirect_uri = Url::parse(redirect_uri).map_err(|e| Error::RedirectServer(Box::new(e)))?;
+
+    if redirect_uri.port_or_known_default() != Some(port) {
+        warn!(
+            "The redirect URI {redirect_uri} doesn't point to port {port} the local redirect server is listening on; the \
+             callback will likely never be received"
+        );
+    }
+
+    let server = Server::http(("127.0.0.1", port)).map_err(Error::RedirectServer)?;
+    debug!("Local redirect server listening on port {port}");
+
+    if let Err(e) = webbrowser::open(authorize_url) {
+        warn!("Failed to open the authorization URL in a browser ({e}), please open it manually: {authorize_url}");
+    }
+
+    wait_for_callback(&server, redirect_uri.path(), timeout)
+}
+
+/// Runs [receive_callback] in a separate thread and asynchronously waits for its result.
+#[cfg(feature = "async")]
+pub(crate) async fn receive_callback_async(
+    authorize_url: String,
+    redirect_uri: String,
+    port: u16,
+    timeout: Duration,
+) -> Result<AuthorizationCallback> {
+    let slot = Arc::new(Mutex::new(CallbackSlot::default()));
+    let thread_slot = Arc::clone(&slot);
+
+    std::thread::spawn(move || {
+        let result = receive_callback(&authorize_url, &redirect_uri, port, timeout);
+        let mut slot = thread_slot.lock().expect("callback slot lock poisoned");
+
+        slot.result = Some(result);
+
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    });
+
+    std::future::poll_fn(move |cx| {
+        let mut slot = slot.lock().expect("callback slot lock poisoned");
+
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+    .await
+}
+
+fn wait_for_callback(server: &Server, callback_path: &str, timeout: Duration) -> Result<AuthorizationCallback> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            return Err(Error::RedirectTimeout);
+        }
+
+        let Some(request) = server
+            .recv_timeout(remaining)
+            .map_err(|e| Error::RedirectServer(Box::new(e)))?
+        else {
+            return Err(Error::RedirectTimeout);
+        };
+
+        let Some(result) = parse_callback(request.url(), callback_path) else {
+            // browsers like to request things like favicons from the server, ignore everything but the callback
+            debug!("Ignoring request to {} in local redirect server", request.url());
+
+            let _ = request.respond(Response::from_string("Not found").with_status_code(404));
+            continue;
+        };
+
+        let (status, page) = match result {
+            Ok(_) => (200, SUCCESS_PAGE),
+            Err(_) => (400, FAILURE_PAGE),
+        };
+
+        let response = Response::from_string(page)
+            .with_status_code(status)
+            .with_header(Header::from_bytes("Content-Type", "text/html; charset=utf-8").expect("valid header"));
+
+        if let Err(e) = request.respond(response) {
+            warn!("Failed to respond to the authorization callback: {e}");
+        }
+
+        return result;
+    }
+}
+
+/// Parses the callback parameters from a request URL. Returns `None` if the request isn't to the callback path.
+fn parse_callback(request_url: &str, callback_path: &str) -> Option<Result<AuthorizationCallback>> {
+    let url = Url::parse(&format!("http://localhost{request_url}")).ok()?;
+
+    if url.path() != callback_path {
+        return None;
+    }
+
+    Some(AuthorizationCallback::from_url(&url))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+    };
+
+    use super::*;
+
+    fn request_and_read_response(port: u16, path: &str) -> String {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+
+        write!(
+            stream,
+            "GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n"
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn callback_is_parsed() {
+        assert_eq!(
+            parse_callback("/callback?code=abc&state=xyz", "/callback")
+                .unwrap()
+                .unwrap(),
+            AuthorizationCallback {
+                code: String::from("abc"),
+                state: String::from("xyz")
+            }
+        );
+
+        assert!(parse_callback("/favicon.ico", "/callback").is_none());
+        assert!(matches!(
+            parse_callback("/callback?state=xyz", "/callback"),
+            Some(Err(Error::InvalidAuthorizationCode))
+        ));
+    }
+
+    #[test]
+    fn denied_authorization_is_returned_after_ignoring_other_requests() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+
+        let client = std::thread::spawn(move || {
+            let favicon = request_and_read_response(port, "/favicon.ico");
+            let callback = request_and_read_response(port, "/callback?error=access_denied&state=xyz");
+            (favicon, callback)
+        });
+
+        let result = wait_for_callback(&server, "/callback", Duration::from_secs(5));
+        let (favicon, callback) = client.join().unwrap();
+
+        assert!(matches!(result, Err(Error::AuthorizationDenied(error)) if error == "access_denied"));
+        assert!(favicon.starts_with("HTTP/1.1 404"));
+        assert!(callback.starts_with("HTTP/1.1 400"));
+    }
+
+    #[test]
+    fn waiting_times_out() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+
+        assert!(matches!(
+            wait_for_callback(&server, "/callback", Duration::from_millis(50)),
+            Err(Error::RedirectTimeout)
+        ));
+    }
+}
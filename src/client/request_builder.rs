@@ -11,7 +11,10 @@ mod private {
     use super::RequestBuilder;
     #[cfg(feature = "sync")]
     use super::SyncResponseHandler;
-    use crate::error::{Error, Result};
+    use crate::{
+        error::{Error, Result},
+        scope::Scope,
+    };
 
     pub trait BaseRequestBuilderContainer<TClient, TResponse, TBody = (), TReturn = TResponse>
     where
@@ -28,17 +31,19 @@ mod private {
         fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, TBody, TReturn>;
         fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, TBody, TReturn>;
 
-        fn replace_body<F>(self, replacer: F) -> Self
+        /// Check the builder's parameters before the request is sent. By default, all parameters are valid.
+        fn validate(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn replace_body<F>(mut self, replacer: F) -> Self
         where
             F: FnOnce(TBody) -> TBody,
         {
-            let common = self.take_base_builder();
-            if let Some(body) = common.body {
-                let new_body = (replacer)(body);
-                Self::new_with_body(common.method, common.base_url, new_body, common.client)
-            } else {
-                Self::new(common.method, common.base_url, common.client)
-            }
+            // replace the body in place so the rest of the builder, such as the response handlers, is kept intact
+            let common = self.get_base_builder_mut();
+            common.body = common.body.take().map(replacer);
+            self
         }
 
         fn append_query<S>(mut self, key: &'static str, value: S) -> Self
@@ -60,8 +65,49 @@ mod private {
             self.get_base_builder_mut().sync_response_handler = handler;
             self
         }
+
+        #[cfg(feature = "cache")]
+        fn with_result_cache(mut self, hooks: ResultCacheHooks<TClient, TReturn>) -> Self {
+            self.get_base_builder_mut().result_cache = Some(hooks);
+            self
+        }
+
+        fn with_market_hook(mut self, hook: MarketHook<TReturn>) -> Self {
+            self.get_base_builder_mut().market_hook = Some(hook);
+            self
+        }
+
+        fn with_required_scope(mut self, scope: Scope) -> Self {
+            self.get_base_builder_mut().required_scope = Some(scope);
+            self
+        }
+
+        /// Send the given body as-is with the given content type, instead of serializing the builder's body as JSON.
+        fn with_raw_body(mut self, content_type: &'static str, body: String) -> Self {
+            self.get_base_builder_mut().raw_body = Some((content_type, body));
+            self
+        }
+    }
+
+    /// A hook for passing the market query parameter of a request, if set, to the request's result once it has been
+    /// received. Used by results that need to remember the market, for example to request more pages in the same
+    /// market.
+    pub type MarketHook<TReturn> = Box<dyn Fn(&mut TReturn, &str) + Send + Sync>;
+
+    /// Hooks for returning a request's result from a cache instead of sending the request, and for storing the result
+    /// of a sent request in the cache. Both hooks receive the market query parameter of the request, if set.
+    #[cfg(feature = "cache")]
+    pub struct ResultCacheHooks<TClient, TReturn> {
+        pub lookup: ResultCacheLookup<TClient, TReturn>,
+        pub store: ResultCacheStore<TClient, TReturn>,
     }
 
+    #[cfg(feature = "cache")]
+    pub type ResultCacheLookup<TClient, TReturn> = Box<dyn Fn(&TClient, Option<&str>) -> Option<TReturn> + Send + Sync>;
+
+    #[cfg(feature = "cache")]
+    pub type ResultCacheStore<TClient, TReturn> = Box<dyn Fn(&TClient, Option<&str>, &TReturn) + Send + Sync>;
+
     // TODO: I really do not like having to use this trait but not doing so would require, yet again, stabilised
     // specialisation
     /// This trait allows graceful handling of empty 200 responses vs actually empty 204 responses. In case the Spotify
@@ -100,31 +146,66 @@ mod private {
     }
 }
 
+mod browse_builder;
 mod catalog_item_builder;
+mod currently_playing_builder;
+mod followed_artists_builder;
+mod library_builder;
 mod player_control_builder;
+mod playlist_builder;
+mod recently_played_builder;
+mod recommendations_builder;
 mod search_builder;
+mod top_items_builder;
 
-use std::{borrow::Cow, collections::HashMap, fmt::Debug, marker::PhantomData};
+use std::{borrow::Cow, collections::HashMap, fmt::Debug, marker::PhantomData, time::Duration};
 #[cfg(feature = "async")]
 use std::{future::Future, pin::Pin};
 
+#[cfg(feature = "async")]
+use futures_util::{stream, Stream, TryStreamExt};
 use log::{error, info, trace, warn};
-use reqwest::{header, header::HeaderMap, Method, StatusCode, Url};
+use reqwest::{
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    Method, StatusCode, Url,
+};
 use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 
-pub(crate) use self::private::{BaseRequestBuilderContainer, TryFromEmptyResponse};
+#[cfg(feature = "cache")]
+pub(crate) use self::private::ResultCacheHooks;
+pub(crate) use self::private::{BaseRequestBuilderContainer, MarketHook, TryFromEmptyResponse};
 pub use self::{
+    browse_builder::BrowseRequestBuilder,
     catalog_item_builder::CatalogItemRequestBuilder,
+    currently_playing_builder::CurrentlyPlayingRequestBuilder,
+    followed_artists_builder::FollowedArtistsRequestBuilder,
+    library_builder::LibraryRequestBuilder,
     player_control_builder::{
         BasePlayerControlRequestBuilder, PlayContextRequestBuilder, PlayItemsRequestBuilder,
-        PlayerControlRequestBuilder,
+        PlayerControlRequestBuilder, TransferPlaybackRequestBuilder,
+    },
+    playlist_builder::{
+        AddPlaylistItemsRequestBuilder, BasePlaylistItemsRequestBuilder, PlaylistCoverRequestBuilder,
+        PlaylistDetailsRequestBuilder, RemovePlaylistItemsRequestBuilder, ReorderPlaylistItemsRequestBuilder,
     },
+    recently_played_builder::RecentlyPlayedRequestBuilder,
+    recommendations_builder::RecommendationsBuilder,
     search_builder::SearchBuilder,
+    top_items_builder::TopItemsRequestBuilder,
 };
+#[cfg(feature = "async")]
+use crate::model::{page::PageInformation, Page};
 use crate::{
-    client::private::AccessTokenExpiryResult,
+    client::{
+        deserialization::{deserialize_strict, deserialize_value, DeserializationMode},
+        object,
+        private::{resolve_endpoint, AccessTokenExpiryResult},
+        rate_limit::RateLimiter,
+    },
     error::{Error, Result},
     model::error::{ApiErrorMessage, ApiErrorResponse},
+    scope::Scope,
 };
 
 #[cfg(feature = "async")]
@@ -179,14 +260,35 @@ where
         self.get_base_builder_mut().auto_refresh_access_token = auto_refresh_access_token;
         self
     }
+
+    /// Whether or not to check the scope the endpoint requires has been granted to the client before sending the
+    /// request. If it hasn't, the request isn't sent and [MissingScope](Error::MissingScope) is returned instead.
+    /// Defaults to `false`.
+    ///
+    /// Only clients that know which scopes have been granted to them, such as the
+    /// [AuthorizationCodeUserClient](crate::client::authorization_code::AuthorizationCodeUserClient), check the scope;
+    /// other clients send the request regardless.
+    fn check_scope(mut self, check_scope: bool) -> Self {
+        self.get_base_builder_mut().check_scope = check_scope;
+        self
+    }
+
+    /// Set an additional header in the request. The header replaces any default header of the same name set in the
+    /// client, such as the `Accept-Language` header set with the [`locale`-function in the client
+    /// builder](crate::client::SpotifyClientBuilder::locale).
+    fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.get_base_builder_mut().headers.insert(name, value);
+        self
+    }
 }
 
 fn handle_403_forbidden_api_response(error_response: ApiErrorResponse) -> Result<()> {
     warn!("Error response: {error_response:?}");
 
-    match error_response.error.message {
-        ApiErrorMessage::RestrictionViolated => Err(Error::Restricted),
-        ApiErrorMessage::PremiumRequired => Err(Error::PremiumRequired),
+    match (error_response.error.message, error_response.error.reason) {
+        (ApiErrorMessage::RestrictionViolated, _) => Err(Error::Restricted),
+        (ApiErrorMessage::PremiumRequired, _) => Err(Error::PremiumRequired),
+        (_, Some(reason)) => Err(Error::PlayerRestricted(reason)),
 
         // TODO: test what actually happens when the user revokes the app's access while the app is
         // running
@@ -215,7 +317,34 @@ fn is_api_error_expired_access_token(error_response: ApiErrorResponse) -> Result
     }
 }
 
-fn extract_rate_limit_retry_after(headers: &HeaderMap) -> Result<u64> {
+/// Returns an error if the given response is an error response whose body isn't JSON, such as an HTML error page
+/// Spotify's edge returns during outages. Parsing such a body as JSON would fail with a decode error that hides the
+/// actual response status.
+fn check_error_response_content_type(status: StatusCode, headers: &HeaderMap) -> Result<()> {
+    // rate-limit responses are handled by their headers alone
+    if !(status.is_client_error() || status.is_server_error()) || status == StatusCode::TOO_MANY_REQUESTS {
+        return Ok(());
+    }
+
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|header| header.to_str().ok());
+
+    match content_type {
+        Some(content_type) if !content_type.contains("json") => {
+            error!("Got {status} response with non-JSON content type {content_type}");
+
+            Err(Error::NonJsonResponse {
+                status: status.as_u16(),
+                content_type: content_type.to_owned(),
+            })
+        }
+
+        _ => Ok(()),
+    }
+}
+
+fn extract_rate_limit_retry_after(headers: &HeaderMap) -> Result<Duration> {
     if let Some(wait_time) = headers
         .get(header::RETRY_AFTER)
         .and_then(|header| header.to_str().ok())
@@ -226,13 +355,26 @@ fn extract_rate_limit_retry_after(headers: &HeaderMap) -> Result<u64> {
             wait_time
         );
 
-        Ok(wait_time)
+        Ok(Duration::from_secs(wait_time))
     } else {
         warn!("Invalid rate-limit response");
         Err(Error::InvalidRateLimitResponse)
     }
 }
 
+/// Returns the market query parameter's value, if set.
+fn market_query<'a>(query_params: &'a HashMap<&'static str, Cow<'static, str>>) -> Option<&'a str> {
+    query_params.get(object::MARKET_QUERY).map(|market| market.as_ref())
+}
+
+/// Returns whether the request's result may be cached. Requests for the [market of the access token's
+/// user](crate::model::Market::FromToken) bypass the cache, since the cache is shared between the clients of different
+/// users and the result would be cached by the literal `from_token` market instead of the user's country.
+#[cfg(feature = "cache")]
+fn is_cacheable(query_params: &HashMap<&'static str, Cow<'static, str>>) -> bool {
+    market_query(query_params) != Some(crate::model::FROM_TOKEN_MARKET)
+}
+
 /// Asynchronous request builder functionality, namely sending the request and processing its response asynchronously.
 #[cfg(feature = "async")]
 #[async_trait::async_trait]
@@ -247,92 +389,226 @@ where
 {
     /// Send the request asynchronously and process the response, extracting the result object from the body.
     async fn send_async(self) -> Result<TReturn> {
+        self.validate()?;
+
         let common = self.take_base_builder();
-        let url = common.build_url();
-
-        loop {
-            let mut request = common.client.build_http_request(common.method.clone(), url.clone());
-
-            if let Some(body) = &common.body {
-                trace!("Request body: {:?}", body);
-                request = request.json(body);
-            } else {
-                // Spotify requires that all empty POST and PUT requests have Content-Length set to 0. I've previously
-                // supposedly observed that reqwest doesn't set Content-Length, even when there's a body, so we have to
-                // set it ourselves when there's an empty body. in hindsight it seems silly reqwest doesn't set
-                // Content-Length but I guess it makes sense if it's streaming the body or smth. setting a default
-                // Content-Length to 0 for every request also doesn't work since then it's set to 0 even when there's a
-                // body, which causes issues
-                if common.method == Method::POST || common.method == Method::PUT {
-                    request = request.header(header::CONTENT_LENGTH, header::HeaderValue::from_static("0"));
-                }
+
+        #[cfg(feature = "cache")]
+        if let Some(result) = common.cached_result() {
+            trace!("Returning cached result");
+            return Ok(result);
+        }
+
+        let (result, _) = send_request_async(common, false).await?;
+        Ok(result)
+    }
+
+    /// Send the request asynchronously and process the response, returning both the result object and the raw JSON
+    /// body Spotify returned. Useful for logging or archiving the original responses. Responses without a body are
+    /// returned as [Null](serde_json::Value::Null).
+    ///
+    /// The request is always sent to Spotify, even if the client has a [cache](crate::client::cache) that contains
+    /// the result.
+    async fn send_async_with_raw(self) -> Result<(TReturn, Value)> {
+        self.validate()?;
+
+        let (result, raw_body) = send_request_async(self.take_base_builder(), true).await?;
+        Ok((result, raw_body.unwrap_or(Value::Null)))
+    }
+}
+
+/// Functions for requests that return a [Page] of items.
+#[cfg(feature = "async")]
+pub trait AsyncPageRequestBuilder<TClient, TResponse, TBody, TInner, TItem>
+where
+    Self: AsyncRequestBuilder<TClient, TResponse, TBody, Page<TInner, TItem>> + Send,
+    TBody: Debug + Serialize + Send,
+    TResponse: Debug + DeserializeOwned + TryFromEmptyResponse + Send + Sync,
+    TInner: PageInformation<TItem> + DeserializeOwned + Debug + TryFromEmptyResponse + Send + Sync,
+    TItem: Send + Sync,
+    Page<TInner, TItem>: TryFrom<TResponse>,
+    TClient: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Clone + Send + Sync,
+    Error: From<<Page<TInner, TItem> as TryFrom<TResponse>>::Error>,
+{
+    /// Send the request asynchronously and turn the returned page into a stream of the items in it and every page
+    /// after it. See [Page::into_stream] for details.
+    fn paginate(mut self) -> impl Stream<Item = Result<TItem>> + Send {
+        let client = self.get_base_builder_mut().client.clone();
+
+        stream::once(async move { self.send_async().await })
+            .map_ok(move |page| page.into_stream(&client))
+            .try_flatten()
+    }
+}
+
+/// Send the request asynchronously, retrying it when necessary, and process the response. The raw response body is
+/// returned along with the result object if `keep_raw_body` is set.
+#[cfg(feature = "async")]
+async fn send_request_async<TClient, TResponse, TBody, TReturn>(
+    common: RequestBuilder<TClient, TResponse, TBody, TReturn>,
+    keep_raw_body: bool,
+) -> Result<(TReturn, Option<Value>)>
+where
+    TBody: Debug + Serialize + Send,
+    TResponse: Debug + DeserializeOwned + TryFromEmptyResponse + Send + Sync,
+    TReturn: TryFrom<TResponse> + Send + Sync,
+    TClient: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    Error: From<<TReturn as TryFrom<TResponse>>::Error>,
+{
+    if let (true, Some(scope)) = (common.check_scope, common.required_scope) {
+        common.client.ensure_scope(scope)?;
+    }
+
+    let url = common.build_url(common.client.api_base_url());
+
+    let rate_limit_policy = common.client.rate_limit_policy();
+    let mut rate_limit_retries = 0;
+
+    loop {
+        if let Some(pause) = common.client.rate_limiter().and_then(RateLimiter::remaining_pause) {
+            if !common.react_to_rate_limit {
+                warn!("A shared rate limit is active ({pause:?}) and reacting to rate limits is disabled");
+                return Err(Error::RateLimit(pause));
+            } else if !rate_limit_policy.allows_wait(pause) {
+                warn!("A shared rate limit is active ({pause:?}) and it is longer than the maximum rate limit wait");
+                return Err(Error::RateLimit(pause));
             }
 
-            let response = request.send().await?;
+            info!("Waiting {pause:?} for a shared rate limit to pass...");
+            common.client.rate_limit_sleeper().sleep(pause).await?;
+        }
+
+        let mut request = common
+            .client
+            .build_http_request(common.method.clone(), url.clone())
+            .headers(common.headers.clone());
+
+        if let Some(body) = &common.body {
+            trace!("Request body: {:?}", body);
+            request = request.json(body);
+        } else if let Some((content_type, body)) = &common.raw_body {
+            trace!(
+                "Raw request body of {} bytes with content type {content_type}",
+                body.len()
+            );
+            request = request.header(header::CONTENT_TYPE, *content_type).body(body.clone());
+        } else {
+            // Spotify requires that all empty POST and PUT requests have Content-Length set to 0. I've previously
+            // supposedly observed that reqwest doesn't set Content-Length, even when there's a body, so we have to
+            // set it ourselves when there's an empty body. in hindsight it seems silly reqwest doesn't set
+            // Content-Length but I guess it makes sense if it's streaming the body or smth. setting a default
+            // Content-Length to 0 for every request also doesn't work since then it's set to 0 even when there's a
+            // body, which causes issues
+            if common.method == Method::POST || common.method == Method::PUT {
+                request = request.header(header::CONTENT_LENGTH, header::HeaderValue::from_static("0"));
+            }
+        }
 
-            match response.status() {
-                StatusCode::BAD_REQUEST => {
-                    error!("Got 400 Bad Request response");
-                    let error_response = response.text().await?;
-                    warn!("Error response: {error_response}");
+        let response = request.send().await?;
+        check_error_response_content_type(response.status(), response.headers())?;
 
-                    return Err(Error::UnhandledSpotifyResponseStatusCode(400));
-                }
+        match response.status() {
+            StatusCode::BAD_REQUEST => {
+                error!("Got 400 Bad Request response");
+                let error_response = response.text().await?;
+                warn!("Error response: {error_response}");
 
-                StatusCode::FORBIDDEN => {
-                    error!("Got 403 Forbidden response");
-                    let error_response: ApiErrorResponse = response.json().await?;
-                    handle_403_forbidden_api_response(error_response)?
-                }
+                return Err(Error::UnhandledSpotifyResponseStatusCode(400));
+            }
 
-                StatusCode::UNAUTHORIZED => {
-                    warn!("Got 401 Unauthorized response");
-                    let error_response = response.json().await?;
-                    is_api_error_expired_access_token(error_response)?;
-
-                    // is_api_error_expired_access_token handles all other errors except the access token being expired
-                    if !common.auto_refresh_access_token
-                        || common.client.handle_access_token_expired().await? == AccessTokenExpiryResult::Inapplicable
-                    {
-                        warn!(
-                            "Refreshing access tokens is disabled for this request, or is inapplicable to this client"
-                        );
-
-                        return Err(Error::AccessTokenExpired);
-                    }
+            StatusCode::FORBIDDEN => {
+                error!("Got 403 Forbidden response");
+                let error_response: ApiErrorResponse = response.json().await?;
+                handle_403_forbidden_api_response(error_response)?
+            }
+
+            StatusCode::UNAUTHORIZED => {
+                warn!("Got 401 Unauthorized response");
+                let error_response = response.json().await?;
+                is_api_error_expired_access_token(error_response)?;
+
+                // is_api_error_expired_access_token handles all other errors except the access token being expired
+                if !common.auto_refresh_access_token
+                    || common.client.handle_access_token_expired().await? == AccessTokenExpiryResult::Inapplicable
+                {
+                    warn!("Refreshing access tokens is disabled for this request, or is inapplicable to this client");
+
+                    return Err(Error::AccessTokenExpired);
                 }
+            }
 
-                StatusCode::TOO_MANY_REQUESTS => {
-                    let headers = response.headers();
-                    let retry_after = extract_rate_limit_retry_after(headers)?;
+            StatusCode::TOO_MANY_REQUESTS => {
+                let headers = response.headers();
+                let retry_after = extract_rate_limit_retry_after(headers)?;
+                let rate_limiter = common.client.rate_limiter();
 
-                    if common.react_to_rate_limit {
-                        info!("Got rate limited, waiting {retry_after} seconds...");
-                        super::rate_limit_sleep_async(retry_after).await?;
-                    } else {
-                        warn!("Got rate limited {retry_after} seconds and reacting to rate limits is disabled");
-                        return Err(Error::RateLimit(retry_after));
-                    }
+                if let Some(rate_limiter) = rate_limiter {
+                    rate_limiter.pause(retry_after);
                 }
 
-                _ => {
-                    let response = (common.async_response_handler)(response).await;
-                    trace!("Handled response: {response:?}");
+                if !common.react_to_rate_limit {
+                    warn!("Got rate limited {retry_after:?} and reacting to rate limits is disabled");
+                    return Err(Error::RateLimit(retry_after));
+                } else if !rate_limit_policy.allows_retry(rate_limit_retries) {
+                    warn!("Got rate limited {retry_after:?} after {rate_limit_retries} retries");
+                    return Err(Error::RateLimit(retry_after));
+                } else if !rate_limit_policy.allows_wait(retry_after) {
+                    warn!("Got rate limited {retry_after:?} and it is longer than the maximum rate limit wait");
+                    return Err(Error::RateLimit(retry_after));
+                }
+
+                let wait = rate_limit_policy.backoff.wait(retry_after, rate_limit_retries);
+                rate_limit_retries += 1;
+                info!("Got rate limited, waiting {wait:?}...");
 
-                    let response = response?;
+                // with a shared rate limiter, the wait for the rate limit itself happens at the start of the loop along
+                // with every other request sharing the rate limiter. any backoff on top of it is waited here
+                if rate_limiter.is_none() || wait > retry_after {
+                    common.client.rate_limit_sleeper().sleep(wait).await?;
+                }
+            }
 
-                    // bypass serialization for 204 responses, since it's possible the return type is the unit type, but
-                    // serde/serde_json won't deserialize the unit type from an empty string, instead failing with an
-                    // EOF error
-                    let response_body = if response.status() == StatusCode::NO_CONTENT {
-                        TResponse::try_from_empty_response()?
+            _ => {
+                let response = (common.async_response_handler)(response).await;
+                trace!("Handled response: {response:?}");
+
+                let response = response?;
+
+                // bypass serialization for 204 responses and other responses without a body (such as the 200
+                // responses to saving items in the user's library), since it's possible the return type is the unit
+                // type, but serde/serde_json won't deserialize the unit type from an empty string, instead failing
+                // with an EOF error
+                let (response_body, raw_body): (TResponse, Option<Value>) =
+                    if response.status() == StatusCode::NO_CONTENT || response.content_length() == Some(0) {
+                        (
+                            TResponse::try_from_empty_response()?,
+                            keep_raw_body.then_some(Value::Null),
+                        )
+                    } else if keep_raw_body {
+                        let raw_body: Value = serde_json::from_str(&response.text().await?)?;
+                        let response_body = deserialize_value(&raw_body, common.client.deserialization_mode())?;
+                        (response_body, Some(raw_body))
                     } else {
-                        response.json().await?
+                        let response_body = match common.client.deserialization_mode() {
+                            DeserializationMode::Lenient => response.json().await?,
+                            DeserializationMode::Strict => deserialize_strict(&response.text().await?)?,
+                        };
+                        (response_body, None)
                     };
 
-                    trace!("Body: {response_body:?}");
-                    return Ok(response_body.try_into()?);
+                trace!("Body: {response_body:?}");
+                let mut result = response_body.try_into()?;
+
+                if let (Some(hook), Some(market)) = (&common.market_hook, market_query(&common.query_params)) {
+                    hook(&mut result, market);
+                }
+
+                #[cfg(feature = "cache")]
+                if let (Some(cache), true) = (&common.result_cache, is_cacheable(&common.query_params)) {
+                    (cache.store)(&common.client, market_query(&common.query_params), &result);
                 }
+
+                return Ok((result, raw_body));
             }
         }
     }
@@ -351,94 +627,200 @@ where
 {
     /// Send the request synchronously and process the response, extracting the result object from the body.
     fn send_sync(self) -> Result<TReturn> {
+        self.validate()?;
+
         let common = self.take_base_builder();
-        let url = common.build_url();
-
-        loop {
-            let mut request = common.client.build_http_request(common.method.clone(), url.clone());
-
-            if let Some(body) = &common.body {
-                trace!("Request body: {:?}", body);
-                request = request.json(body);
-            } else {
-                // Spotify requires that all empty POST and PUT requests have Content-Length set to 0. I've previously
-                // supposedly observed that reqwest doesn't set Content-Length, even when there's a body, so we have to
-                // set it ourselves when there's an empty body. in hindsight it seems silly reqwest doesn't set
-                // Content-Length but I guess it makes sense if it's streaming the body or smth. setting a default
-                // Content-Length to 0 for every request also doesn't work since then it's set to 0 even when there's a
-                // body, which causes issues
-                if common.method == Method::POST || common.method == Method::PUT {
-                    request = request.header(header::CONTENT_LENGTH, header::HeaderValue::from_static("0"));
-                }
+
+        #[cfg(feature = "cache")]
+        if let Some(result) = common.cached_result() {
+            trace!("Returning cached result");
+            return Ok(result);
+        }
+
+        let (result, _) = send_request_sync(common, false)?;
+        Ok(result)
+    }
+
+    /// Send the request synchronously and process the response, returning both the result object and the raw JSON
+    /// body Spotify returned. See [send_async_with_raw](AsyncRequestBuilder::send_async_with_raw) for details.
+    fn send_sync_with_raw(self) -> Result<(TReturn, Value)> {
+        self.validate()?;
+
+        let (result, raw_body) = send_request_sync(self.take_base_builder(), true)?;
+        Ok((result, raw_body.unwrap_or(Value::Null)))
+    }
+}
+
+/// Send the request synchronously, retrying it when necessary, and process the response. The raw response body is
+/// returned along with the result object if `keep_raw_body` is set.
+#[cfg(feature = "sync")]
+fn send_request_sync<TClient, TResponse, TBody, TReturn>(
+    common: RequestBuilder<TClient, TResponse, TBody, TReturn>,
+    keep_raw_body: bool,
+) -> Result<(TReturn, Option<Value>)>
+where
+    TBody: Debug + Serialize,
+    TResponse: Debug + DeserializeOwned + TryFromEmptyResponse,
+    TReturn: TryFrom<TResponse>,
+    TClient: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    Error: From<<TReturn as TryFrom<TResponse>>::Error>,
+{
+    if let (true, Some(scope)) = (common.check_scope, common.required_scope) {
+        common.client.ensure_scope(scope)?;
+    }
+
+    let url = common.build_url(common.client.api_base_url());
+
+    let rate_limit_policy = common.client.rate_limit_policy();
+    let mut rate_limit_retries = 0;
+
+    loop {
+        if let Some(pause) = common.client.rate_limiter().and_then(RateLimiter::remaining_pause) {
+            if !common.react_to_rate_limit {
+                warn!("A shared rate limit is active ({pause:?}) and reacting to rate limits is disabled");
+                return Err(Error::RateLimit(pause));
+            } else if !rate_limit_policy.allows_wait(pause) {
+                warn!("A shared rate limit is active ({pause:?}) and it is longer than the maximum rate limit wait");
+                return Err(Error::RateLimit(pause));
             }
 
-            let response = request.send()?;
+            info!("Waiting {pause:?} for a shared rate limit to pass...");
+            common.client.rate_limit_sleeper().sleep(pause)?;
+        }
 
-            match response.status() {
-                StatusCode::BAD_REQUEST => {
-                    error!("Got 400 Bad Request response");
-                    let error_response = response.text()?;
-                    warn!("Error response: {error_response}");
+        let mut request = common
+            .client
+            .build_http_request(common.method.clone(), url.clone())
+            .headers(common.headers.clone());
+
+        if let Some(body) = &common.body {
+            trace!("Request body: {:?}", body);
+            request = request.json(body);
+        } else if let Some((content_type, body)) = &common.raw_body {
+            trace!(
+                "Raw request body of {} bytes with content type {content_type}",
+                body.len()
+            );
+            request = request.header(header::CONTENT_TYPE, *content_type).body(body.clone());
+        } else {
+            // Spotify requires that all empty POST and PUT requests have Content-Length set to 0. I've previously
+            // supposedly observed that reqwest doesn't set Content-Length, even when there's a body, so we have to
+            // set it ourselves when there's an empty body. in hindsight it seems silly reqwest doesn't set
+            // Content-Length but I guess it makes sense if it's streaming the body or smth. setting a default
+            // Content-Length to 0 for every request also doesn't work since then it's set to 0 even when there's a
+            // body, which causes issues
+            if common.method == Method::POST || common.method == Method::PUT {
+                request = request.header(header::CONTENT_LENGTH, header::HeaderValue::from_static("0"));
+            }
+        }
 
-                    return Err(Error::UnhandledSpotifyResponseStatusCode(400));
-                }
+        let response = request.send()?;
+        check_error_response_content_type(response.status(), response.headers())?;
 
-                StatusCode::FORBIDDEN => {
-                    error!("Got 403 Forbidden response");
-                    let error_response: ApiErrorResponse = response.json()?;
-                    handle_403_forbidden_api_response(error_response)?
-                }
+        match response.status() {
+            StatusCode::BAD_REQUEST => {
+                error!("Got 400 Bad Request response");
+                let error_response = response.text()?;
+                warn!("Error response: {error_response}");
+
+                return Err(Error::UnhandledSpotifyResponseStatusCode(400));
+            }
+
+            StatusCode::FORBIDDEN => {
+                error!("Got 403 Forbidden response");
+                let error_response: ApiErrorResponse = response.json()?;
+                handle_403_forbidden_api_response(error_response)?
+            }
 
-                StatusCode::UNAUTHORIZED => {
-                    warn!("Got 401 Unauthorized response");
-                    let error_response = response.json()?;
-                    warn!("Error response: {error_response:?}");
+            StatusCode::UNAUTHORIZED => {
+                warn!("Got 401 Unauthorized response");
+                let error_response = response.json()?;
+                warn!("Error response: {error_response:?}");
 
-                    is_api_error_expired_access_token(error_response)?;
+                is_api_error_expired_access_token(error_response)?;
 
-                    // is_api_error_expired_access_token handles all other errors except the access token being expired
-                    if !common.auto_refresh_access_token
-                        || common.client.handle_access_token_expired()? == AccessTokenExpiryResult::Inapplicable
-                    {
-                        warn!(
-                            "Refreshing access tokens is disabled for this request, or is inapplicable to this client"
-                        );
+                // is_api_error_expired_access_token handles all other errors except the access token being expired
+                if !common.auto_refresh_access_token
+                    || common.client.handle_access_token_expired()? == AccessTokenExpiryResult::Inapplicable
+                {
+                    warn!("Refreshing access tokens is disabled for this request, or is inapplicable to this client");
 
-                        return Err(Error::AccessTokenExpired);
-                    }
+                    return Err(Error::AccessTokenExpired);
                 }
+            }
 
-                StatusCode::TOO_MANY_REQUESTS => {
-                    let headers = response.headers();
-                    let retry_after = extract_rate_limit_retry_after(headers)?;
+            StatusCode::TOO_MANY_REQUESTS => {
+                let headers = response.headers();
+                let retry_after = extract_rate_limit_retry_after(headers)?;
+                let rate_limiter = common.client.rate_limiter();
 
-                    if common.react_to_rate_limit {
-                        info!("Got rate limited, waiting {retry_after} seconds...");
-                        super::rate_limit_sleep_sync(retry_after)?;
-                    } else {
-                        warn!("Got rate limited ({retry_after}) and reacting to rate limits is disabled");
-                        return Err(Error::RateLimit(retry_after));
-                    }
+                if let Some(rate_limiter) = rate_limiter {
+                    rate_limiter.pause(retry_after);
                 }
 
-                _ => {
-                    let response = (common.sync_response_handler)(response);
-                    trace!("Handled response: {response:?}");
+                if !common.react_to_rate_limit {
+                    warn!("Got rate limited ({retry_after:?}) and reacting to rate limits is disabled");
+                    return Err(Error::RateLimit(retry_after));
+                } else if !rate_limit_policy.allows_retry(rate_limit_retries) {
+                    warn!("Got rate limited ({retry_after:?}) after {rate_limit_retries} retries");
+                    return Err(Error::RateLimit(retry_after));
+                } else if !rate_limit_policy.allows_wait(retry_after) {
+                    warn!("Got rate limited ({retry_after:?}) and it is longer than the maximum rate limit wait");
+                    return Err(Error::RateLimit(retry_after));
+                }
 
-                    let response = response?;
+                let wait = rate_limit_policy.backoff.wait(retry_after, rate_limit_retries);
+                rate_limit_retries += 1;
+                info!("Got rate limited, waiting {wait:?}...");
 
-                    // bypass serialization for 204 responses, since it's possible the return type is the unit type, but
-                    // serde/serde_json won't deserialize the unit type from an empty string, instead failing with an
-                    // EOF error
-                    let response_body = if response.status() == StatusCode::NO_CONTENT {
-                        TResponse::try_from_empty_response()?
+                // with a shared rate limiter, the wait for the rate limit itself happens at the start of the loop along
+                // with every other request sharing the rate limiter. any backoff on top of it is waited here
+                if rate_limiter.is_none() || wait > retry_after {
+                    common.client.rate_limit_sleeper().sleep(wait)?;
+                }
+            }
+
+            _ => {
+                let response = (common.sync_response_handler)(response);
+                trace!("Handled response: {response:?}");
+
+                let response = response?;
+
+                // bypass serialization for 204 responses and other responses without a body (such as the 200
+                // responses to saving items in the user's library), since it's possible the return type is the unit
+                // type, but serde/serde_json won't deserialize the unit type from an empty string, instead failing
+                // with an EOF error
+                let (response_body, raw_body): (TResponse, Option<Value>) =
+                    if response.status() == StatusCode::NO_CONTENT || response.content_length() == Some(0) {
+                        (
+                            TResponse::try_from_empty_response()?,
+                            keep_raw_body.then_some(Value::Null),
+                        )
+                    } else if keep_raw_body {
+                        let raw_body: Value = serde_json::from_str(&response.text()?)?;
+                        let response_body = deserialize_value(&raw_body, common.client.deserialization_mode())?;
+                        (response_body, Some(raw_body))
                     } else {
-                        response.json()?
+                        let response_body = match common.client.deserialization_mode() {
+                            DeserializationMode::Lenient => response.json()?,
+                            DeserializationMode::Strict => deserialize_strict(&response.text()?)?,
+                        };
+                        (response_body, None)
                     };
 
-                    trace!("Body: {response_body:?}");
-                    return Ok(response_body.try_into()?);
+                trace!("Body: {response_body:?}");
+                let mut result = response_body.try_into()?;
+
+                if let (Some(hook), Some(market)) = (&common.market_hook, market_query(&common.query_params)) {
+                    hook(&mut result, market);
+                }
+
+                #[cfg(feature = "cache")]
+                if let (Some(cache), true) = (&common.result_cache, is_cacheable(&common.query_params)) {
+                    (cache.store)(&common.client, market_query(&common.query_params), &result);
                 }
+
+                return Ok((result, raw_body));
             }
         }
     }
@@ -453,7 +835,9 @@ pub struct RequestBuilder<TClient, TResponse, TBody = (), TReturn = TResponse> {
     method: Method,
     base_url: Cow<'static, str>,
     query_params: HashMap<&'static str, Cow<'static, str>>,
+    headers: HeaderMap,
     body: Option<TBody>,
+    raw_body: Option<(&'static str, String)>,
 
     #[cfg(feature = "async")]
     async_response_handler: AsyncResponseHandler,
@@ -462,14 +846,35 @@ pub struct RequestBuilder<TClient, TResponse, TBody = (), TReturn = TResponse> {
 
     react_to_rate_limit: bool,
     auto_refresh_access_token: bool,
+    check_scope: bool,
+    required_scope: Option<Scope>,
+
+    #[cfg(feature = "cache")]
+    result_cache: Option<ResultCacheHooks<TClient, TReturn>>,
+
+    market_hook: Option<MarketHook<TReturn>>,
 
     phantom: PhantomData<(TReturn, TResponse)>,
 }
 
 impl<TClient, TResponse, TBody, TReturn> RequestBuilder<TClient, TResponse, TBody, TReturn> {
-    fn build_url(&self) -> Url {
-        Url::parse_with_params(&self.base_url, &self.query_params)
-            .unwrap_or_else(|_| panic!("failed to build URL from base: {}", self.base_url))
+    /// Builds the request URL by resolving the builder's endpoint relative to the given API base URL. Endpoints that
+    /// are already absolute URLs, such as a page's next page URL, are used as-is.
+    fn build_url(&self, api_base_url: &Url) -> Url {
+        let mut url = resolve_endpoint(api_base_url, &self.base_url);
+        url.query_pairs_mut().extend_pairs(&self.query_params);
+        url
+    }
+
+    /// Returns the request's result from the cache, if the request has a cache and the result is in it.
+    #[cfg(feature = "cache")]
+    fn cached_result(&self) -> Option<TReturn> {
+        if !is_cacheable(&self.query_params) {
+            return None;
+        }
+
+        let cache = self.result_cache.as_ref()?;
+        (cache.lookup)(&self.client, market_query(&self.query_params))
     }
 }
 
@@ -485,7 +890,9 @@ impl<TClient, TResponse, TBody, TReturn> private::BaseRequestBuilderContainer<TC
             method,
             base_url: base_url.into(),
             query_params: HashMap::new(),
+            headers: HeaderMap::new(),
             body: None,
+            raw_body: None,
 
             #[cfg(feature = "async")]
             async_response_handler: Box::new(async_response_handler_noop),
@@ -494,6 +901,13 @@ impl<TClient, TResponse, TBody, TReturn> private::BaseRequestBuilderContainer<TC
 
             react_to_rate_limit: true,
             auto_refresh_access_token: true,
+            check_scope: false,
+            required_scope: None,
+
+            #[cfg(feature = "cache")]
+            result_cache: None,
+
+            market_hook: None,
 
             phantom: PhantomData,
         }
@@ -536,6 +950,21 @@ where
 {
 }
 
+#[cfg(feature = "async")]
+impl<TBuilder, TClient, TResponse, TBody, TInner, TItem>
+    AsyncPageRequestBuilder<TClient, TResponse, TBody, TInner, TItem> for TBuilder
+where
+    TBuilder: AsyncRequestBuilder<TClient, TResponse, TBody, Page<TInner, TItem>> + Send,
+    TBody: Debug + Serialize + Send,
+    TResponse: Debug + DeserializeOwned + TryFromEmptyResponse + Send + Sync,
+    TInner: PageInformation<TItem> + DeserializeOwned + Debug + TryFromEmptyResponse + Send + Sync,
+    TItem: Send + Sync,
+    Page<TInner, TItem>: TryFrom<TResponse>,
+    TClient: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Clone + Send + Sync,
+    Error: From<<Page<TInner, TItem> as TryFrom<TResponse>>::Error>,
+{
+}
+
 #[cfg(feature = "sync")]
 impl<TBuilder, TClient, TResponse, TBody, TReturn> SyncRequestBuilder<TClient, TResponse, TBody, TReturn> for TBuilder
 where
@@ -547,3 +976,748 @@ where
     Error: From<<TReturn as TryFrom<TResponse>>::Error>,
 {
 }
+
+#[cfg(all(test, feature = "async"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use reqwest::IntoUrl;
+
+    use super::*;
+    use crate::{
+        client::{
+            private::{AccessTokenExpiryAsync, BaseUrls, BuildHttpRequestAsync, RateLimitPolicy},
+            rate_limit::{AsyncRateLimitSleeper, RateLimitBackoff},
+        },
+        model::{
+            album::{CommonAlbumInformation, FullAlbum, FullAlbumInformation},
+            artist::CommonArtistInformation,
+            error::PlayerError,
+            id::IdTrait,
+            playlist::{CommonPlaylistInformation, CurrentUserPlaylists, PartialPlaylist},
+            search::{SearchResults, SearchResultsObject},
+            test_responses::{FULL_ALBUM_RESPONSE, FULL_TRACK_RESPONSE},
+            track::{CommonTrackInformation, FullTrack},
+            CountryCode, Page,
+        },
+        test_support::{json_response, request_line, serve_responses},
+    };
+
+    const RATE_LIMIT_RESPONSE_3: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 3\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const RATE_LIMIT_RESPONSE_5: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 5\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    const HTML_SERVICE_UNAVAILABLE_RESPONSE: &str = concat!(
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: 30\r\n",
+        "Connection: close\r\n\r\n<html><body>Down</body></html>"
+    );
+    const OK_RESPONSE: &str =
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 6\r\nConnection: close\r\n\r\n[1, 2]";
+
+    /// Records the rate limit waits instead of sleeping.
+    #[derive(Debug, Default)]
+    struct RecordingSleeper(Mutex<Vec<Duration>>);
+
+    #[async_trait::async_trait]
+    impl AsyncRateLimitSleeper for RecordingSleeper {
+        async fn sleep(&self, duration: Duration) -> Result<()> {
+            self.0.lock().unwrap().push(duration);
+            Ok(())
+        }
+    }
+
+    #[derive(Clone)]
+    struct MockClient {
+        http_client: reqwest::Client,
+        sleeper: Arc<RecordingSleeper>,
+        rate_limiter: Option<Arc<RateLimiter>>,
+        rate_limit_policy: RateLimitPolicy,
+        granted_scopes: Option<Vec<Scope>>,
+        base_urls: BaseUrls,
+        deserialization_mode: DeserializationMode,
+    }
+
+    impl MockClient {
+        fn new() -> Self {
+            Self {
+                http_client: reqwest::Client::builder().no_proxy().build().unwrap(),
+                sleeper: Arc::default(),
+                rate_limiter: None,
+                rate_limit_policy: RateLimitPolicy::default(),
+                granted_scopes: None,
+                base_urls: BaseUrls::default(),
+                deserialization_mode: DeserializationMode::default(),
+            }
+        }
+
+        fn with_rate_limiter(rate_limiter: Arc<RateLimiter>) -> Self {
+            Self {
+                rate_limiter: Some(rate_limiter),
+                ..Self::new()
+            }
+        }
+
+        fn with_rate_limit_policy(rate_limit_policy: RateLimitPolicy) -> Self {
+            Self {
+                rate_limit_policy,
+                ..Self::new()
+            }
+        }
+
+        fn with_granted_scopes(granted_scopes: Vec<Scope>) -> Self {
+            Self {
+                granted_scopes: Some(granted_scopes),
+                ..Self::new()
+            }
+        }
+
+        fn with_deserialization_mode(deserialization_mode: DeserializationMode) -> Self {
+            Self {
+                deserialization_mode,
+                ..Self::new()
+            }
+        }
+    }
+
+    impl crate::private::Sealed for MockClient {}
+
+    impl BuildHttpRequestAsync for MockClient {
+        fn build_http_request<U>(&self, method: Method, url: U) -> reqwest::RequestBuilder
+        where
+            U: IntoUrl,
+        {
+            self.http_client.request(method, url)
+        }
+
+        fn api_base_url(&self) -> &Url {
+            &self.base_urls.api
+        }
+
+        fn rate_limit_sleeper(&self) -> &dyn AsyncRateLimitSleeper {
+            self.sleeper.as_ref()
+        }
+
+        fn rate_limiter(&self) -> Option<&RateLimiter> {
+            self.rate_limiter.as_deref()
+        }
+
+        fn rate_limit_policy(&self) -> RateLimitPolicy {
+            self.rate_limit_policy
+        }
+
+        fn deserialization_mode(&self) -> DeserializationMode {
+            self.deserialization_mode
+        }
+
+        fn ensure_scope(&self, scope: Scope) -> Result<()> {
+            match &self.granted_scopes {
+                Some(granted_scopes) if !granted_scopes.contains(&scope) => Err(Error::MissingScope),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl AccessTokenExpiryAsync for MockClient {
+        async fn handle_access_token_expired(&self) -> Result<AccessTokenExpiryResult> {
+            Ok(AccessTokenExpiryResult::Inapplicable)
+        }
+    }
+
+    /// Serves the given raw HTTP responses to consecutive connections and returns the server's URL.
+    fn serve_raw_responses(responses: &[&str]) -> String {
+        serve_responses(|_| responses.iter().map(|response| response.to_string()).collect()).0
+    }
+
+    type PlaylistsPage = Page<CurrentUserPlaylists, PartialPlaylist>;
+
+    /// Builds a page of the current user's playlists containing playlists with the given names.
+    fn playlists_page(names: &[&str], next: Option<&str>) -> String {
+        playlists_page_at(names, 0, next, None)
+    }
+
+    fn playlists_page_at(names: &[&str], offset: usize, next: Option<&str>, previous: Option<&str>) -> String {
+        let items: Vec<_> = names
+            .iter()
+            .map(|name| {
+                serde_json::json!({
+                    "collaborative": false,
+                    "description": null,
+                    "external_urls": {},
+                    "id": "37i9dQZF1DWZipvLjDtZYe",
+                    "images": [],
+                    "name": name,
+                    "owner": { "external_urls": {}, "id": "spotify", "type": "user" },
+                    "public": true,
+                    "snapshot_id": "MTY3NzQ5NTIwMA==",
+                    "type": "playlist"
+                })
+            })
+            .collect();
+
+        json_response(
+            &serde_json::json!({
+                "items": items,
+                "next": next,
+                "previous": previous,
+                "limit": 2,
+                "offset": offset,
+                "total": 3
+            })
+            .to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn rate_limited_request_is_retried_after_waiting() {
+        let url = serve_raw_responses(&[RATE_LIMIT_RESPONSE_3, RATE_LIMIT_RESPONSE_5, OK_RESPONSE]);
+        let client = MockClient::new();
+
+        let response = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client.clone())
+            .send_async()
+            .await
+            .unwrap();
+
+        assert_eq!(response, vec![1, 2]);
+        assert_eq!(
+            *client.sleeper.0.lock().unwrap(),
+            vec![Duration::from_secs(3), Duration::from_secs(5)]
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limited_request_is_not_retried_when_disabled() {
+        let url = serve_raw_responses(&[RATE_LIMIT_RESPONSE_3]);
+        let client = MockClient::new();
+
+        let result = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client.clone())
+            .react_to_rate_limit(false)
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::RateLimit(retry_after)) if retry_after == Duration::from_secs(3)));
+        assert!(client.sleeper.0.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rate_limited_request_gives_up_after_max_retries() {
+        let url = serve_raw_responses(&[RATE_LIMIT_RESPONSE_3, RATE_LIMIT_RESPONSE_5, OK_RESPONSE]);
+        let client = MockClient::with_rate_limit_policy(RateLimitPolicy {
+            max_retries: Some(1),
+            ..RateLimitPolicy::default()
+        });
+
+        let result = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client.clone())
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::RateLimit(retry_after)) if retry_after == Duration::from_secs(5)));
+        assert_eq!(*client.sleeper.0.lock().unwrap(), vec![Duration::from_secs(3)]);
+    }
+
+    #[tokio::test]
+    async fn repeatedly_rate_limited_request_backs_off() {
+        let url = serve_raw_responses(&[
+            RATE_LIMIT_RESPONSE_3,
+            RATE_LIMIT_RESPONSE_3,
+            RATE_LIMIT_RESPONSE_3,
+            OK_RESPONSE,
+        ]);
+        let client = MockClient::with_rate_limit_policy(RateLimitPolicy {
+            backoff: RateLimitBackoff::Exponential {
+                max_jitter: Duration::ZERO,
+                max_wait: Duration::from_secs(10),
+            },
+            ..RateLimitPolicy::default()
+        });
+
+        RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client.clone())
+            .send_async()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *client.sleeper.0.lock().unwrap(),
+            vec![Duration::from_secs(3), Duration::from_secs(6), Duration::from_secs(10)]
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limit_longer_than_max_wait_is_returned() {
+        let url = serve_raw_responses(&[RATE_LIMIT_RESPONSE_5]);
+        let client = MockClient::with_rate_limit_policy(RateLimitPolicy {
+            max_wait: Some(Duration::from_secs(4)),
+            ..RateLimitPolicy::default()
+        });
+
+        let result = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client.clone())
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::RateLimit(retry_after)) if retry_after == Duration::from_secs(5)));
+        assert!(client.sleeper.0.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rate_limit_pauses_clients_sharing_rate_limiter() {
+        let rate_limiter = Arc::new(RateLimiter::new());
+        let first_client = MockClient::with_rate_limiter(Arc::clone(&rate_limiter));
+        let second_client = MockClient::with_rate_limiter(rate_limiter);
+
+        let url = serve_raw_responses(&[RATE_LIMIT_RESPONSE_3, OK_RESPONSE]);
+        RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, first_client.clone())
+            .send_async()
+            .await
+            .unwrap();
+
+        // the recording sleeper doesn't actually wait, so the shared rate limit is still active for the second client
+        let url = serve_raw_responses(&[OK_RESPONSE]);
+        RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, second_client.clone())
+            .send_async()
+            .await
+            .unwrap();
+
+        for client in [first_client, second_client] {
+            let waits = client.sleeper.0.lock().unwrap();
+            assert_eq!(waits.len(), 1);
+            assert!(waits[0] > Duration::from_secs(2) && waits[0] <= Duration::from_secs(3));
+        }
+    }
+
+    #[tokio::test]
+    async fn shared_rate_limit_is_returned_when_reacting_is_disabled() {
+        let rate_limiter = Arc::new(RateLimiter::new());
+        rate_limiter.pause(Duration::from_millis(2500));
+        let client = MockClient::with_rate_limiter(rate_limiter);
+
+        let result = RequestBuilder::<_, Vec<u32>>::new(Method::GET, "http://127.0.0.1:1", client.clone())
+            .react_to_rate_limit(false)
+            .send_async()
+            .await;
+
+        match result {
+            Err(Error::RateLimit(retry_after)) => {
+                assert!(retry_after > Duration::from_secs(2) && retry_after <= Duration::from_millis(2500))
+            }
+
+            other => panic!("expected rate limit error, got {other:?}"),
+        }
+
+        assert!(client.sleeper.0.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn missing_scope_is_returned_without_sending_request() {
+        let (url, requests) = serve_responses(|_| vec![OK_RESPONSE.to_owned()]);
+        let client = MockClient::with_granted_scopes(vec![Scope::UserReadEmail]);
+
+        let result = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url.clone(), client.clone())
+            .with_required_scope(Scope::UserTopRead)
+            .check_scope(true)
+            .send_async()
+            .await;
+
+        assert!(matches!(result, Err(Error::MissingScope)));
+        assert!(requests.lock().unwrap().is_empty());
+
+        let response = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client)
+            .with_required_scope(Scope::UserTopRead)
+            .send_async()
+            .await
+            .unwrap();
+
+        assert_eq!(response, vec![1, 2]);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn cached_result_is_returned_without_sending_request() {
+        // the server only responds once, so a second request would fail
+        let url = serve_raw_responses(&[OK_RESPONSE]);
+        let client = MockClient::new();
+        let cached: Arc<Mutex<Option<Vec<u32>>>> = Arc::default();
+
+        let builder = || {
+            let lookup_cache = Arc::clone(&cached);
+            let store_cache = Arc::clone(&cached);
+
+            RequestBuilder::<_, Vec<u32>>::new(Method::GET, url.clone(), client.clone()).with_result_cache(
+                ResultCacheHooks {
+                    lookup: Box::new(move |_, _| lookup_cache.lock().unwrap().clone()),
+                    store: Box::new(move |_, _, result: &Vec<u32>| *store_cache.lock().unwrap() = Some(result.clone())),
+                },
+            )
+        };
+
+        assert_eq!(builder().send_async().await.unwrap(), vec![1, 2]);
+        assert_eq!(builder().send_async().await.unwrap(), vec![1, 2]);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn from_token_market_bypasses_result_cache() {
+        let (url, requests) = serve_responses(|_| vec![OK_RESPONSE.to_owned(), OK_RESPONSE.to_owned()]);
+        let client = MockClient::new();
+        let cached: Arc<Mutex<Option<Vec<u32>>>> = Arc::new(Mutex::new(Some(vec![3, 4])));
+
+        let builder = || {
+            let lookup_cache = Arc::clone(&cached);
+            let store_cache = Arc::clone(&cached);
+
+            RequestBuilder::<_, Vec<u32>>::new(Method::GET, url.clone(), client.clone())
+                .append_query(object::MARKET_QUERY, crate::model::Market::FromToken.to_string())
+                .with_result_cache(ResultCacheHooks {
+                    lookup: Box::new(move |_, _| lookup_cache.lock().unwrap().clone()),
+                    store: Box::new(move |_, _, result: &Vec<u32>| *store_cache.lock().unwrap() = Some(result.clone())),
+                })
+        };
+
+        // another user's result in the cache must not be returned, and this user's result must not be stored
+        assert_eq!(builder().send_async().await.unwrap(), vec![1, 2]);
+        assert_eq!(builder().send_async().await.unwrap(), vec![1, 2]);
+        assert_eq!(*cached.lock().unwrap(), Some(vec![3, 4]));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn all_items_walks_every_page() {
+        let (url, _) = serve_responses(|url| {
+            vec![
+                playlists_page(&["First", "Second"], Some(url)),
+                playlists_page(&["Third"], None),
+            ]
+        });
+        let client = MockClient::new();
+
+        let page = RequestBuilder::<_, CurrentUserPlaylists, (), PlaylistsPage>::new(Method::GET, url, client.clone())
+            .send_async()
+            .await
+            .unwrap();
+        let playlists = page.all_items_async(&client, None).await.unwrap();
+
+        assert_eq!(
+            playlists.iter().map(|playlist| playlist.name()).collect::<Vec<_>>(),
+            ["First", "Second", "Third"]
+        );
+    }
+
+    #[tokio::test]
+    async fn paginate_streams_items_across_pages() {
+        let (url, requests) = serve_responses(|url| {
+            vec![
+                playlists_page(&["First", "Second"], Some(url)),
+                playlists_page(&["Third"], None),
+            ]
+        });
+        let client = MockClient::new();
+
+        let mut playlists = Box::pin(
+            RequestBuilder::<_, CurrentUserPlaylists, (), PlaylistsPage>::new(Method::GET, url, client).paginate(),
+        );
+
+        // the second page is only requested once the first page's items have been consumed
+        assert_eq!(playlists.try_next().await.unwrap().unwrap().name(), "First");
+        assert_eq!(playlists.try_next().await.unwrap().unwrap().name(), "Second");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        let rest: Vec<_> = playlists.try_collect().await.unwrap();
+        assert_eq!(
+            rest.iter().map(|playlist| playlist.name()).collect::<Vec<_>>(),
+            ["Third"]
+        );
+    }
+
+    #[tokio::test]
+    async fn previous_page_is_requested() {
+        let (url, _) = serve_responses(|url| {
+            vec![
+                playlists_page_at(&["Third"], 2, None, Some(url)),
+                playlists_page(&["First", "Second"], None),
+            ]
+        });
+        let client = MockClient::new();
+
+        let page = RequestBuilder::<_, CurrentUserPlaylists, (), PlaylistsPage>::new(Method::GET, url, client.clone())
+            .send_async()
+            .await
+            .unwrap();
+        assert_eq!((page.offset(), page.limit(), page.total()), (2, 2, 3));
+
+        let previous = page.previous_page_async(&client).await.unwrap().unwrap();
+        assert_eq!(previous.offset(), 0);
+        assert_eq!(
+            previous
+                .items()
+                .iter()
+                .map(|playlist| playlist.name())
+                .collect::<Vec<_>>(),
+            ["First", "Second"]
+        );
+        assert!(previous.previous_page_async(&client).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn all_items_stops_at_max() {
+        // the server only responds to the first page, so requesting the next page would fail
+        let (url, _) = serve_responses(|url| vec![playlists_page(&["First", "Second"], Some(url))]);
+        let client = MockClient::new();
+
+        let page = RequestBuilder::<_, CurrentUserPlaylists, (), PlaylistsPage>::new(Method::GET, url, client.clone())
+            .send_async()
+            .await
+            .unwrap();
+        let playlists = page.all_items_async(&client, Some(1)).await.unwrap();
+
+        assert_eq!(playlists.len(), 1);
+        assert_eq!(playlists[0].name(), "First");
+    }
+
+    #[tokio::test]
+    async fn album_tracks_next_page_is_requested_in_album_market() {
+        let (url, requests) = serve_responses(|url| {
+            let album = serde_json::json!({
+                "album_type": "album",
+                "artists": [],
+                "copyrights": [],
+                "external_ids": {},
+                "external_urls": {},
+                "genres": [],
+                "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                "images": [],
+                "label": "Test label",
+                "name": "Test album",
+                "popularity": 50,
+                "release_date": "2021",
+                "release_date_precision": "year",
+                "tracks": {
+                    "items": [],
+                    "limit": 50,
+                    "next": format!("{url}albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=50&limit=50"),
+                    "offset": 0,
+                    "total": 60
+                },
+                "type": "album"
+            });
+            let tracks = serde_json::json!({ "items": [], "limit": 50, "next": null, "offset": 50, "total": 60 });
+
+            vec![json_response(&album.to_string()), json_response(&tracks.to_string())]
+        });
+        let client = MockClient::new();
+
+        let album = RequestBuilder::<_, FullAlbum>::new(
+            Method::GET,
+            format!("{url}albums/0tDsHtvN9YNuZjlqHvDY2P"),
+            client.clone(),
+        )
+        .append_query(object::MARKET_QUERY, "FI")
+        .with_market_hook(Box::new(|album: &mut FullAlbum, market| {
+            album.set_tracks_market(market)
+        }))
+        .send_async()
+        .await
+        .unwrap();
+        album.tracks().next_page_async(&client).await.unwrap().unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(
+            request_line(&requests[1]),
+            "GET /albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=50&limit=50&market=FI HTTP/1.1"
+        );
+    }
+
+    #[tokio::test]
+    async fn full_track_and_album_are_deserialized_strictly() {
+        let (url, _) =
+            serve_responses(|_| vec![json_response(FULL_TRACK_RESPONSE), json_response(FULL_ALBUM_RESPONSE)]);
+        let client = MockClient::with_deserialization_mode(DeserializationMode::Strict);
+
+        let track = RequestBuilder::<_, FullTrack>::new(
+            Method::GET,
+            format!("{url}tracks/2pDPOMX0kWA7kcPBcDCQBu"),
+            client.clone(),
+        )
+        .send_async()
+        .await
+        .unwrap();
+        let album =
+            RequestBuilder::<_, FullAlbum>::new(Method::GET, format!("{url}albums/0tDsHtvN9YNuZjlqHvDY2P"), client)
+                .send_async()
+                .await
+                .unwrap();
+
+        assert_eq!(track.name(), "The Funeral");
+        assert_eq!(album.name(), "Everything All the Time");
+    }
+
+    #[tokio::test]
+    async fn catalog_item_market_is_sent_and_relinking_applied() {
+        let track = serde_json::json!({
+            "album": {
+                "album_type": "album",
+                "artists": [],
+                "external_urls": {},
+                "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                "images": [],
+                "name": "Test album",
+                "release_date": "2021",
+                "release_date_precision": "year",
+                "type": "album"
+            },
+            "artists": [],
+            "disc_number": 1,
+            "duration_ms": 180000,
+            "explicit": false,
+            "external_ids": {},
+            "external_urls": {},
+            "id": "2pDPOMX0kWA7kcPBcDCQBu",
+            "is_local": false,
+            "is_playable": true,
+            "linked_from": {
+                "external_urls": {},
+                "id": "6kLCHFM39wkFjOuyPGLGeQ"
+            },
+            "name": "Test track",
+            "popularity": 50,
+            "preview_url": null,
+            "track_number": 1,
+            "type": "track"
+        });
+        let (url, requests) = serve_responses(|_| vec![json_response(&track.to_string())]);
+        let client = MockClient::new();
+
+        let track = CatalogItemRequestBuilder::<_, FullTrack>::new(
+            Method::GET,
+            format!("{url}tracks/2pDPOMX0kWA7kcPBcDCQBu"),
+            client,
+        )
+        .market(CountryCode::FI)
+        .send_async()
+        .await
+        .unwrap();
+
+        assert_eq!(
+            request_line(&requests.lock().unwrap()[0]),
+            "GET /tracks/2pDPOMX0kWA7kcPBcDCQBu?market=FI HTTP/1.1"
+        );
+        assert_eq!(
+            track.linked_from().map(|linked_from| linked_from.id.as_str()),
+            Some("6kLCHFM39wkFjOuyPGLGeQ")
+        );
+    }
+
+    #[tokio::test]
+    async fn search_results_are_paged_with_the_search_client() {
+        fn artist(name: &str) -> serde_json::Value {
+            serde_json::json!({
+                "external_urls": {},
+                "followers": { "href": null, "total": 0 },
+                "genres": [],
+                "id": "0TnOYISbd1XYRBk9myaseg",
+                "images": [],
+                "name": name,
+                "popularity": 0,
+                "type": "artist"
+            })
+        }
+
+        let (url, _) = serve_responses(|url| {
+            let first = serde_json::json!({
+                "artists": {
+                    "items": [artist("First")],
+                    "limit": 1,
+                    "next": format!("{url}search?q=test&type=artist&offset=1&limit=1"),
+                    "offset": 0,
+                    "total": 2
+                }
+            });
+            let second = serde_json::json!({
+                "artists": { "items": [artist("Second")], "limit": 1, "next": null, "offset": 1, "total": 2 }
+            });
+
+            vec![json_response(&first.to_string()), json_response(&second.to_string())]
+        });
+        let client = MockClient::new();
+
+        let results = RequestBuilder::<_, SearchResultsObject, (), SearchResults>::new(
+            Method::GET,
+            format!("{url}search"),
+            client.clone(),
+        )
+        .send_async()
+        .await
+        .unwrap();
+
+        let first_page = results.artists().unwrap();
+        assert_eq!(first_page.items()[0].name(), "First");
+
+        let second_page = first_page.next_page_async(&client).await.unwrap().unwrap();
+        assert_eq!(second_page.items()[0].name(), "Second");
+        assert!(second_page.next_page_async(&client).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn raw_response_body_is_returned_alongside_result() {
+        let url = serve_raw_responses(&[OK_RESPONSE]);
+        let client = MockClient::new();
+
+        let (response, raw_body) = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client)
+            .send_async_with_raw()
+            .await
+            .unwrap();
+
+        assert_eq!(response, vec![1, 2]);
+        assert_eq!(raw_body, serde_json::json!([1, 2]));
+    }
+
+    #[tokio::test]
+    async fn html_error_response_is_not_parsed_as_json() {
+        let url = serve_raw_responses(&[HTML_SERVICE_UNAVAILABLE_RESPONSE]);
+        let client = MockClient::new();
+
+        let result = RequestBuilder::<_, Vec<u32>>::new(Method::GET, url, client)
+            .send_async()
+            .await;
+
+        match result {
+            Err(Error::NonJsonResponse { status, content_type }) => {
+                assert_eq!(status, 503);
+                assert_eq!(content_type, "text/html; charset=utf-8");
+            }
+
+            other => panic!("expected non-JSON response error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn forbidden_player_reason_is_returned() {
+        fn handle(json: &str) -> Result<()> {
+            handle_403_forbidden_api_response(serde_json::from_str(json).unwrap())
+        }
+
+        assert!(matches!(
+            handle(
+                r#"{"error":{"status":403,"message":"Player command failed: No previous track","reason":"NO_PREV_TRACK"}}"#
+            ),
+            Err(Error::PlayerRestricted(PlayerError::NoPrevTrack))
+        ));
+        assert!(matches!(
+            handle(r#"{"error":{"status":403,"message":"Player command failed","reason":"VOLUME_CONTROL_DISABLED"}}"#),
+            Err(Error::PlayerRestricted(PlayerError::VolumeControlDisallow))
+        ));
+        assert!(matches!(
+            handle(r#"{"error":{"status":403,"message":"Player command failed","reason":"SOMETHING_NEW"}}"#),
+            Err(Error::PlayerRestricted(PlayerError::Unknown))
+        ));
+        assert!(matches!(
+            handle(
+                r#"{"error":{"status":403,"message":"Player command failed: Premium required","reason":"PREMIUM_REQUIRED"}}"#
+            ),
+            Err(Error::PremiumRequired)
+        ));
+        assert!(matches!(
+            handle(r#"{"error":{"status":403,"message":"Forbidden."}}"#),
+            Err(Error::Forbidden)
+        ));
+    }
+}
@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    model::{
+        album::NewReleases,
+        category::{Categories, CategoryPlaylists},
+        CountryCode,
+    },
+};
+
+const COUNTRY_QUERY: &str = "country";
+const LOCALE_QUERY: &str = "locale";
+const LIMIT_QUERY: &str = "limit";
+const OFFSET_QUERY: &str = "offset";
+
+impl TryFromEmptyResponse for Categories {}
+impl TryFromEmptyResponse for CategoryPlaylists {}
+impl TryFromEmptyResponse for NewReleases {}
+
+/// A builder type for requests to Spotify's browse endpoints, such as the
+/// [categories-function](crate::client::UnscopedClient::categories) in
+/// [UnscopedClient](crate::client::UnscopedClient).
+pub struct BrowseRequestBuilder<TClient, TResponse, TReturn = TResponse>(
+    RequestBuilder<TClient, TResponse, (), TReturn>,
+);
+
+impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse, (), TReturn>
+    for BrowseRequestBuilder<TClient, TResponse, TReturn>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, (), TReturn> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, (), TReturn> {
+        &mut self.0
+    }
+}
+
+impl<TClient, TResponse, TReturn> BrowseRequestBuilder<TClient, TResponse, TReturn> {
+    /// Specify a country such that only content relevant to that country will be returned.
+    pub fn country(self, country: CountryCode) -> Self {
+        self.append_query(COUNTRY_QUERY, country.to_string())
+    }
+
+    /// Specify the language the returned content should be in, as an ISO 639-1 language code and an ISO 3166-1 alpha-2
+    /// country code joined by an underscore, for example `es_MX`. If not given, the content is returned in American
+    /// English.
+    pub fn locale<S>(self, locale: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.append_query(LOCALE_QUERY, locale.into())
+    }
+
+    /// The maximum number of items to return in each page.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.append_query(LIMIT_QUERY, limit.to_string())
+    }
+
+    /// The index of the first item to return. By combining this with [limit](Self::limit), you may request specific
+    /// pages of content.
+    ///
+    /// Default: 0.
+    pub fn offset(self, offset: u32) -> Self {
+        self.append_query(OFFSET_QUERY, offset.to_string())
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
         object,
         request_builder::{BaseRequestBuilderContainer, RequestBuilder},
     },
-    model::CountryCode,
+    model::Market,
 };
 
 /// A builder type for catalog searches and item retrievals.
@@ -44,7 +44,10 @@ impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse
 impl<TReturn, C> CatalogItemRequestBuilder<TReturn, C> {
     /// Specify a target market country for this request. Only content that is available in that market will be returned
     /// and [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
-    pub fn market(self, market: CountryCode) -> Self {
-        self.append_query(object::MARKET_QUERY, market.to_string())
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(object::MARKET_QUERY, market.into().to_string())
     }
 }
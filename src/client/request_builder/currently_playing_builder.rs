@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    model::playback::CurrentlyPlayingType,
+};
+
+const ADDITIONAL_TYPES_QUERY: &str = "additional_types";
+
+/// A builder for retrieving the user's playback state or currently playing item. New instances are returned by the
+/// [playback_state-](crate::client::ScopedClient::playback_state) and
+/// [currently_playing_item-functions](crate::client::ScopedClient::currently_playing_item) in
+/// [ScopedClient](crate::client::ScopedClient).
+pub struct CurrentlyPlayingRequestBuilder<TClient, TResponse>(RequestBuilder<TClient, TResponse>);
+
+impl<TClient, TResponse> BaseRequestBuilderContainer<TClient, TResponse>
+    for CurrentlyPlayingRequestBuilder<TClient, TResponse>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, TResponse> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse> {
+        &mut self.0
+    }
+}
+
+impl<TClient, TResponse> CurrentlyPlayingRequestBuilder<TClient, TResponse> {
+    /// The item types to return in addition to tracks. By default, Spotify only returns tracks and any other playing
+    /// item, such as a podcast episode, is returned as if nothing is publicly playing.
+    pub fn additional_types<I>(self, types: I) -> Self
+    where
+        I: IntoIterator<Item = CurrentlyPlayingType>,
+    {
+        let types: Vec<_> = types.into_iter().map(CurrentlyPlayingType::as_str).collect();
+        self.append_query(ADDITIONAL_TYPES_QUERY, types.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn additional_types_are_joined() {
+        let builder =
+            CurrentlyPlayingRequestBuilder::<_, ()>::new(Method::GET, "https://api.spotify.com/v1/me/player", ())
+                .additional_types([CurrentlyPlayingType::Track, CurrentlyPlayingType::Episode]);
+
+        assert_eq!(
+            builder.0.query_params.get(ADDITIONAL_TYPES_QUERY).unwrap(),
+            "track,episode"
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object::{self, FollowedArtistsResponse},
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    model::{artist::FullArtist, CursorPage, ItemType},
+};
+
+const LIMIT_QUERY: &str = "limit";
+const AFTER_QUERY: &str = "after";
+
+/// A builder for retrieving the artists the current user follows. New instances are returned by the
+/// [followed_artists-function](crate::client::ScopedClient::followed_artists) in
+/// [ScopedClient](crate::client::ScopedClient).
+///
+/// The artists are paginated with cursors instead of offsets.
+pub struct FollowedArtistsRequestBuilder<TClient>(
+    RequestBuilder<TClient, FollowedArtistsResponse, (), CursorPage<FullArtist>>,
+);
+
+impl<TClient> BaseRequestBuilderContainer<TClient, FollowedArtistsResponse, (), CursorPage<FullArtist>>
+    for FollowedArtistsRequestBuilder<TClient>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(
+            RequestBuilder::new(method, base_url, client)
+                .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::Artist.to_string()),
+        )
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(
+            RequestBuilder::new_with_body(method, base_url, body, client)
+                .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::Artist.to_string()),
+        )
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, FollowedArtistsResponse, (), CursorPage<FullArtist>> {
+        self.0
+    }
+
+    fn get_base_builder_mut(
+        &mut self,
+    ) -> &mut RequestBuilder<TClient, FollowedArtistsResponse, (), CursorPage<FullArtist>> {
+        &mut self.0
+    }
+}
+
+impl<TClient> FollowedArtistsRequestBuilder<TClient> {
+    /// The maximum number of artists to return.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.append_query(LIMIT_QUERY, limit.to_string())
+    }
+
+    /// Return artists after this artist ID. The cursor for the next page of artists is returned by
+    /// [CursorPage::next_cursor].
+    pub fn after<S>(self, after: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.append_query(AFTER_QUERY, after.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn followed_artists_are_requested_by_type() {
+        let builder = FollowedArtistsRequestBuilder::new(Method::GET, "https://api.spotify.com/v1/me/following", ())
+            .after("0TnOYISbd1XYRBk9myaseg");
+
+        assert_eq!(
+            builder.0.query_params.get(object::FOLLOWED_TYPE_QUERY).unwrap(),
+            "artist"
+        );
+        assert_eq!(
+            builder.0.query_params.get(AFTER_QUERY).unwrap(),
+            "0TnOYISbd1XYRBk9myaseg"
+        );
+    }
+
+    #[test]
+    fn deserialize_followed_artists_response() {
+        let json = r#"{
+            "artists": {
+                "items": [
+                    {
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/artist/0TnOYISbd1XYRBk9myaseg"
+                        },
+                        "followers": {
+                            "href": null,
+                            "total": 1337
+                        },
+                        "genres": [],
+                        "href": "https://api.spotify.com/v1/artists/0TnOYISbd1XYRBk9myaseg",
+                        "id": "0TnOYISbd1XYRBk9myaseg",
+                        "images": [],
+                        "name": "Test artist",
+                        "popularity": 80,
+                        "type": "artist",
+                        "uri": "spotify:artist:0TnOYISbd1XYRBk9myaseg"
+                    }
+                ],
+                "next": "https://api.spotify.com/v1/me/following?type=artist&after=0TnOYISbd1XYRBk9myaseg&limit=1",
+                "total": 2,
+                "cursors": {
+                    "after": "0TnOYISbd1XYRBk9myaseg"
+                },
+                "limit": 1,
+                "href": "https://api.spotify.com/v1/me/following?type=artist&limit=1"
+            }
+        }"#;
+
+        let page: CursorPage<FullArtist> = serde_json::from_str::<FollowedArtistsResponse>(json).unwrap().into();
+
+        assert_eq!(page.items().len(), 1);
+        assert_eq!(page.next_cursor(), Some("0TnOYISbd1XYRBk9myaseg"));
+    }
+}
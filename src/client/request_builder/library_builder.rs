@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    },
+    model::{
+        library::{SavedAlbums, SavedEpisodes, SavedShows, SavedTracks},
+        Market,
+    },
+};
+
+const LIMIT_QUERY: &str = "limit";
+const OFFSET_QUERY: &str = "offset";
+
+impl TryFromEmptyResponse for SavedTracks {}
+impl TryFromEmptyResponse for SavedAlbums {}
+impl TryFromEmptyResponse for SavedEpisodes {}
+impl TryFromEmptyResponse for SavedShows {}
+
+/// A builder type for retrieving items saved in the current user's library, such as the
+/// [saved_tracks-function](crate::client::ScopedClient::saved_tracks) in
+/// [ScopedClient](crate::client::ScopedClient).
+pub struct LibraryRequestBuilder<TClient, TResponse, TReturn = TResponse>(
+    RequestBuilder<TClient, TResponse, (), TReturn>,
+);
+
+impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse, (), TReturn>
+    for LibraryRequestBuilder<TClient, TResponse, TReturn>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, (), TReturn> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, (), TReturn> {
+        &mut self.0
+    }
+}
+
+impl<TClient, TResponse, TReturn> LibraryRequestBuilder<TClient, TResponse, TReturn> {
+    /// The maximum number of items to return in each page.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.append_query(LIMIT_QUERY, limit.to_string())
+    }
+
+    /// The index of the first item to return. By combining this with [limit](Self::limit), you may request specific
+    /// pages of content.
+    ///
+    /// Default: 0.
+    pub fn offset(self, offset: u32) -> Self {
+        self.append_query(OFFSET_QUERY, offset.to_string())
+    }
+
+    /// Specify a target market country for this request. Only content that is available in that market will be returned
+    /// and [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(object::MARKET_QUERY, market.into().to_string())
+    }
+}
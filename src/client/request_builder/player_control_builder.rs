@@ -2,18 +2,36 @@ use std::borrow::Cow;
 
 use reqwest::Method;
 
-use crate::client::{
-    object,
-    request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    error::{Error, Result},
+    model::id::{IdTrait, PlayableItem},
 };
 
+/// The maximum number of items Spotify accepts in a single play request.
+pub(crate) const MAX_PLAY_ITEMS: usize = 100;
+/// The URI prefix of artist contexts, which don't support an offset.
+const ARTIST_CONTEXT_URI_PREFIX: &str = "spotify:artist:";
+
 /// A base builder type for the various player control request builders.
 pub struct BasePlayerControlRequestBuilder<TClient, TBody>(RequestBuilder<TClient, (), TBody>);
 /// A builder type for playing a context.
+///
+/// The [offset](Self::offset_uri) and [position](Self::position_ms) may be combined to resume a context from a certain
+/// point in a certain item. Artist contexts don't support an offset; sending the request with one fails with
+/// [UnsupportedPlayOffset](crate::error::Error::UnsupportedPlayOffset)-error.
 pub struct PlayContextRequestBuilder<TClient>(RequestBuilder<TClient, (), object::PlayContextBody>);
+/// A builder type for playing one or more playable items.
+///
+/// Spotify accepts at most 100 items in a single play request. Sending the request with more items fails with
+/// [InvalidPlayItemsCount](crate::error::Error::InvalidPlayItemsCount)-error.
+pub struct PlayItemsRequestBuilder<TClient>(RequestBuilder<TClient, (), object::PlayItemsBody>);
+/// A builder type for transferring playback to another device.
+pub struct TransferPlaybackRequestBuilder<TClient>(RequestBuilder<TClient, (), object::TransferPlaybackBody>);
 
-/// A type alias for a builder type for playing one or more playable items.
-pub type PlayItemsRequestBuilder<TClient> = BasePlayerControlRequestBuilder<TClient, object::PlayItemsBody>;
 /// A type alias for the various player control requests.
 pub type PlayerControlRequestBuilder<TClient> = BasePlayerControlRequestBuilder<TClient, ()>;
 
@@ -65,6 +83,75 @@ impl<TClient> BaseRequestBuilderContainer<TClient, (), object::PlayContextBody>
     fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, (), object::PlayContextBody> {
         &mut self.0
     }
+
+    fn validate(&self) -> Result<()> {
+        match &self.0.body {
+            Some(body) if body.offset.is_some() && body.context_uri.starts_with(ARTIST_CONTEXT_URI_PREFIX) => {
+                Err(Error::UnsupportedPlayOffset(body.context_uri.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<TClient> BaseRequestBuilderContainer<TClient, (), object::PlayItemsBody> for PlayItemsRequestBuilder<TClient> {
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: object::PlayItemsBody, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, (), object::PlayItemsBody> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, (), object::PlayItemsBody> {
+        &mut self.0
+    }
+
+    fn validate(&self) -> Result<()> {
+        let item_count = self.0.body.as_ref().map_or(0, |body| body.uris.len());
+
+        if item_count > MAX_PLAY_ITEMS {
+            Err(Error::InvalidPlayItemsCount(item_count))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<TClient> BaseRequestBuilderContainer<TClient, (), object::TransferPlaybackBody>
+    for TransferPlaybackRequestBuilder<TClient>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: object::TransferPlaybackBody, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, (), object::TransferPlaybackBody> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, (), object::TransferPlaybackBody> {
+        &mut self.0
+    }
 }
 
 impl<TClient, TReturn> BasePlayerControlRequestBuilder<TClient, TReturn> {
@@ -78,17 +165,263 @@ impl<TClient, TReturn> BasePlayerControlRequestBuilder<TClient, TReturn> {
 }
 
 impl<TClient> PlayContextRequestBuilder<TClient> {
-    /// Start playing a certain track from the context, identified by its zero-based index in the context.
+    /// Start playing a certain item from the context, identified by its zero-based index in the context.
+    ///
+    /// This is the same as [offset_position](Self::offset_position).
     pub fn offset<U>(self, offset: U) -> Self
     where
         U: Into<u32>,
+    {
+        self.offset_position(offset.into())
+    }
+
+    /// Start playing a certain item from the context, identified by its zero-based index in the context. For example,
+    /// to start an album from its fifth track, set this to 4. Artist contexts don't support an offset.
+    ///
+    /// Overrides any previously set [offset_uri](Self::offset_uri).
+    pub fn offset_position(self, position: u32) -> Self {
+        self.replace_body(|body| object::PlayContextBody {
+            offset: Some(object::PlayOffset::Position(position)),
+            ..body
+        })
+    }
+
+    /// Start playing a certain item from the context, identified by the item itself. The item has to be in the
+    /// context. Artist contexts don't support an offset.
+    ///
+    /// Overrides any previously set [offset_position](Self::offset_position).
+    pub fn offset_uri<'a, P>(self, item: P) -> Self
+    where
+        P: Into<PlayableItem<'a>>,
     {
         self.replace_body(|body| object::PlayContextBody {
-            offset: object::PlayContextOffset {
-                position: Some(offset.into()),
-                ..body.offset
-            },
+            offset: Some(object::PlayOffset::Uri(item.into().as_uri().into_owned())),
             ..body
         })
     }
+
+    /// Start playing the first item at the given position in milliseconds. If the position is past the item's length,
+    /// playback skips to the next item.
+    pub fn position_ms(self, position_ms: u32) -> Self {
+        self.replace_body(|body| object::PlayContextBody {
+            position_ms: Some(position_ms),
+            ..body
+        })
+    }
+}
+
+impl<TClient> PlayItemsRequestBuilder<TClient> {
+    /// Target playback on a certain Spotify device in the user's account.
+    pub fn device_id<S>(self, device_id: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        self.append_query(object::DEVICE_ID_QUERY, device_id.into())
+    }
+
+    /// Start playing a certain item from the given items, identified by its zero-based index in the items.
+    ///
+    /// Overrides any previously set [offset_uri](Self::offset_uri).
+    pub fn offset_position(self, position: u32) -> Self {
+        self.replace_body(|body| object::PlayItemsBody {
+            offset: Some(object::PlayOffset::Position(position)),
+            ..body
+        })
+    }
+
+    /// Start playing a certain item from the given items, identified by the item itself. The item has to be one of
+    /// the given items.
+    ///
+    /// Overrides any previously set [offset_position](Self::offset_position).
+    pub fn offset_uri<'a, P>(self, item: P) -> Self
+    where
+        P: Into<PlayableItem<'a>>,
+    {
+        self.replace_body(|body| object::PlayItemsBody {
+            offset: Some(object::PlayOffset::Uri(item.into().as_uri().into_owned())),
+            ..body
+        })
+    }
+
+    /// Start playing the first item at the given position in milliseconds. If the position is past the item's length,
+    /// playback skips to the next item.
+    pub fn position_ms(self, position_ms: u32) -> Self {
+        self.replace_body(|body| object::PlayItemsBody {
+            position_ms: Some(position_ms),
+            ..body
+        })
+    }
+}
+
+impl<TClient> TransferPlaybackRequestBuilder<TClient> {
+    /// Set whether playback should start on the target device. If `false`, the current playback state is kept, i.e.
+    /// paused playback stays paused and ongoing playback continues.
+    ///
+    /// Default: false.
+    pub fn play(self, play: bool) -> Self {
+        self.replace_body(|body| object::TransferPlaybackBody { play, ..body })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::id::{EpisodeId, Id, IdFromBare, TrackId};
+
+    #[test]
+    fn transfer_playback_body_contains_play() {
+        let body = object::TransferPlaybackBody {
+            device_ids: vec!["device".to_owned()],
+            play: false,
+        };
+        let builder: TransferPlaybackRequestBuilder<()> = TransferPlaybackRequestBuilder::new_with_body(
+            Method::PUT,
+            "https://api.spotify.com/v1/me/player",
+            body,
+            (),
+        )
+        .play(true);
+
+        assert_eq!(
+            serde_json::to_value(builder.0.body.unwrap()).unwrap(),
+            json!({ "device_ids": ["device"], "play": true })
+        );
+    }
+
+    #[test]
+    fn replacing_body_keeps_query() {
+        let body = object::PlayContextBody {
+            context_uri: "spotify:album:0tDsHtvN9YNuZjlqHvDY2P".to_owned(),
+            offset: None,
+            position_ms: None,
+        };
+        let builder: PlayContextRequestBuilder<()> = PlayContextRequestBuilder::new_with_body(
+            Method::PUT,
+            "https://api.spotify.com/v1/me/player/play",
+            body,
+            (),
+        )
+        .append_query(object::DEVICE_ID_QUERY, "device")
+        .offset(3u32);
+
+        assert_eq!(builder.0.query_params.get(object::DEVICE_ID_QUERY).unwrap(), "device");
+        assert_eq!(builder.0.body.unwrap().offset, Some(object::PlayOffset::Position(3)));
+    }
+
+    #[test]
+    fn play_context_body_contains_offset_and_position() {
+        let body = object::PlayContextBody {
+            context_uri: "spotify:album:0tDsHtvN9YNuZjlqHvDY2P".to_owned(),
+            offset: None,
+            position_ms: None,
+        };
+        let builder: PlayContextRequestBuilder<()> = PlayContextRequestBuilder::new_with_body(
+            Method::PUT,
+            "https://api.spotify.com/v1/me/player/play",
+            body,
+            (),
+        )
+        .offset_position(4)
+        .position_ms(30000);
+
+        assert_eq!(
+            serde_json::to_value(builder.0.body.unwrap()).unwrap(),
+            json!({
+                "context_uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P",
+                "offset": { "position": 4 },
+                "position_ms": 30000
+            })
+        );
+    }
+
+    #[test]
+    fn play_items_offset_uri_replaces_position() {
+        let track = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let body = object::PlayItemsBody {
+            uris: vec![track.as_uri().into_owned()],
+            offset: None,
+            position_ms: None,
+        };
+        let builder: PlayItemsRequestBuilder<()> =
+            PlayItemsRequestBuilder::new_with_body(Method::PUT, "https://api.spotify.com/v1/me/player/play", body, ())
+                .offset_position(1)
+                .offset_uri(track);
+
+        assert_eq!(
+            serde_json::to_value(builder.0.body.unwrap()).unwrap(),
+            json!({
+                "uris": ["spotify:track:2pDPOMX0kWA7kcPBcDCQBu"],
+                "offset": { "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu" }
+            })
+        );
+    }
+
+    #[test]
+    fn play_items_over_limit_is_invalid() {
+        let track = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let builder = |item_count| {
+            let body = object::PlayItemsBody {
+                uris: vec![track.as_uri().into_owned(); item_count],
+                offset: None,
+                position_ms: None,
+            };
+
+            PlayItemsRequestBuilder::new_with_body(Method::PUT, "https://api.spotify.com/v1/me/player/play", body, ())
+        };
+
+        assert!(builder(MAX_PLAY_ITEMS).validate().is_ok());
+        assert!(matches!(
+            builder(MAX_PLAY_ITEMS + 1).validate(),
+            Err(Error::InvalidPlayItemsCount(101))
+        ));
+    }
+
+    #[test]
+    fn play_context_body_resumes_from_uri_and_position() {
+        let episode = Id::<EpisodeId>::from_bare("512ojhOuo1ktJprKbVcKyQ").unwrap();
+        let body = object::PlayContextBody {
+            context_uri: "spotify:show:38bS44xjbVVZ3No3ByF1dJ".to_owned(),
+            offset: None,
+            position_ms: None,
+        };
+        let builder: PlayContextRequestBuilder<()> = PlayContextRequestBuilder::new_with_body(
+            Method::PUT,
+            "https://api.spotify.com/v1/me/player/play",
+            body,
+            (),
+        )
+        .offset_uri(episode)
+        .position_ms(1234567);
+
+        assert!(builder.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(builder.0.body.unwrap()).unwrap(),
+            json!({
+                "context_uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ",
+                "offset": { "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ" },
+                "position_ms": 1234567
+            })
+        );
+    }
+
+    #[test]
+    fn play_artist_context_with_offset_is_invalid() {
+        let builder = |offset| {
+            let body = object::PlayContextBody {
+                context_uri: "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF".to_owned(),
+                offset,
+                position_ms: None,
+            };
+
+            PlayContextRequestBuilder::new_with_body(Method::PUT, "https://api.spotify.com/v1/me/player/play", body, ())
+        };
+
+        assert!(builder(None).validate().is_ok());
+        assert!(matches!(
+            builder(Some(object::PlayOffset::Position(1))).validate(),
+            Err(Error::UnsupportedPlayOffset(uri)) if uri == "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+        ));
+    }
 }
@@ -0,0 +1,299 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder},
+    },
+    error::{Error, Result},
+};
+
+/// The maximum size of a base64-encoded playlist cover image in bytes.
+const MAX_PLAYLIST_COVER_SIZE: usize = 256 * 1024;
+
+/// A builder type for creating a playlist or changing a playlist's details. New instances are returned by the
+/// [create_playlist](crate::client::ScopedClient::create_playlist) and
+/// [change_playlist_details](crate::client::ScopedClient::change_playlist_details) functions in
+/// [ScopedClient](crate::client::ScopedClient).
+///
+/// Details that are not set are left unchanged, or for new playlists, left to their defaults.
+pub struct PlaylistDetailsRequestBuilder<TClient, TResponse>(
+    RequestBuilder<TClient, TResponse, object::PlaylistDetailsBody>,
+);
+
+/// A base builder type for the various requests that modify a playlist's items. The requests return the playlist's
+/// new snapshot ID.
+pub struct BasePlaylistItemsRequestBuilder<TClient, TBody>(
+    RequestBuilder<TClient, object::SnapshotIdResponse, TBody, String>,
+);
+
+/// A builder type for uploading a custom cover image for a playlist. New instances are returned by the
+/// [upload_playlist_cover](crate::client::ScopedClient::upload_playlist_cover) function in
+/// [ScopedClient](crate::client::ScopedClient).
+///
+/// The image has to be at most 256 KB when base64-encoded. Sending the request otherwise fails with
+/// [PlaylistCoverTooLarge](crate::error::Error::PlaylistCoverTooLarge)-error.
+pub struct PlaylistCoverRequestBuilder<TClient>(RequestBuilder<TClient, ()>);
+
+/// A type alias for a builder type for adding items to a playlist.
+pub type AddPlaylistItemsRequestBuilder<TClient> =
+    BasePlaylistItemsRequestBuilder<TClient, object::AddPlaylistItemsBody>;
+/// A type alias for a builder type for removing items from a playlist.
+pub type RemovePlaylistItemsRequestBuilder<TClient> =
+    BasePlaylistItemsRequestBuilder<TClient, object::RemovePlaylistItemsBody>;
+/// A type alias for a builder type for reordering a playlist's items.
+pub type ReorderPlaylistItemsRequestBuilder<TClient> =
+    BasePlaylistItemsRequestBuilder<TClient, object::ReorderPlaylistItemsBody>;
+
+impl<TClient, TResponse> BaseRequestBuilderContainer<TClient, TResponse, object::PlaylistDetailsBody>
+    for PlaylistDetailsRequestBuilder<TClient, TResponse>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: object::PlaylistDetailsBody, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, object::PlaylistDetailsBody> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, object::PlaylistDetailsBody> {
+        &mut self.0
+    }
+}
+
+impl<TClient, TBody> BaseRequestBuilderContainer<TClient, object::SnapshotIdResponse, TBody, String>
+    for BasePlaylistItemsRequestBuilder<TClient, TBody>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: TBody, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, object::SnapshotIdResponse, TBody, String> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, object::SnapshotIdResponse, TBody, String> {
+        &mut self.0
+    }
+}
+
+impl<TClient> BaseRequestBuilderContainer<TClient, ()> for PlaylistCoverRequestBuilder<TClient> {
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, ()> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, ()> {
+        &mut self.0
+    }
+
+    fn validate(&self) -> Result<()> {
+        match &self.0.raw_body {
+            Some((_, image)) if image.len() > MAX_PLAYLIST_COVER_SIZE => Err(Error::PlaylistCoverTooLarge(image.len())),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<TClient, TResponse> PlaylistDetailsRequestBuilder<TClient, TResponse> {
+    /// Set the playlist's name.
+    pub fn name<S>(self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::PlaylistDetailsBody {
+            name: Some(name.into()),
+            ..body
+        })
+    }
+
+    /// Set the playlist's description, as displayed in Spotify clients and the Web API.
+    pub fn description<S>(self, description: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::PlaylistDetailsBody {
+            description: Some(description.into()),
+            ..body
+        })
+    }
+
+    /// Set whether the playlist is public and displayed on the user's profile.
+    ///
+    /// Public playlists require the [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) scope and private
+    /// playlists the [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) scope.
+    pub fn public(self, public: bool) -> Self {
+        self.replace_body(|body| object::PlaylistDetailsBody {
+            public: Some(public),
+            ..body
+        })
+    }
+
+    /// Set whether the playlist is collaborative, allowing other users to modify it. Only private playlists can be
+    /// collaborative, so the playlist should also be set as [not public](Self::public).
+    pub fn collaborative(self, collaborative: bool) -> Self {
+        self.replace_body(|body| object::PlaylistDetailsBody {
+            collaborative: Some(collaborative),
+            ..body
+        })
+    }
+}
+
+impl<TClient> AddPlaylistItemsRequestBuilder<TClient> {
+    /// The zero-based index to insert the items in the playlist at. By default, the items are appended to the end of
+    /// the playlist.
+    pub fn position(self, position: u32) -> Self {
+        self.replace_body(|body| object::AddPlaylistItemsBody {
+            position: Some(position),
+            ..body
+        })
+    }
+}
+
+impl<TClient> RemovePlaylistItemsRequestBuilder<TClient> {
+    /// The playlist's snapshot ID to remove the items from. By default, the items are removed from the latest version
+    /// of the playlist.
+    pub fn snapshot_id<S>(self, snapshot_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::RemovePlaylistItemsBody {
+            snapshot_id: Some(snapshot_id.into()),
+            ..body
+        })
+    }
+}
+
+impl<TClient> ReorderPlaylistItemsRequestBuilder<TClient> {
+    /// The zero-based index of the first item to move.
+    ///
+    /// Default: 0.
+    pub fn range_start(self, range_start: u32) -> Self {
+        self.replace_body(|body| object::ReorderPlaylistItemsBody { range_start, ..body })
+    }
+
+    /// The zero-based index to move the items before. For example, to move the items to the start of the playlist, set
+    /// this to 0. To move the items to the end of the playlist, set this to the playlist's length.
+    ///
+    /// Default: 0.
+    pub fn insert_before(self, insert_before: u32) -> Self {
+        self.replace_body(|body| object::ReorderPlaylistItemsBody { insert_before, ..body })
+    }
+
+    /// The number of consecutive items to move, starting from [range_start](Self::range_start).
+    ///
+    /// Default: 1.
+    pub fn range_length(self, range_length: u32) -> Self {
+        self.replace_body(|body| object::ReorderPlaylistItemsBody {
+            range_length: Some(range_length),
+            ..body
+        })
+    }
+
+    /// The playlist's snapshot ID to reorder the items in. By default, the items are reordered in the latest version of
+    /// the playlist.
+    pub fn snapshot_id<S>(self, snapshot_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.replace_body(|body| object::ReorderPlaylistItemsBody {
+            snapshot_id: Some(snapshot_id.into()),
+            ..body
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn unset_details_are_not_serialized() {
+        let builder: PlaylistDetailsRequestBuilder<(), ()> = PlaylistDetailsRequestBuilder::new_with_body(
+            Method::PUT,
+            "https://api.spotify.com/v1/playlists/37i9dQZF1DXcBWIGoYBM5M",
+            object::PlaylistDetailsBody::default(),
+            (),
+        )
+        .description("A description")
+        .public(false);
+
+        assert_eq!(
+            serde_json::to_value(builder.0.body.unwrap()).unwrap(),
+            json!({ "description": "A description", "public": false })
+        );
+    }
+
+    #[test]
+    fn playlist_cover_size_is_validated() {
+        fn builder(size: usize) -> PlaylistCoverRequestBuilder<()> {
+            PlaylistCoverRequestBuilder::new(
+                Method::PUT,
+                "https://api.spotify.com/v1/playlists/37i9dQZF1DXcBWIGoYBM5M/images",
+                (),
+            )
+            .with_raw_body("image/jpeg", "A".repeat(size))
+        }
+
+        assert!(builder(MAX_PLAYLIST_COVER_SIZE).validate().is_ok());
+        assert!(matches!(
+            builder(MAX_PLAYLIST_COVER_SIZE + 1).validate(),
+            Err(Error::PlaylistCoverTooLarge(size)) if size == MAX_PLAYLIST_COVER_SIZE + 1
+        ));
+    }
+
+    #[test]
+    fn reorder_body_contains_set_fields() {
+        let builder: ReorderPlaylistItemsRequestBuilder<()> = ReorderPlaylistItemsRequestBuilder::new_with_body(
+            Method::PUT,
+            "https://api.spotify.com/v1/playlists/37i9dQZF1DXcBWIGoYBM5M/tracks",
+            object::ReorderPlaylistItemsBody::default(),
+            (),
+        )
+        .range_start(5)
+        .range_length(2)
+        .snapshot_id("snapshot");
+
+        assert_eq!(
+            serde_json::to_value(builder.0.body.unwrap()).unwrap(),
+            json!({ "range_start": 5, "insert_before": 0, "range_length": 2, "snapshot_id": "snapshot" })
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    model::{playback::PlayHistory, CursorPage},
+};
+
+const LIMIT_QUERY: &str = "limit";
+const BEFORE_QUERY: &str = "before";
+const AFTER_QUERY: &str = "after";
+
+impl TryFromEmptyResponse for CursorPage<PlayHistory> {}
+
+/// A builder for retrieving the current user's recently played tracks. New instances are returned by the
+/// [recently_played-function](crate::client::ScopedClient::recently_played) in
+/// [ScopedClient](crate::client::ScopedClient).
+///
+/// The tracks are paginated with cursors instead of offsets. Only one of [before](Self::before) and
+/// [after](Self::after) may be given; setting one replaces the other.
+pub struct RecentlyPlayedRequestBuilder<TClient>(RequestBuilder<TClient, CursorPage<PlayHistory>>);
+
+impl<TClient> BaseRequestBuilderContainer<TClient, CursorPage<PlayHistory>> for RecentlyPlayedRequestBuilder<TClient> {
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, CursorPage<PlayHistory>> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, CursorPage<PlayHistory>> {
+        &mut self.0
+    }
+}
+
+impl<TClient> RecentlyPlayedRequestBuilder<TClient> {
+    /// The maximum number of tracks to return.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.append_query(LIMIT_QUERY, limit.to_string())
+    }
+
+    /// Return tracks played before (but not including) this Unix timestamp in milliseconds. The cursor for the next
+    /// page of older tracks is returned by [CursorPage::next_cursor] as a string.
+    pub fn before(mut self, before: u64) -> Self {
+        self.0.query_params.remove(AFTER_QUERY);
+        self.append_query(BEFORE_QUERY, before.to_string())
+    }
+
+    /// Return tracks played after (but not including) this Unix timestamp in milliseconds.
+    pub fn after(mut self, after: u64) -> Self {
+        self.0.query_params.remove(BEFORE_QUERY);
+        self.append_query(AFTER_QUERY, after.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn before_and_after_replace_each_other() {
+        let builder = RecentlyPlayedRequestBuilder::new(Method::GET, "https://api.spotify.com/v1/", ())
+            .after(1677670000000)
+            .before(1677680000000);
+
+        assert_eq!(builder.0.query_params.get(BEFORE_QUERY).unwrap(), "1677680000000");
+        assert!(!builder.0.query_params.contains_key(AFTER_QUERY));
+
+        let builder = builder.after(1677670000000);
+        assert!(!builder.0.query_params.contains_key(BEFORE_QUERY));
+    }
+}
@@ -0,0 +1,329 @@
+use std::{borrow::Cow, time::Duration};
+
+use reqwest::Method;
+
+use crate::{
+    client::{
+        object,
+        request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    },
+    error::{Error, Result},
+    model::{
+        id::{ArtistId, Id, IdTrait, TrackId},
+        recommendations::Recommendations,
+        Market,
+    },
+};
+
+const SEED_ARTISTS_QUERY: &str = "seed_artists";
+const SEED_TRACKS_QUERY: &str = "seed_tracks";
+const SEED_GENRES_QUERY: &str = "seed_genres";
+const LIMIT_QUERY: &str = "limit";
+
+const MAX_SEEDS: usize = 5;
+
+impl TryFromEmptyResponse for Recommendations {}
+
+/// A builder for retrieving track recommendations. New instances are returned by the
+/// [recommendations-function](crate::client::UnscopedClient::recommendations) in
+/// [UnscopedClient](crate::client::UnscopedClient).
+///
+/// At least one and at most five seeds in total have to be given with the [seed_artists](Self::seed_artists),
+/// [seed_tracks](Self::seed_tracks) and [seed_genres](Self::seed_genres) functions. Sending the request otherwise fails
+/// with [InvalidRecommendationSeedCount](crate::error::Error::InvalidRecommendationSeedCount)-error.
+///
+/// The recommendations may be further tuned with the `min_*`, `max_*` and `target_*` functions for each tunable track
+/// attribute. The `min_*` and `max_*` functions set hard limits for the attribute, while the `target_*` functions
+/// prefer tracks whose attribute is close to the given value. The attributes are the same ones available in [track
+/// audio features](crate::model::audio::AudioFeatures).
+pub struct RecommendationsBuilder<TClient>(RequestBuilder<TClient, Recommendations>);
+
+impl<TClient> BaseRequestBuilderContainer<TClient, Recommendations> for RecommendationsBuilder<TClient> {
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, Recommendations> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, Recommendations> {
+        &mut self.0
+    }
+
+    fn validate(&self) -> Result<()> {
+        let seed_count = [SEED_ARTISTS_QUERY, SEED_TRACKS_QUERY, SEED_GENRES_QUERY]
+            .into_iter()
+            .filter_map(|key| self.0.query_params.get(key))
+            .map(|seeds| seeds.split(',').filter(|seed| !seed.is_empty()).count())
+            .sum();
+
+        if (1..=MAX_SEEDS).contains(&seed_count) {
+            Ok(())
+        } else {
+            Err(Error::InvalidRecommendationSeedCount(seed_count))
+        }
+    }
+}
+
+/// Generates the `min_*`, `max_*` and `target_*` functions for a tunable track attribute.
+macro_rules! tunable_attribute {
+    ($min:ident, $max:ident, $target:ident, $ty:ty, $attribute:literal, $description:literal) => {
+        #[doc = concat!("Set the minimum ", $description, " of the recommended tracks.")]
+        pub fn $min(self, $min: $ty) -> Self {
+            self.append_query(concat!("min_", $attribute), $min.to_string())
+        }
+
+        #[doc = concat!("Set the maximum ", $description, " of the recommended tracks.")]
+        pub fn $max(self, $max: $ty) -> Self {
+            self.append_query(concat!("max_", $attribute), $max.to_string())
+        }
+
+        #[doc = concat!("Set the target ", $description, " of the recommended tracks.")]
+        pub fn $target(self, $target: $ty) -> Self {
+            self.append_query(concat!("target_", $attribute), $target.to_string())
+        }
+    };
+}
+
+impl<TClient> RecommendationsBuilder<TClient> {
+    /// Add up to five artists to seed the recommendations with.
+    pub fn seed_artists<'a, I>(self, artists: I) -> Self
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        self.append_query(
+            SEED_ARTISTS_QUERY,
+            artists
+                .into_iter()
+                .map(|id| id.as_str().to_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    /// Add up to five tracks to seed the recommendations with.
+    pub fn seed_tracks<'a, I>(self, tracks: I) -> Self
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        self.append_query(
+            SEED_TRACKS_QUERY,
+            tracks
+                .into_iter()
+                .map(|id| id.as_str().to_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    /// Add up to five genres to seed the recommendations with. The available genres can be retrieved with the
+    /// [`available_genre_seeds`-function](crate::client::UnscopedClient::available_genre_seeds).
+    pub fn seed_genres<I, S>(self, genres: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.append_query(
+            SEED_GENRES_QUERY,
+            genres
+                .into_iter()
+                .map(|genre| genre.as_ref().to_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    /// The target number of recommended tracks.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 100.
+    pub fn limit(self, limit: u32) -> Self {
+        self.append_query(LIMIT_QUERY, limit.to_string())
+    }
+
+    /// Specify a target market country for this request. Only content that is available in that market will be returned
+    /// and [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(object::MARKET_QUERY, market.into().to_string())
+    }
+
+    /// Set the minimum duration of the recommended tracks.
+    pub fn min_duration(self, min_duration: Duration) -> Self {
+        self.append_query("min_duration_ms", min_duration.as_millis().to_string())
+    }
+
+    /// Set the maximum duration of the recommended tracks.
+    pub fn max_duration(self, max_duration: Duration) -> Self {
+        self.append_query("max_duration_ms", max_duration.as_millis().to_string())
+    }
+
+    /// Set the target duration of the recommended tracks.
+    pub fn target_duration(self, target_duration: Duration) -> Self {
+        self.append_query("target_duration_ms", target_duration.as_millis().to_string())
+    }
+
+    tunable_attribute!(
+        min_acousticness,
+        max_acousticness,
+        target_acousticness,
+        f32,
+        "acousticness",
+        "acousticness (0.0 to 1.0)"
+    );
+    tunable_attribute!(
+        min_danceability,
+        max_danceability,
+        target_danceability,
+        f32,
+        "danceability",
+        "danceability (0.0 to 1.0)"
+    );
+    tunable_attribute!(
+        min_energy,
+        max_energy,
+        target_energy,
+        f32,
+        "energy",
+        "energy (0.0 to 1.0)"
+    );
+    tunable_attribute!(
+        min_instrumentalness,
+        max_instrumentalness,
+        target_instrumentalness,
+        f32,
+        "instrumentalness",
+        "instrumentalness (0.0 to 1.0)"
+    );
+    tunable_attribute!(
+        min_key,
+        max_key,
+        target_key,
+        u8,
+        "key",
+        "key in pitch class notation (0 to 11)"
+    );
+    tunable_attribute!(
+        min_liveness,
+        max_liveness,
+        target_liveness,
+        f32,
+        "liveness",
+        "liveness (0.0 to 1.0)"
+    );
+    tunable_attribute!(
+        min_loudness,
+        max_loudness,
+        target_loudness,
+        f32,
+        "loudness",
+        "loudness in decibels"
+    );
+    tunable_attribute!(
+        min_mode,
+        max_mode,
+        target_mode,
+        u8,
+        "mode",
+        "modality (0 is minor, 1 is major)"
+    );
+    tunable_attribute!(
+        min_popularity,
+        max_popularity,
+        target_popularity,
+        u32,
+        "popularity",
+        "popularity (0 to 100)"
+    );
+    tunable_attribute!(
+        min_speechiness,
+        max_speechiness,
+        target_speechiness,
+        f32,
+        "speechiness",
+        "speechiness (0.0 to 1.0)"
+    );
+    tunable_attribute!(
+        min_tempo,
+        max_tempo,
+        target_tempo,
+        f32,
+        "tempo",
+        "tempo in beats per minute"
+    );
+    tunable_attribute!(
+        min_time_signature,
+        max_time_signature,
+        target_time_signature,
+        u8,
+        "time_signature",
+        "time signature"
+    );
+    tunable_attribute!(
+        min_valence,
+        max_valence,
+        target_valence,
+        f32,
+        "valence",
+        "valence (0.0 to 1.0)"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::id::IdFromBare;
+
+    fn builder() -> RecommendationsBuilder<()> {
+        RecommendationsBuilder::new(Method::GET, "https://api.spotify.com/v1/recommendations", ())
+    }
+
+    #[test]
+    fn recommendations_without_seeds_are_invalid() {
+        assert!(matches!(
+            builder().validate(),
+            Err(Error::InvalidRecommendationSeedCount(0))
+        ));
+    }
+
+    #[test]
+    fn recommendations_with_too_many_seeds_are_invalid() {
+        let builder = builder()
+            .seed_artists([Id::from_bare("4NHQUGzhtTLFvgF5SZesLK").unwrap()])
+            .seed_tracks([
+                Id::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap(),
+                Id::from_bare("0c6xIDDpzE81m2q797ordA").unwrap(),
+            ])
+            .seed_genres(["classical", "country", "rock"]);
+
+        assert!(matches!(
+            builder.validate(),
+            Err(Error::InvalidRecommendationSeedCount(6))
+        ));
+    }
+
+    #[test]
+    fn recommendations_with_combined_seeds_are_valid() {
+        let builder = builder()
+            .seed_artists([Id::from_bare("4NHQUGzhtTLFvgF5SZesLK").unwrap()])
+            .seed_tracks([Id::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap()])
+            .seed_genres(["classical", "country"])
+            .target_energy(0.8)
+            .max_popularity(50);
+
+        assert!(builder.validate().is_ok());
+        assert_eq!(builder.0.query_params.get("target_energy").unwrap(), "0.8");
+    }
+}
@@ -4,12 +4,13 @@ use reqwest::Method;
 
 use crate::{
     client::request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    error::{Error, Result},
     model::{
         search::{
             AlbumSearchResults, ArtistSearchResults, SearchResults, SearchResultsObject, ToTypesString,
             TrackSearchResults, DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_OFFSET, DEFAULT_SEARCH_TYPES_STRING,
         },
-        CountryCode,
+        Market,
     },
 };
 
@@ -19,6 +20,9 @@ const SEARCH_LIMIT: &str = "limit";
 const SEARCH_OFFSET: &str = "offset";
 const SEARCH_MARKET: &str = "market";
 
+const MAX_SEARCH_LIMIT: u32 = 50;
+const MAX_SEARCH_OFFSET: u32 = 1000;
+
 impl TryFromEmptyResponse for SearchResultsObject {}
 impl TryFromEmptyResponse for TrackSearchResults {}
 impl TryFromEmptyResponse for AlbumSearchResults {}
@@ -26,6 +30,10 @@ impl TryFromEmptyResponse for ArtistSearchResults {}
 
 /// A builder for a search in Spotify's catalog. New instances are returned by the
 /// [search-function](crate::client::UnscopedClient::search) in [UnscopedClient](crate::client::UnscopedClient)
+///
+/// The [limit](Self::limit) has to be between 1 and 50, and the [offset](Self::offset) at most 1000. Sending the
+/// request otherwise fails with [InvalidSearchLimit](crate::error::Error::InvalidSearchLimit)- or
+/// [InvalidSearchOffset](crate::error::Error::InvalidSearchOffset)-error.
 pub struct SearchBuilder<TClient>(RequestBuilder<TClient, SearchResultsObject, (), SearchResults>);
 
 impl<TClient> BaseRequestBuilderContainer<TClient, SearchResultsObject, (), SearchResults> for SearchBuilder<TClient> {
@@ -55,13 +63,33 @@ impl<TClient> BaseRequestBuilderContainer<TClient, SearchResultsObject, (), Sear
     fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, SearchResultsObject, (), SearchResults> {
         &mut self.0
     }
+
+    fn validate(&self) -> Result<()> {
+        let limit = self.query_param_u32(SEARCH_LIMIT);
+        let offset = self.query_param_u32(SEARCH_OFFSET);
+
+        match (limit, offset) {
+            (Some(limit), _) if !(1..=MAX_SEARCH_LIMIT).contains(&limit) => Err(Error::InvalidSearchLimit(limit)),
+            (_, Some(offset)) if offset > MAX_SEARCH_OFFSET => Err(Error::InvalidSearchOffset(offset)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<TClient> SearchBuilder<TClient> {
+    fn query_param_u32(&self, key: &str) -> Option<u32> {
+        self.0.query_params.get(key).and_then(|value| value.parse().ok())
+    }
 }
 
 impl<C> SearchBuilder<C>
 where
     C: Clone,
 {
-    pub(crate) fn query(self, query: String) -> Self {
+    pub(crate) fn query<S>(self, query: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
         self.append_query(SEARCH_QUERY, query)
     }
 
@@ -78,7 +106,7 @@ where
 
     /// The maximum number of results to return in each item type.
     ///
-    /// Default: 20. Maximum: 50.
+    /// Default: 20. Minimum: 1. Maximum: 50.
     pub fn limit(self, limit: u32) -> Self {
         self.append_query(SEARCH_LIMIT, limit.to_string())
     }
@@ -86,7 +114,7 @@ where
     /// The index of the first result to return. By combining this with [limit](SearchBuilder::limit), you may request
     /// new pages of content.
     ///
-    /// Default: 0.
+    /// Default: 0. Maximum: 1000.
     pub fn offset(self, offset: u32) -> Self {
         self.append_query(SEARCH_OFFSET, offset.to_string())
     }
@@ -94,7 +122,57 @@ where
     /// Specify a country such that content that is available in that market will be returned. If using an
     /// user-authenticated client, the country associated with the corresponding user account will take priority over
     /// this parameter.
-    pub fn market(self, market: CountryCode) -> Self {
-        self.append_query(SEARCH_MARKET, market.to_string())
+    pub fn market<M>(self, market: M) -> Self
+    where
+        M: Into<Market>,
+    {
+        self.append_query(SEARCH_MARKET, market.into().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::CountryCode;
+
+    fn builder() -> SearchBuilder<()> {
+        SearchBuilder::new(Method::GET, "https://api.spotify.com/v1/search", ()).query("test")
+    }
+
+    #[test]
+    fn default_search_is_valid() {
+        assert!(builder().validate().is_ok());
+    }
+
+    #[test]
+    fn search_limit_out_of_range_is_invalid() {
+        assert!(matches!(
+            builder().limit(0).validate(),
+            Err(Error::InvalidSearchLimit(0))
+        ));
+        assert!(matches!(
+            builder().limit(51).validate(),
+            Err(Error::InvalidSearchLimit(51))
+        ));
+        assert!(builder().limit(50).validate().is_ok());
+    }
+
+    #[test]
+    fn search_offset_out_of_range_is_invalid() {
+        assert!(matches!(
+            builder().offset(1001).validate(),
+            Err(Error::InvalidSearchOffset(1001))
+        ));
+        assert!(builder().offset(1000).validate().is_ok());
+    }
+
+    #[test]
+    fn search_parameters_are_set_as_query() {
+        let builder = builder().limit(10).offset(30).market(CountryCode::FI);
+
+        assert_eq!(builder.0.query_params.get(SEARCH_QUERY).unwrap(), "test");
+        assert_eq!(builder.0.query_params.get(SEARCH_LIMIT).unwrap(), "10");
+        assert_eq!(builder.0.query_params.get(SEARCH_OFFSET).unwrap(), "30");
+        assert_eq!(builder.0.query_params.get(SEARCH_MARKET).unwrap(), "FI");
     }
 }
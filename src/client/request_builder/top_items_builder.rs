@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use reqwest::Method;
+
+use crate::{
+    client::request_builder::{BaseRequestBuilderContainer, RequestBuilder, TryFromEmptyResponse},
+    model::top::{TimeRange, TopArtists, TopTracks},
+};
+
+const TIME_RANGE_QUERY: &str = "time_range";
+const LIMIT_QUERY: &str = "limit";
+const OFFSET_QUERY: &str = "offset";
+
+impl TryFromEmptyResponse for TopArtists {}
+impl TryFromEmptyResponse for TopTracks {}
+
+/// A builder for retrieving the current user's top artists or tracks. New instances are returned by the
+/// [top_artists](crate::client::ScopedClient::top_artists) and [top_tracks](crate::client::ScopedClient::top_tracks)
+/// functions in [ScopedClient](crate::client::ScopedClient).
+pub struct TopItemsRequestBuilder<TClient, TResponse, TReturn = TResponse>(
+    RequestBuilder<TClient, TResponse, (), TReturn>,
+);
+
+impl<TClient, TResponse, TReturn> BaseRequestBuilderContainer<TClient, TResponse, (), TReturn>
+    for TopItemsRequestBuilder<TClient, TResponse, TReturn>
+{
+    fn new<S>(method: Method, base_url: S, client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new(method, base_url, client))
+    }
+
+    fn new_with_body<S>(method: Method, base_url: S, body: (), client: TClient) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(RequestBuilder::new_with_body(method, base_url, body, client))
+    }
+
+    fn take_base_builder(self) -> RequestBuilder<TClient, TResponse, (), TReturn> {
+        self.0
+    }
+
+    fn get_base_builder_mut(&mut self) -> &mut RequestBuilder<TClient, TResponse, (), TReturn> {
+        &mut self.0
+    }
+}
+
+impl<TClient, TResponse, TReturn> TopItemsRequestBuilder<TClient, TResponse, TReturn> {
+    /// The time frame the top items are calculated over.
+    ///
+    /// Default: [MediumTerm](TimeRange::MediumTerm).
+    pub fn time_range(self, time_range: TimeRange) -> Self {
+        self.append_query(TIME_RANGE_QUERY, time_range.as_str())
+    }
+
+    /// The maximum number of items to return in each page.
+    ///
+    /// Default: 20. Minimum: 1. Maximum: 50.
+    pub fn limit(self, limit: u32) -> Self {
+        self.append_query(LIMIT_QUERY, limit.to_string())
+    }
+
+    /// The index of the first item to return. By combining this with [limit](Self::limit), you may request specific
+    /// pages of content.
+    ///
+    /// Default: 0.
+    pub fn offset(self, offset: u32) -> Self {
+        self.append_query(OFFSET_QUERY, offset.to_string())
+    }
+}
@@ -1,29 +1,73 @@
+#[cfg(any(
+    feature = "sync",
+    all(
+        feature = "async",
+        any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+    )
+))]
+use std::time::Duration;
 #[cfg(feature = "async")]
 use std::{future::Future, pin::Pin};
 
+#[cfg(all(
+    feature = "async",
+    any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+))]
+use futures_util::Stream;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
 use log::{error, trace, warn};
+#[cfg(feature = "lyrics")]
+use reqwest::header::{HeaderName, HeaderValue};
 use reqwest::{Method, StatusCode};
 
+#[cfg(feature = "async")]
+use super::request_builder::AsyncRequestBuilder;
+#[cfg(feature = "sync")]
+use super::request_builder::SyncRequestBuilder;
 use super::API_CURRENT_USER_PROFILE_ENDPOINT;
+#[cfg(feature = "lyrics")]
+use super::{request_builder::BaseRequestBuilder, SPCLIENT_LYRICS_ENDPOINT};
+#[cfg(feature = "lyrics")]
+use crate::model::Market;
 use crate::{
     client::{
         object,
         request_builder::{
-            BaseRequestBuilderContainer, PlayContextRequestBuilder, PlayItemsRequestBuilder,
-            PlayerControlRequestBuilder, RequestBuilder,
+            AddPlaylistItemsRequestBuilder, BaseRequestBuilderContainer, CurrentlyPlayingRequestBuilder,
+            FollowedArtistsRequestBuilder, LibraryRequestBuilder, PlayContextRequestBuilder, PlayItemsRequestBuilder,
+            PlayerControlRequestBuilder, PlaylistCoverRequestBuilder, PlaylistDetailsRequestBuilder,
+            RecentlyPlayedRequestBuilder, RemovePlaylistItemsRequestBuilder, ReorderPlaylistItemsRequestBuilder,
+            RequestBuilder, TopItemsRequestBuilder, TransferPlaybackRequestBuilder,
         },
-        API_CURRENTLY_PLAYING_ITEM_ENDPOINT, API_PLAYBACK_STATE_ENDPOINT, API_PLAYER_DEVICES_ENDPOINT,
-        API_PLAYER_NEXT_ENDPOINT, API_PLAYER_PAUSE_ENDPOINT, API_PLAYER_PLAY_ENDPOINT, API_PLAYER_PREVIOUS_ENDPOINT,
-        API_PLAYER_QUEUE_ENDPOINT, API_PLAYER_REPEAT_ENDPOINT, API_PLAYER_SEEK_ENDPOINT, API_PLAYER_SHUFFLE_ENDPOINT,
-        API_PLAYER_VOLUME_ENDPOINT,
+        API_CURRENTLY_PLAYING_ITEM_ENDPOINT, API_CURRENT_USER_PLAYLISTS_ENDPOINT, API_FOLLOWING_CONTAINS_ENDPOINT,
+        API_FOLLOWING_ENDPOINT, API_PLAYBACK_STATE_ENDPOINT, API_PLAYER_DEVICES_ENDPOINT, API_PLAYER_NEXT_ENDPOINT,
+        API_PLAYER_PAUSE_ENDPOINT, API_PLAYER_PLAY_ENDPOINT, API_PLAYER_PREVIOUS_ENDPOINT, API_PLAYER_QUEUE_ENDPOINT,
+        API_PLAYER_REPEAT_ENDPOINT, API_PLAYER_SEEK_ENDPOINT, API_PLAYER_SHUFFLE_ENDPOINT, API_PLAYER_VOLUME_ENDPOINT,
+        API_PLAYLISTS_ENDPOINT, API_RECENTLY_PLAYED_ENDPOINT, API_SAVED_ALBUMS_CONTAINS_ENDPOINT,
+        API_SAVED_ALBUMS_ENDPOINT, API_SAVED_EPISODES_CONTAINS_ENDPOINT, API_SAVED_EPISODES_ENDPOINT,
+        API_SAVED_SHOWS_CONTAINS_ENDPOINT, API_SAVED_SHOWS_ENDPOINT, API_SAVED_TRACKS_CONTAINS_ENDPOINT,
+        API_SAVED_TRACKS_ENDPOINT, API_TOP_ARTISTS_ENDPOINT, API_TOP_TRACKS_ENDPOINT, API_USER_PROFILE_ENDPOINT,
     },
     error::{Error, Result},
     model::{
+        artist::FullArtist,
         error::{ApiErrorMessage, ApiErrorResponse},
-        id::{IdTrait, PlayableContext, PlayableItem},
-        playback::{CurrentlyPlayingItem, Device, PlaybackState, RepeatState},
+        id::{
+            AlbumId, ArtistId, EpisodeId, Id, IdTrait, ItemTypeId, PlayableContext, PlayableItem, PlaylistId, ShowId,
+            TrackId, UserId,
+        },
+        library::{
+            SavedAlbum, SavedAlbums, SavedEpisode, SavedEpisodes, SavedShow, SavedShows, SavedTrack, SavedTracks,
+        },
+        playback::{CurrentlyPlayingItem, Device, PlaybackQueue, PlaybackState, RepeatState},
+        playlist::{CurrentUserPlaylists, FullPlaylist, PartialPlaylist},
+        top::{TopArtists, TopTracks},
+        track::{FullTrack, NonLocalTrackInformation},
         user::User,
+        ItemType, Page,
     },
+    scope::Scope,
 };
 
 /// All scoped Spotify endpoints. The functions in this trait require user authentication, since they're specific to a
@@ -37,18 +81,128 @@ where
     /// Get information about the user's current playback state, including track or episode, progress, and active
     /// device.
     ///
-    /// This function returns a superset of the [currently playing item](Self::currently_playing_item).
+    /// This function returns a superset of the [currently playing item](Self::currently_playing_item). The same way,
+    /// only tracks are returned by default.
     ///
     /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
-    fn playback_state(&self) -> RequestBuilder<Self, Option<PlaybackState>> {
-        RequestBuilder::new(Method::GET, API_PLAYBACK_STATE_ENDPOINT, self.clone())
+    fn playback_state(&self) -> CurrentlyPlayingRequestBuilder<Self, Option<PlaybackState>> {
+        CurrentlyPlayingRequestBuilder::new(Method::GET, API_PLAYBACK_STATE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadPlaybackState)
     }
 
     /// Get the item currently being played on the user's Spotify account.
     ///
+    /// Only tracks are returned by default. Other kinds of items, such as podcast episodes, are returned if they're
+    /// requested with the [`additional_types`-function in the request builder this function
+    /// returns](CurrentlyPlayingRequestBuilder::additional_types).
+    ///
     /// Required scope: [UserReadCurrentlyPlaying](crate::scope::Scope::UserReadCurrentlyPlaying).
-    fn currently_playing_item(&self) -> RequestBuilder<Self, Option<CurrentlyPlayingItem>> {
-        RequestBuilder::new(Method::GET, API_CURRENTLY_PLAYING_ITEM_ENDPOINT, self.clone())
+    fn currently_playing_item(&self) -> CurrentlyPlayingRequestBuilder<Self, Option<CurrentlyPlayingItem>> {
+        CurrentlyPlayingRequestBuilder::new(Method::GET, API_CURRENTLY_PLAYING_ITEM_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadCurrentlyPlaying)
+    }
+
+    /// Returns a stream that polls the user's [playback state](Self::playback_state) and yields it whenever it
+    /// [meaningfully changes](PlaybackState::meaningfully_differs_from); when the playing track changes, playback is
+    /// paused or resumed, or playback moves to another device. The first playback state is yielded immediately.
+    ///
+    /// The playback state is polled every `poll_interval`. While nothing is playing, the interval is doubled for each
+    /// poll, up to eight times the given interval. The waits between polls are done with the sleep function selected
+    /// with the crate's sleep features, so this function is only available if one of the `tokio_sleep`,
+    /// `async_std_sleep` or `smol_sleep` features is enabled. Any errors are yielded as they happen and polling
+    /// continues.
+    ///
+    /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
+    #[cfg(all(
+        feature = "async",
+        any(feature = "tokio_sleep", feature = "async_std_sleep", feature = "smol_sleep")
+    ))]
+    fn playback_changes_async(&self, poll_interval: Duration) -> impl Stream<Item = Result<PlaybackState>> + Send
+    where
+        Self: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    {
+        super::playback_poller::playback_changes_async(self.clone(), poll_interval)
+    }
+
+    /// Returns an iterator that polls the user's [playback state](Self::playback_state) and yields it whenever it
+    /// [meaningfully changes](PlaybackState::meaningfully_differs_from). Each call to the iterator's `next` blocks until
+    /// the playback state changes.
+    ///
+    /// See [playback_changes_async](Self::playback_changes_async) for details on how the playback state is polled. The
+    /// waits between polls block the current thread.
+    ///
+    /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
+    #[cfg(feature = "sync")]
+    fn playback_changes_sync(&self, poll_interval: Duration) -> impl Iterator<Item = Result<PlaybackState>>
+    where
+        Self: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    {
+        super::playback_poller::playback_changes_sync(self.clone(), poll_interval)
+    }
+
+    /// Start a radio from the track currently playing on the user's account. Retrieves the playing track, requests
+    /// `count` [recommendations](super::UnscopedClient::recommendations) seeded with it and starts playing the
+    /// recommended tracks on the user's currently active device. Returns the recommended tracks.
+    ///
+    /// In case nothing is playing, or the playing item is not a track (e.g. an episode or an ad), the function will
+    /// return an [Error::NoPlayingTrack](crate::error::Error::NoPlayingTrack). If Spotify doesn't recommend any
+    /// tracks, playback is left untouched and an empty list is returned.
+    ///
+    /// Required scopes: [UserReadCurrentlyPlaying](crate::scope::Scope::UserReadCurrentlyPlaying),
+    /// [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
+    #[cfg(feature = "async")]
+    fn start_radio_from_current_async(&self, count: u32) -> impl Future<Output = Result<Vec<FullTrack>>> + Send
+    where
+        Self: super::UnscopedClient
+            + super::private::BuildHttpRequestAsync
+            + super::private::AccessTokenExpiryAsync
+            + Send
+            + Sync,
+    {
+        async move {
+            let seed = radio_seed(self.currently_playing_item().send_async().await?)?;
+            let tracks = self
+                .recommendations()
+                .seed_tracks([seed])
+                .limit(count)
+                .send_async()
+                .await?
+                .take_tracks();
+
+            if !tracks.is_empty() {
+                self.play_items(tracks.iter().map(|track| track.id()))
+                    .send_async()
+                    .await?;
+            }
+
+            Ok(tracks)
+        }
+    }
+
+    /// Start a radio from the track currently playing on the user's account. Returns the recommended tracks.
+    ///
+    /// See [start_radio_from_current_async](Self::start_radio_from_current_async) for details.
+    ///
+    /// Required scopes: [UserReadCurrentlyPlaying](crate::scope::Scope::UserReadCurrentlyPlaying),
+    /// [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
+    #[cfg(feature = "sync")]
+    fn start_radio_from_current_sync(&self, count: u32) -> Result<Vec<FullTrack>>
+    where
+        Self: super::UnscopedClient + super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    {
+        let seed = radio_seed(self.currently_playing_item().send_sync()?)?;
+        let tracks = self
+            .recommendations()
+            .seed_tracks([seed])
+            .limit(count)
+            .send_sync()?
+            .take_tracks();
+
+        if !tracks.is_empty() {
+            self.play_items(tracks.iter().map(|track| track.id())).send_sync()?;
+        }
+
+        Ok(tracks)
     }
 
     /// Get information about the user's available devices.
@@ -56,30 +210,99 @@ where
     /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
     fn devices(&self) -> RequestBuilder<Self, object::DevicesResponse, (), Vec<Device>> {
         RequestBuilder::new(Method::GET, API_PLAYER_DEVICES_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadPlaybackState)
+    }
+
+    /// Get the user's currently active device, or `None` if none of the user's [devices](Self::devices) is active.
+    ///
+    /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
+    #[cfg(feature = "async")]
+    fn active_device_async(&self) -> impl Future<Output = Result<Option<Device>>> + Send
+    where
+        Self: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    {
+        async move { Ok(find_active_device(self.devices().send_async().await?)) }
+    }
+
+    /// Get the user's currently active device, or `None` if none of the user's [devices](Self::devices) is active.
+    ///
+    /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
+    #[cfg(feature = "sync")]
+    fn active_device_sync(&self) -> Result<Option<Device>>
+    where
+        Self: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    {
+        Ok(find_active_device(self.devices().send_sync()?))
+    }
+
+    /// Transfer playback to another device in the user's account. By default, the current playback state is kept on
+    /// the new device. Playback may be started on the device with the [`play`-function in the request builder this
+    /// function returns](crate::client::request_builder::TransferPlaybackRequestBuilder::play).
+    ///
+    /// In case the device could not be activated for playback, the function will return an
+    /// [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
+    ///
+    /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
+    fn transfer_playback<S>(&self, device_id: S) -> TransferPlaybackRequestBuilder<Self>
+    where
+        S: Into<String>,
+    {
+        let body = object::TransferPlaybackBody {
+            device_ids: vec![device_id.into()],
+            play: false,
+        };
+
+        trace!("Transfer playback body: {:?}", body);
+        let mut builder =
+            TransferPlaybackRequestBuilder::new_with_body(Method::PUT, API_PLAYBACK_STATE_ENDPOINT, body, self.clone())
+                .with_required_scope(Scope::UserModifyPlaybackState);
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(Box::new(handle_player_control_response_async));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(Box::new(handle_player_control_response_sync));
+        }
+
+        builder
     }
 
     /// Start playing a collection of playable items in order; tracks or episodes.
     ///
     /// A Spotify device ID in the user's account may be supplied with the [`device_id`-function in the request builder
-    /// this function returns](crate::client::request_builder::BasePlayerControlRequestBuilder::device_id) such that
+    /// this function returns](PlayItemsRequestBuilder::device_id) such that
     /// playback will be targeted on that device. If no device is given, playback will be targeted on the user's
     /// currently active device. In case no device is active and no device is given, the function will
     /// return an [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
+    /// Playback starts from the first item by default. The item to start from may be set with the
+    /// [`offset_position`](PlayItemsRequestBuilder::offset_position) or
+    /// [`offset_uri`](PlayItemsRequestBuilder::offset_uri)-functions, and the position in it with the
+    /// [`position_ms`-function](PlayItemsRequestBuilder::position_ms).
+    ///
+    /// Spotify accepts at most 100 items in a single play request. Sending the request with more items fails with an
+    /// [Error::InvalidPlayItemsCount](crate::error::Error::InvalidPlayItemsCount) without contacting Spotify. Any
+    /// further items may be queued afterwards with the [`add_to_queue`-function](Self::add_to_queue).
+    ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn play_items<'a, I, P>(&'a self, items: I) -> PlayItemsRequestBuilder<Self>
     where
         I: IntoIterator<Item = P>,
         P: Into<PlayableItem<'a>>,
     {
-        let tracks: Vec<_> = items.into_iter().map(|id| id.into()).collect();
         let body = object::PlayItemsBody {
-            uris: tracks.iter().map(|id| id.as_uri().to_string()).collect(),
+            uris: playable_uris(items),
+            offset: None,
+            position_ms: None,
         };
 
         trace!("Play body: {:?}", body);
         let mut builder =
-            PlayItemsRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone());
+            PlayItemsRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone())
+                .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -102,19 +325,20 @@ where
     /// currently active device. In case no device is active and no device is given, the function will return an
     /// [Error::NoActiveDevice](crate::error::Error::NoActiveDevice).
     ///
+    /// Playback starts from the start of the context by default. The item to start from may be set with the
+    /// [`offset_position`](PlayContextRequestBuilder::offset_position) or
+    /// [`offset_uri`](PlayContextRequestBuilder::offset_uri)-functions, and the position in it with the
+    /// [`position_ms`-function](PlayContextRequestBuilder::position_ms). The offset and the position may be combined to
+    /// resume the context from where the user left off, for example in an album or a show.
+    ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn play_context<'a>(&'a self, context: PlayableContext<'a>) -> PlayContextRequestBuilder<Self> {
-        let body = object::PlayContextBody {
-            context_uri: context.as_uri().to_string(),
-            offset: object::PlayContextOffset {
-                position: Some(0),
-                uri: None,
-            },
-        };
+        let body = play_context_body(&context);
 
         trace!("Play body: {:?}", body);
         let mut builder =
-            PlayContextRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone());
+            PlayContextRequestBuilder::new_with_body(Method::PUT, API_PLAYER_PLAY_ENDPOINT, body, self.clone())
+                .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -139,7 +363,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn resume(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PLAY_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PLAY_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -164,7 +389,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn pause(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PAUSE_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_PAUSE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -190,6 +416,7 @@ where
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn repeat_state(&self, repeat_state: RepeatState) -> PlayerControlRequestBuilder<Self> {
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_REPEAT_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::REPEAT_STATE_QUERY, repeat_state.as_str());
 
         #[cfg(feature = "async")]
@@ -216,6 +443,7 @@ where
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn shuffle(&self, shuffle: bool) -> PlayerControlRequestBuilder<Self> {
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_SHUFFLE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::SHUFFLE_QUERY, if shuffle { "true" } else { "false" });
 
         #[cfg(feature = "async")]
@@ -246,6 +474,7 @@ where
     {
         let volume_percent = volume_percent.into().to_string();
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_VOLUME_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::VOLUME_PERCENT_QUERY, volume_percent);
 
         #[cfg(feature = "async")]
@@ -271,7 +500,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn next(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_NEXT_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_NEXT_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -296,7 +526,8 @@ where
     ///
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn previous(&self) -> PlayerControlRequestBuilder<Self> {
-        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_PREVIOUS_ENDPOINT, self.clone());
+        let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_PREVIOUS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState);
 
         #[cfg(feature = "async")]
         {
@@ -328,6 +559,7 @@ where
     {
         let position = position.into().to_string();
         let mut builder = PlayerControlRequestBuilder::new(Method::PUT, API_PLAYER_SEEK_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::SEEK_POSITION_QUERY, position);
 
         #[cfg(feature = "async")]
@@ -354,6 +586,7 @@ where
     /// Required scope: [UserModifyPlaybackState](crate::scope::Scope::UserModifyPlaybackState).
     fn add_to_queue<'a>(&'a self, item: PlayableItem<'a>) -> PlayerControlRequestBuilder<Self> {
         let mut builder = PlayerControlRequestBuilder::new(Method::POST, API_PLAYER_QUEUE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserModifyPlaybackState)
             .append_query(object::QUEUE_URI_QUERY, item.as_uri().to_string());
 
         #[cfg(feature = "async")]
@@ -369,6 +602,55 @@ where
         builder
     }
 
+    /// Get the user's playback queue; the currently playing item and the items queued after it.
+    ///
+    /// Required scope: [UserReadPlaybackState](crate::scope::Scope::UserReadPlaybackState).
+    fn queue(&self) -> RequestBuilder<Self, PlaybackQueue> {
+        RequestBuilder::new(Method::GET, API_PLAYER_QUEUE_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadPlaybackState)
+    }
+
+    /// Get a track's time-synced lyrics in the market of the user the client's access token belongs to.
+    ///
+    /// **This function uses an unofficial, undocumented Spotify endpoint and it may break at any time.** The endpoint
+    /// isn't part of the public Web API; Spotify only serves lyrics to access tokens issued to its own web player, and
+    /// access tokens from the regular authorization flows are usually rejected with a
+    /// [Forbidden-error](Error::Forbidden). If the track has no lyrics, such as when it's instrumental, a
+    /// [LyricsUnavailable-error](Error::LyricsUnavailable) is returned.
+    #[cfg(feature = "lyrics")]
+    fn track_lyrics<'a>(
+        &'a self,
+        track: Id<'a, TrackId>,
+    ) -> RequestBuilder<Self, object::LyricsResponse, (), crate::model::lyrics::Lyrics> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", SPCLIENT_LYRICS_ENDPOINT, track.as_str()),
+            self.clone(),
+        )
+        .append_query(object::LYRICS_FORMAT_QUERY, "json")
+        .append_query(object::MARKET_QUERY, Market::FromToken.to_string())
+        .header(
+            HeaderName::from_static("app-platform"),
+            HeaderValue::from_static("WebPlayer"),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(super::unscoped::not_found_response_handler_async_fn(
+                Error::LyricsUnavailable(track.as_owned()),
+            ));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(super::unscoped::not_found_response_handler_sync_fn(
+                Error::LyricsUnavailable(track.as_owned()),
+            ));
+        }
+
+        builder
+    }
+
     /// Get detailed profile information about the current user.
     ///
     /// Required scope: [UserReadEmail](crate::scope::Scope::UserReadEmail). Optionally required scope:
@@ -387,6 +669,692 @@ where
     fn current_user_profile(&self) -> RequestBuilder<Self, User> {
         RequestBuilder::new(Method::GET, API_CURRENT_USER_PROFILE_ENDPOINT, self.clone())
     }
+
+    /// Get the tracks the current user has recently played. Only tracks are returned, podcast episodes aren't
+    /// supported.
+    ///
+    /// The tracks are paginated with cursors. The number of tracks may be specified with the
+    /// [`limit`-function](RecentlyPlayedRequestBuilder::limit), and the tracks may be limited to ones played before or
+    /// after a certain time with the [`before`](RecentlyPlayedRequestBuilder::before) and
+    /// [`after`](RecentlyPlayedRequestBuilder::after)-functions in the request builder this function returns. Walk
+    /// backwards through the listening history by giving the [next cursor](crate::model::CursorPage::next_cursor) of a
+    /// returned page to the `before`-function.
+    ///
+    /// Required scope: [UserReadRecentlyPlayed](crate::scope::Scope::UserReadRecentlyPlayed).
+    fn recently_played(&self) -> RecentlyPlayedRequestBuilder<Self> {
+        RecentlyPlayedRequestBuilder::new(Method::GET, API_RECENTLY_PLAYED_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserReadRecentlyPlayed)
+    }
+
+    /// Get the current user's top artists based on their listening history.
+    ///
+    /// The time frame the top artists are calculated over may be specified with the
+    /// [`time_range`-function](TopItemsRequestBuilder::time_range) in the request builder this function returns. The
+    /// page size and offset may be specified with the [`limit`](TopItemsRequestBuilder::limit) and
+    /// [`offset`](TopItemsRequestBuilder::offset)-functions.
+    ///
+    /// Required scope: [UserTopRead](crate::scope::Scope::UserTopRead).
+    fn top_artists(&self) -> TopItemsRequestBuilder<Self, TopArtists, Page<TopArtists, FullArtist>> {
+        TopItemsRequestBuilder::new(Method::GET, API_TOP_ARTISTS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserTopRead)
+    }
+
+    /// Get the current user's top tracks based on their listening history.
+    ///
+    /// The time frame the top tracks are calculated over may be specified with the
+    /// [`time_range`-function](TopItemsRequestBuilder::time_range) in the request builder this function returns. The
+    /// page size and offset may be specified with the [`limit`](TopItemsRequestBuilder::limit) and
+    /// [`offset`](TopItemsRequestBuilder::offset)-functions.
+    ///
+    /// Required scope: [UserTopRead](crate::scope::Scope::UserTopRead).
+    fn top_tracks(&self) -> TopItemsRequestBuilder<Self, TopTracks, Page<TopTracks, FullTrack>> {
+        TopItemsRequestBuilder::new(Method::GET, API_TOP_TRACKS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserTopRead)
+    }
+
+    /// Get the tracks saved in the current user's library.
+    ///
+    /// The page size and offset may be specified with the [`limit`](LibraryRequestBuilder::limit) and
+    /// [`offset`](LibraryRequestBuilder::offset)-functions in the request builder this function returns. An optional
+    /// market country may be specified with the [`market`-function](LibraryRequestBuilder::market).
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn saved_tracks(&self) -> LibraryRequestBuilder<Self, SavedTracks, Page<SavedTracks, SavedTrack>> {
+        LibraryRequestBuilder::new(Method::GET, API_SAVED_TRACKS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+    }
+
+    /// Save one or more tracks in the current user's library.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn save_tracks<'a, I>(&'a self, tracks: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        RequestBuilder::new(Method::PUT, API_SAVED_TRACKS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::TRACKS_IDS_QUERY, join_ids(tracks))
+    }
+
+    /// Remove one or more tracks from the current user's library.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn remove_saved_tracks<'a, I>(&'a self, tracks: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        RequestBuilder::new(Method::DELETE, API_SAVED_TRACKS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::TRACKS_IDS_QUERY, join_ids(tracks))
+    }
+
+    /// Check if one or more tracks are saved in the current user's library. The returned booleans are in the same order
+    /// as the given IDs.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn check_saved_tracks<'a, I>(&'a self, tracks: I) -> RequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        RequestBuilder::new(Method::GET, API_SAVED_TRACKS_CONTAINS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+            .append_query(object::TRACKS_IDS_QUERY, join_ids(tracks))
+    }
+
+    /// Get the albums saved in the current user's library.
+    ///
+    /// The page size and offset may be specified with the [`limit`](LibraryRequestBuilder::limit) and
+    /// [`offset`](LibraryRequestBuilder::offset)-functions in the request builder this function returns. An optional
+    /// market country may be specified with the [`market`-function](LibraryRequestBuilder::market).
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn saved_albums(&self) -> LibraryRequestBuilder<Self, SavedAlbums, Page<SavedAlbums, SavedAlbum>> {
+        LibraryRequestBuilder::new(Method::GET, API_SAVED_ALBUMS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+    }
+
+    /// Save one or more albums in the current user's library.
+    ///
+    /// Up to 20 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn save_albums<'a, I>(&'a self, albums: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        RequestBuilder::new(Method::PUT, API_SAVED_ALBUMS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::ALBUMS_IDS_QUERY, join_ids(albums))
+    }
+
+    /// Remove one or more albums from the current user's library.
+    ///
+    /// Up to 20 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn remove_saved_albums<'a, I>(&'a self, albums: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        RequestBuilder::new(Method::DELETE, API_SAVED_ALBUMS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::ALBUMS_IDS_QUERY, join_ids(albums))
+    }
+
+    /// Check if one or more albums are saved in the current user's library. The returned booleans are in the same order
+    /// as the given IDs.
+    ///
+    /// Up to 20 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn check_saved_albums<'a, I>(&'a self, albums: I) -> RequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        RequestBuilder::new(Method::GET, API_SAVED_ALBUMS_CONTAINS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+            .append_query(object::ALBUMS_IDS_QUERY, join_ids(albums))
+    }
+
+    /// Get the podcast episodes saved in the current user's library.
+    ///
+    /// The page size and offset may be specified with the [`limit`](LibraryRequestBuilder::limit) and
+    /// [`offset`](LibraryRequestBuilder::offset)-functions in the request builder this function returns. An optional
+    /// market country may be specified with the [`market`-function](LibraryRequestBuilder::market).
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead). The episodes' resume points
+    /// additionally require [UserReadPlaybackPosition](crate::scope::Scope::UserReadPlaybackPosition).
+    fn saved_episodes(&self) -> LibraryRequestBuilder<Self, SavedEpisodes, Page<SavedEpisodes, SavedEpisode>> {
+        LibraryRequestBuilder::new(Method::GET, API_SAVED_EPISODES_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+    }
+
+    /// Save one or more podcast episodes in the current user's library.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn save_episodes<'a, I>(&'a self, episodes: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, EpisodeId>>,
+    {
+        RequestBuilder::new(Method::PUT, API_SAVED_EPISODES_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::EPISODES_IDS_QUERY, join_ids(episodes))
+    }
+
+    /// Remove one or more podcast episodes from the current user's library.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn remove_saved_episodes<'a, I>(&'a self, episodes: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, EpisodeId>>,
+    {
+        RequestBuilder::new(Method::DELETE, API_SAVED_EPISODES_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::EPISODES_IDS_QUERY, join_ids(episodes))
+    }
+
+    /// Check if one or more podcast episodes are saved in the current user's library. The returned booleans are in the
+    /// same order as the given IDs.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn check_saved_episodes<'a, I>(&'a self, episodes: I) -> RequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, EpisodeId>>,
+    {
+        RequestBuilder::new(Method::GET, API_SAVED_EPISODES_CONTAINS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+            .append_query(object::EPISODES_IDS_QUERY, join_ids(episodes))
+    }
+
+    /// Get the podcast shows saved in the current user's library.
+    ///
+    /// The page size and offset may be specified with the [`limit`](LibraryRequestBuilder::limit) and
+    /// [`offset`](LibraryRequestBuilder::offset)-functions in the request builder this function returns.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn saved_shows(&self) -> LibraryRequestBuilder<Self, SavedShows, Page<SavedShows, SavedShow>> {
+        LibraryRequestBuilder::new(Method::GET, API_SAVED_SHOWS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+    }
+
+    /// Save one or more podcast shows in the current user's library.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn save_shows<'a, I>(&'a self, shows: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        RequestBuilder::new(Method::PUT, API_SAVED_SHOWS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::SHOWS_IDS_QUERY, join_ids(shows))
+    }
+
+    /// Remove one or more podcast shows from the current user's library.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryModify](crate::scope::Scope::UserLibraryModify).
+    fn remove_saved_shows<'a, I>(&'a self, shows: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        RequestBuilder::new(Method::DELETE, API_SAVED_SHOWS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryModify)
+            .append_query(object::SHOWS_IDS_QUERY, join_ids(shows))
+    }
+
+    /// Check if one or more podcast shows are saved in the current user's library. The returned booleans are in the
+    /// same order as the given IDs.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserLibraryRead](crate::scope::Scope::UserLibraryRead).
+    fn check_saved_shows<'a, I>(&'a self, shows: I) -> RequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        RequestBuilder::new(Method::GET, API_SAVED_SHOWS_CONTAINS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserLibraryRead)
+            .append_query(object::SHOWS_IDS_QUERY, join_ids(shows))
+    }
+
+    /// Get the artists the current user follows.
+    ///
+    /// The artists are paginated with cursors. The number of artists may be specified with the
+    /// [`limit`-function](FollowedArtistsRequestBuilder::limit) in the request builder this function returns. The next
+    /// page of artists may be retrieved by giving the [next cursor](crate::model::CursorPage::next_cursor) of a returned
+    /// page to the [`after`-function](FollowedArtistsRequestBuilder::after).
+    ///
+    /// Required scope: [UserFollowRead](crate::scope::Scope::UserFollowRead).
+    fn followed_artists(&self) -> FollowedArtistsRequestBuilder<Self> {
+        FollowedArtistsRequestBuilder::new(Method::GET, API_FOLLOWING_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserFollowRead)
+    }
+
+    /// Follow one or more artists as the current user.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn follow_artists<'a, I>(&'a self, artists: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        RequestBuilder::new(Method::PUT, API_FOLLOWING_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserFollowModify)
+            .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::Artist.to_string())
+            .append_query(object::FOLLOWED_IDS_QUERY, join_ids(artists))
+    }
+
+    /// Unfollow one or more artists as the current user.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn unfollow_artists<'a, I>(&'a self, artists: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        RequestBuilder::new(Method::DELETE, API_FOLLOWING_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserFollowModify)
+            .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::Artist.to_string())
+            .append_query(object::FOLLOWED_IDS_QUERY, join_ids(artists))
+    }
+
+    /// Check if the current user follows one or more artists. The returned booleans are in the same order as the
+    /// given IDs.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserFollowRead](crate::scope::Scope::UserFollowRead).
+    fn check_following_artists<'a, I>(&'a self, artists: I) -> RequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        RequestBuilder::new(Method::GET, API_FOLLOWING_CONTAINS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserFollowRead)
+            .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::Artist.to_string())
+            .append_query(object::FOLLOWED_IDS_QUERY, join_ids(artists))
+    }
+
+    /// Follow one or more users as the current user.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn follow_users<'a, I>(&'a self, users: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, UserId>>,
+    {
+        RequestBuilder::new(Method::PUT, API_FOLLOWING_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserFollowModify)
+            .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::User.to_string())
+            .append_query(object::FOLLOWED_IDS_QUERY, join_ids(users))
+    }
+
+    /// Unfollow one or more users as the current user.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserFollowModify](crate::scope::Scope::UserFollowModify).
+    fn unfollow_users<'a, I>(&'a self, users: I) -> RequestBuilder<Self, ()>
+    where
+        I: IntoIterator<Item = Id<'a, UserId>>,
+    {
+        RequestBuilder::new(Method::DELETE, API_FOLLOWING_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserFollowModify)
+            .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::User.to_string())
+            .append_query(object::FOLLOWED_IDS_QUERY, join_ids(users))
+    }
+
+    /// Check if the current user follows one or more users. The returned booleans are in the same order as the
+    /// given IDs.
+    ///
+    /// Up to 50 IDs may be given.
+    ///
+    /// Required scope: [UserFollowRead](crate::scope::Scope::UserFollowRead).
+    fn check_following_users<'a, I>(&'a self, users: I) -> RequestBuilder<Self, Vec<bool>>
+    where
+        I: IntoIterator<Item = Id<'a, UserId>>,
+    {
+        RequestBuilder::new(Method::GET, API_FOLLOWING_CONTAINS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::UserFollowRead)
+            .append_query(object::FOLLOWED_TYPE_QUERY, ItemType::User.to_string())
+            .append_query(object::FOLLOWED_IDS_QUERY, join_ids(users))
+    }
+
+    /// Get the current user's playlists, both owned and followed.
+    ///
+    /// The page size and offset may be specified with the [`limit`](LibraryRequestBuilder::limit) and
+    /// [`offset`](LibraryRequestBuilder::offset)-functions in the request builder this function returns.
+    ///
+    /// Required scope: [PlaylistReadPrivate](crate::scope::Scope::PlaylistReadPrivate). Collaborative playlists
+    /// additionally require the [PlaylistReadCollaborative](crate::scope::Scope::PlaylistReadCollaborative) scope.
+    fn current_user_playlists(
+        &self,
+    ) -> LibraryRequestBuilder<Self, CurrentUserPlaylists, Page<CurrentUserPlaylists, PartialPlaylist>> {
+        LibraryRequestBuilder::new(Method::GET, API_CURRENT_USER_PLAYLISTS_ENDPOINT, self.clone())
+            .with_required_scope(Scope::PlaylistReadPrivate)
+    }
+
+    /// Get all of the current user's playlists by requesting every page of
+    /// [current_user_playlists](Self::current_user_playlists). Once the first page tells how many playlists there are,
+    /// the rest of the pages are requested concurrently, a few at a time. The playlists are returned in the same order
+    /// as the pages list them, and rate limits are respected as with any other request. For users with a lot of
+    /// playlists, consider [current_user_playlists_up_to_async](Self::current_user_playlists_up_to_async) instead.
+    ///
+    /// Required scope: [PlaylistReadPrivate](crate::scope::Scope::PlaylistReadPrivate).
+    #[cfg(feature = "async")]
+    fn all_current_user_playlists_async(&self) -> impl Future<Output = Result<Vec<PartialPlaylist>>> + Send
+    where
+        Self: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    {
+        current_user_playlists_async(self, None)
+    }
+
+    /// Get up to `max` of the current user's playlists by requesting the pages of
+    /// [current_user_playlists](Self::current_user_playlists) that contain them.
+    ///
+    /// See [all_current_user_playlists_async](Self::all_current_user_playlists_async) for details.
+    ///
+    /// Required scope: [PlaylistReadPrivate](crate::scope::Scope::PlaylistReadPrivate).
+    #[cfg(feature = "async")]
+    fn current_user_playlists_up_to_async(
+        &self,
+        max: usize,
+    ) -> impl Future<Output = Result<Vec<PartialPlaylist>>> + Send
+    where
+        Self: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    {
+        current_user_playlists_async(self, Some(max))
+    }
+
+    /// Get all of the current user's playlists by walking through every page of
+    /// [current_user_playlists](Self::current_user_playlists). The pages are requested one after another and rate
+    /// limits are respected as with any other request. For users with a lot of playlists, consider
+    /// [current_user_playlists_up_to_sync](Self::current_user_playlists_up_to_sync) instead.
+    ///
+    /// Required scope: [PlaylistReadPrivate](crate::scope::Scope::PlaylistReadPrivate).
+    #[cfg(feature = "sync")]
+    fn all_current_user_playlists_sync(&self) -> Result<Vec<PartialPlaylist>>
+    where
+        Self: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    {
+        current_user_playlists_sync(self, None)
+    }
+
+    /// Get up to `max` of the current user's playlists by walking through the pages of
+    /// [current_user_playlists](Self::current_user_playlists) until enough playlists have been retrieved.
+    ///
+    /// See [all_current_user_playlists_sync](Self::all_current_user_playlists_sync) for details.
+    ///
+    /// Required scope: [PlaylistReadPrivate](crate::scope::Scope::PlaylistReadPrivate).
+    #[cfg(feature = "sync")]
+    fn current_user_playlists_up_to_sync(&self, max: usize) -> Result<Vec<PartialPlaylist>>
+    where
+        Self: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    {
+        current_user_playlists_sync(self, Some(max))
+    }
+
+    /// Create a new playlist for a Spotify user. The playlist will be empty until items are added to it. The
+    /// playlist's description and visibility may be set with the [request builder this function
+    /// returns](crate::client::request_builder::PlaylistDetailsRequestBuilder).
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for public playlists,
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for private playlists.
+    fn create_playlist<'a, S>(
+        &'a self,
+        user: Id<'a, UserId>,
+        name: S,
+    ) -> PlaylistDetailsRequestBuilder<Self, FullPlaylist>
+    where
+        S: Into<String>,
+    {
+        PlaylistDetailsRequestBuilder::new_with_body(
+            Method::POST,
+            format!("{}/{}/playlists", API_USER_PROFILE_ENDPOINT, user.as_str()),
+            object::PlaylistDetailsBody {
+                name: Some(name.into()),
+                ..Default::default()
+            },
+            self.clone(),
+        )
+    }
+
+    /// Change a playlist's name, description or visibility. The details to change are given with the [request builder
+    /// this function returns](crate::client::request_builder::PlaylistDetailsRequestBuilder). The current user has to
+    /// own the playlist.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for public playlists,
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for private playlists.
+    fn change_playlist_details<'a>(&'a self, playlist: Id<'a, PlaylistId>) -> PlaylistDetailsRequestBuilder<Self, ()> {
+        PlaylistDetailsRequestBuilder::new_with_body(
+            Method::PUT,
+            format!("{}/{}", API_PLAYLISTS_ENDPOINT, playlist.as_str()),
+            object::PlaylistDetailsBody::default(),
+            self.clone(),
+        )
+    }
+
+    /// Add one or more playable items to a playlist; tracks or episodes. By default, the items are appended to the end of
+    /// the playlist. The position to insert the items at may be set with the [`position`-function in the request builder
+    /// this function returns](crate::client::request_builder::AddPlaylistItemsRequestBuilder::position). Returns the
+    /// playlist's new snapshot ID.
+    ///
+    /// Up to 100 items may be given.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for public playlists,
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for private playlists.
+    fn add_items_to_playlist<'a, I, P>(
+        &'a self,
+        playlist: Id<'a, PlaylistId>,
+        items: I,
+    ) -> AddPlaylistItemsRequestBuilder<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PlayableItem<'a>>,
+    {
+        let body = object::AddPlaylistItemsBody {
+            uris: playable_uris(items),
+            position: None,
+        };
+
+        AddPlaylistItemsRequestBuilder::new_with_body(
+            Method::POST,
+            format!("{}/{}/tracks", API_PLAYLISTS_ENDPOINT, playlist.as_str()),
+            body,
+            self.clone(),
+        )
+    }
+
+    /// Remove every occurrence of one or more playable items from a playlist; tracks or episodes. Returns the
+    /// playlist's new snapshot ID.
+    ///
+    /// Up to 100 items may be given.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for public playlists,
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for private playlists.
+    fn remove_items_from_playlist<'a, I, P>(
+        &'a self,
+        playlist: Id<'a, PlaylistId>,
+        items: I,
+    ) -> RemovePlaylistItemsRequestBuilder<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PlayableItem<'a>>,
+    {
+        let body = object::RemovePlaylistItemsBody {
+            tracks: playable_uris(items)
+                .into_iter()
+                .map(|uri| object::PlaylistItemUri { uri })
+                .collect(),
+            snapshot_id: None,
+        };
+
+        RemovePlaylistItemsRequestBuilder::new_with_body(
+            Method::DELETE,
+            format!("{}/{}/tracks", API_PLAYLISTS_ENDPOINT, playlist.as_str()),
+            body,
+            self.clone(),
+        )
+    }
+
+    /// Move a range of items in a playlist to another position. The range and the position are given with the [request
+    /// builder this function returns](crate::client::request_builder::ReorderPlaylistItemsRequestBuilder). Returns the
+    /// playlist's new snapshot ID.
+    ///
+    /// Required scope: [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for public playlists,
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for private playlists.
+    fn reorder_playlist_items<'a>(&'a self, playlist: Id<'a, PlaylistId>) -> ReorderPlaylistItemsRequestBuilder<Self> {
+        ReorderPlaylistItemsRequestBuilder::new_with_body(
+            Method::PUT,
+            format!("{}/{}/tracks", API_PLAYLISTS_ENDPOINT, playlist.as_str()),
+            object::ReorderPlaylistItemsBody::default(),
+            self.clone(),
+        )
+    }
+
+    /// Upload a custom cover image for a playlist. The image is given as the raw bytes of a JPEG image, which are
+    /// base64-encoded before sending them. The current user has to own the playlist.
+    ///
+    /// The image has to be at most 256 KB when base64-encoded. Sending the request otherwise fails with
+    /// [PlaylistCoverTooLarge](crate::error::Error::PlaylistCoverTooLarge)-error.
+    ///
+    /// Required scope: [UgcImageUpload](crate::scope::Scope::UgcImageUpload). Additionally,
+    /// [PlaylistModifyPublic](crate::scope::Scope::PlaylistModifyPublic) for public playlists or
+    /// [PlaylistModifyPrivate](crate::scope::Scope::PlaylistModifyPrivate) for private playlists.
+    fn upload_playlist_cover<'a>(
+        &'a self,
+        playlist: Id<'a, PlaylistId>,
+        jpeg_image: &[u8],
+    ) -> PlaylistCoverRequestBuilder<Self> {
+        PlaylistCoverRequestBuilder::new(
+            Method::PUT,
+            format!("{}/{}/images", API_PLAYLISTS_ENDPOINT, playlist.as_str()),
+            self.clone(),
+        )
+        .with_required_scope(Scope::UgcImageUpload)
+        .with_raw_body("image/jpeg", STANDARD.encode(jpeg_image))
+    }
+}
+
+/// Join the given IDs into a comma-separated list for a query parameter.
+fn join_ids<'a, I, T>(ids: I) -> String
+where
+    I: IntoIterator<Item = Id<'a, T>>,
+    T: ItemTypeId + 'static,
+{
+    ids.into_iter()
+        .map(|id| id.as_str().to_owned())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Convert the given playable items into their Spotify URIs.
+fn playable_uris<'a, I, P>(items: I) -> Vec<String>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<PlayableItem<'a>>,
+{
+    items
+        .into_iter()
+        .map(|item| item.into().as_uri().into_owned())
+        .collect()
+}
+
+/// Build the body for playing the given context from its start.
+fn play_context_body(context: &PlayableContext) -> object::PlayContextBody {
+    object::PlayContextBody {
+        context_uri: context.as_uri().to_string(),
+        offset: None,
+        position_ms: None,
+    }
+}
+
+/// Return the active device from the given devices, if any.
+fn find_active_device(devices: Vec<Device>) -> Option<Device> {
+    devices.into_iter().find(Device::is_active)
+}
+
+/// Return the ID of the playing track to seed a radio with, or an error if no track is playing.
+fn radio_seed(item: Option<CurrentlyPlayingItem>) -> Result<Id<'static, TrackId>> {
+    item.as_ref()
+        .and_then(CurrentlyPlayingItem::playing_track_id)
+        .map(|id| id.as_owned())
+        .ok_or(Error::NoPlayingTrack)
+}
+
+/// The largest page size the current user's playlists can be requested with.
+const CURRENT_USER_PLAYLISTS_PAGE_SIZE: u32 = 50;
+
+/// How many pages of the current user's playlists are requested at a time after the first page.
+#[cfg(feature = "async")]
+const CONCURRENT_PLAYLIST_PAGE_REQUESTS: usize = 4;
+
+#[cfg(feature = "async")]
+async fn current_user_playlists_async<C>(client: &C, max: Option<usize>) -> Result<Vec<PartialPlaylist>>
+where
+    C: ScopedClient + super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+{
+    use futures_util::{stream, StreamExt, TryStreamExt};
+
+    let first_page = client
+        .current_user_playlists()
+        .limit(CURRENT_USER_PLAYLISTS_PAGE_SIZE)
+        .send_async()
+        .await?;
+
+    let total = max.map_or(first_page.total(), |max| max.min(first_page.total()));
+    let page_size = first_page.limit().max(1);
+    let mut playlists = first_page.take_items();
+
+    // the first page tells how many pages there are, so the rest of them can be requested concurrently. buffered keeps
+    // the pages in order regardless of which request finishes first
+    let pages: Vec<_> = stream::iter((page_size..total).step_by(page_size))
+        .map(|offset| {
+            client
+                .current_user_playlists()
+                .limit(CURRENT_USER_PLAYLISTS_PAGE_SIZE)
+                .offset(offset as u32)
+                .send_async()
+        })
+        .buffered(CONCURRENT_PLAYLIST_PAGE_REQUESTS)
+        .try_collect()
+        .await?;
+
+    playlists.extend(pages.into_iter().flat_map(Page::take_items));
+    if let Some(max) = max {
+        playlists.truncate(max);
+    }
+
+    Ok(playlists)
+}
+
+#[cfg(feature = "sync")]
+fn current_user_playlists_sync<C>(client: &C, max: Option<usize>) -> Result<Vec<PartialPlaylist>>
+where
+    C: ScopedClient + super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+{
+    client
+        .current_user_playlists()
+        .limit(CURRENT_USER_PLAYLISTS_PAGE_SIZE)
+        .send_sync()?
+        .all_items_sync(client, max)
 }
 
 #[cfg(feature = "async")]
@@ -456,3 +1424,182 @@ fn handle_player_control_response_sync(response: reqwest::blocking::Response) ->
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::model::id::{EpisodeId, IdFromKnownKind, ShowId};
+
+    /// Returns the offset query parameter in the given request line, or zero if there isn't one.
+    #[cfg(feature = "async")]
+    fn page_offset(request_line: &str) -> usize {
+        request_line
+            .split(['?', '&', ' '])
+            .find_map(|param| param.strip_prefix("offset="))
+            .map_or(0, |offset| offset.parse().unwrap())
+    }
+
+    /// Builds a page of the current user's playlists, named by their position, starting from the given offset.
+    #[cfg(feature = "async")]
+    fn playlists_page(offset: usize, total: usize) -> String {
+        use crate::model::test_responses::CURRENT_USER_PLAYLISTS_RESPONSE;
+
+        let mut page: serde_json::Value = serde_json::from_str(CURRENT_USER_PLAYLISTS_RESPONSE).unwrap();
+        let playlist = page["items"][0].clone();
+        let page_size = CURRENT_USER_PLAYLISTS_PAGE_SIZE as usize;
+
+        page["items"] = (offset..total.min(offset + page_size))
+            .map(|position| {
+                let mut playlist = playlist.clone();
+                playlist["name"] = json!(format!("Playlist {position}"));
+                playlist
+            })
+            .collect();
+        page["limit"] = json!(page_size);
+        page["offset"] = json!(offset);
+        page["total"] = json!(total);
+
+        page.to_string()
+    }
+
+    /// Returns a user client served by a local server that has the given total of playlists, and the offsets of the
+    /// pages of playlists the client requests. The server serves the two token requests creating the client and the
+    /// given number of pages, which may be requested in any order.
+    #[cfg(feature = "async")]
+    async fn current_user_playlists_client(
+        total: usize,
+        pages: usize,
+    ) -> (
+        crate::client::authorization_code::AsyncAuthorizationCodeUserClient,
+        impl Fn() -> Vec<usize>,
+    ) {
+        use crate::{
+            client::SpotifyClientBuilder,
+            test_support::{json_response, request_line, serve_responses_by_request},
+        };
+
+        const TOKEN_RESPONSE: &str = r#"{"access_token":"access token","token_type":"Bearer","expires_in":3600}"#;
+
+        let (url, requests) = serve_responses_by_request(2 + pages, move |head| {
+            let request_line = request_line(head);
+            if request_line.starts_with("POST ") {
+                json_response(TOKEN_RESPONSE)
+            } else {
+                json_response(&playlists_page(page_offset(request_line), total))
+            }
+        });
+
+        let client = SpotifyClientBuilder::new("client ID")
+            .client_secret("client secret")
+            .http_client_async(reqwest::Client::builder().no_proxy().build().unwrap())
+            .api_base_url(url.as_str())
+            .accounts_base_url(url.as_str())
+            .build_async()
+            .await
+            .unwrap()
+            .authorization_code_client_with_refresh_token("refresh token", |_| {})
+            .await
+            .unwrap();
+
+        let page_offsets = move || {
+            let mut page_offsets: Vec<_> = requests
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|head| request_line(head))
+                .filter(|request_line| request_line.starts_with("GET /me/playlists?"))
+                .map(page_offset)
+                .collect();
+            page_offsets.sort_unstable();
+            page_offsets
+        };
+
+        (client, page_offsets)
+    }
+
+    #[cfg(feature = "async")]
+    fn playlist_names(playlists: &[PartialPlaylist]) -> Vec<String> {
+        use crate::model::playlist::CommonPlaylistInformation;
+
+        playlists.iter().map(|playlist| playlist.name().to_owned()).collect()
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn all_current_user_playlists_are_returned_in_order() {
+        let (client, page_offsets) = current_user_playlists_client(120, 3).await;
+        let playlists = client.all_current_user_playlists_async().await.unwrap();
+
+        let expected: Vec<_> = (0..120).map(|position| format!("Playlist {position}")).collect();
+        assert_eq!(playlist_names(&playlists), expected);
+        assert_eq!(page_offsets(), [0, 50, 100]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn current_user_playlists_up_to_max_are_returned() {
+        let (client, page_offsets) = current_user_playlists_client(120, 2).await;
+        let playlists = client.current_user_playlists_up_to_async(60).await.unwrap();
+
+        let expected: Vec<_> = (0..60).map(|position| format!("Playlist {position}")).collect();
+        assert_eq!(playlist_names(&playlists), expected);
+        assert_eq!(page_offsets(), [0, 50]);
+    }
+
+    #[test]
+    fn episodes_are_played_with_episode_uris() {
+        let episode: Id<EpisodeId> = Id::from_url("https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ").unwrap();
+        let track: Id<TrackId> = Id::from_uri("spotify:track:2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+
+        let body = object::PlayItemsBody {
+            uris: playable_uris([PlayableItem::Episode(episode), PlayableItem::Track(track)]),
+            offset: None,
+            position_ms: None,
+        };
+
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            json!({ "uris": ["spotify:episode:512ojhOuo1ktJprKbVcKyQ", "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"] })
+        );
+    }
+
+    #[test]
+    fn active_device_is_found() {
+        let device = |id: &str, is_active: bool| -> Device {
+            serde_json::from_value(json!({
+                "id": id,
+                "is_active": is_active,
+                "is_private_session": false,
+                "is_restricted": false,
+                "name": id,
+                "type": "Computer",
+                "volume_percent": 50
+            }))
+            .unwrap()
+        };
+
+        let active = find_active_device(vec![device("first", false), device("second", true)]);
+        assert_eq!(active.unwrap().id(), "second");
+
+        assert!(find_active_device(vec![device("first", false)]).is_none());
+    }
+
+    #[test]
+    fn shows_are_played_with_show_uris() {
+        let show = PlayableContext::from_url("https://open.spotify.com/show/38bS44xjbVVZ3No3ByF1dJ").unwrap();
+        let body = play_context_body(&show);
+
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            json!({ "context_uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ" })
+        );
+
+        let show: Id<ShowId> = Id::from_uri("spotify:show:38bS44xjbVVZ3No3ByF1dJ").unwrap();
+        assert_eq!(
+            play_context_body(&show.into()).context_uri,
+            "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+        );
+    }
+}
@@ -1,22 +1,54 @@
+use std::borrow::Cow;
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+
 use log::warn;
 use reqwest::{Method, StatusCode};
 
-use super::{request_builder::RequestBuilder, API_USER_PROFILE_ENDPOINT};
+use super::{
+    request_builder::RequestBuilder, API_ALBUMS_ENDPOINT, API_ARTISTS_ENDPOINT, API_AUDIO_ANALYSIS_ENDPOINT,
+    API_AUDIO_FEATURES_ENDPOINT, API_AVAILABLE_GENRE_SEEDS_ENDPOINT, API_CATEGORIES_ENDPOINT, API_EPISODES_ENDPOINT,
+    API_MARKETS_ENDPOINT, API_NEW_RELEASES_ENDPOINT, API_PLAYLISTS_ENDPOINT, API_RECOMMENDATIONS_ENDPOINT,
+    API_SHOWS_ENDPOINT, API_USER_PROFILE_ENDPOINT,
+};
+
+// the most IDs each batch endpoint accepts in a single request
+const MAX_TRACKS_PER_REQUEST: usize = 50;
+const MAX_EPISODES_PER_REQUEST: usize = 50;
+const MAX_ALBUMS_PER_REQUEST: usize = 20;
+const MAX_ARTISTS_PER_REQUEST: usize = 50;
+const MAX_SHOWS_PER_REQUEST: usize = 50;
 #[cfg(feature = "async")]
-use crate::client::request_builder::AsyncResponseHandler;
+use crate::client::request_builder::{AsyncRequestBuilder, AsyncResponseHandler};
 #[cfg(feature = "sync")]
-use crate::client::request_builder::SyncResponseHandler;
+use crate::client::request_builder::{SyncRequestBuilder, SyncResponseHandler};
+#[cfg(feature = "cache")]
+use crate::client::{private::TrackCacheAccess, request_builder::ResultCacheHooks};
 use crate::{
     client::{
         object,
-        request_builder::{BaseRequestBuilderContainer, CatalogItemRequestBuilder, SearchBuilder},
+        request_builder::{
+            BaseRequestBuilderContainer, BrowseRequestBuilder, CatalogItemRequestBuilder, RecommendationsBuilder,
+            SearchBuilder,
+        },
         API_SEARCH_ENDPOINT, API_TRACKS_ENDPOINT,
     },
-    error::Error,
+    error::{Error, Result},
     model::{
-        id::{Id, IdTrait, TrackId, UserId},
-        track::FullTrack,
+        album::{FullAlbum, NewReleases, PartialAlbum},
+        artist::FullArtist,
+        audio::{AudioAnalysis, AudioFeatures},
+        category::{Categories, Category, CategoryPlaylists},
+        episode::{FullEpisode, PartialEpisode},
+        id::{
+            AlbumId, ArtistId, EpisodeId, Id, IdTrait, ItemTypeId, PlayableContext, PlayableItem, PlaylistId, ShowId,
+            SpotifyId, TrackId, UserId,
+        },
+        playlist::{FullPlaylist, PartialPlaylist},
+        show::{FullShow, PartialShow, ShowEpisodes},
+        track::{CommonTrackInformation, FullTrack, NonLocalTrackInformation},
         user::PublicUser,
+        CountryCode, Market, Page, SpotifyObject,
     },
 };
 
@@ -24,13 +56,15 @@ use crate::{
 /// clients implement this trait.
 pub trait UnscopedClient
 where
-    Self: crate::private::Sealed + Clone + Sized,
+    Self: crate::private::Sealed + super::private::TrackCacheAccess + Clone + Sized,
 {
     /// Get Spotify catalog information for a single track identified by its unique Spotify ID.
     ///
     /// An optional market country may be specified with the [`market`-function in the request builder this function
     /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned and
     /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    ///
+    /// If the client has a [track cache](crate::client::cache), the track is returned from the cache if it's in it.
     fn track<'a>(&'a self, track: Id<'a, TrackId>) -> CatalogItemRequestBuilder<Self, FullTrack> {
         let mut builder = CatalogItemRequestBuilder::new(
             Method::GET,
@@ -40,12 +74,21 @@ where
 
         #[cfg(feature = "async")]
         {
-            builder = builder.with_async_response_handler(track_response_handler_async_fn(track.as_owned()));
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(Error::NonexistentTrack(
+                track.as_owned(),
+            )));
         }
 
         #[cfg(feature = "sync")]
         {
-            builder = builder.with_sync_response_handler(track_response_handler_sync_fn(track.as_owned()));
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(Error::NonexistentTrack(
+                track.as_owned(),
+            )));
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            builder = builder.with_result_cache(track_cache_hooks(track.as_owned()));
         }
 
         builder
@@ -58,12 +101,116 @@ where
     /// An optional market country may be specified with the [`market`-function in the request builder this function
     /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned and
     /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    ///
+    /// If the client has a [track cache](crate::client::cache), the tracks are returned from the cache only if every
+    /// track is in it. Otherwise, every track is requested from Spotify.
     fn tracks<'a, I>(&'a self, tracks: I) -> CatalogItemRequestBuilder<Self, object::TracksResponse, Vec<FullTrack>>
     where
         I: IntoIterator<Item = Id<'a, TrackId>>,
     {
-        CatalogItemRequestBuilder::new(Method::GET, API_TRACKS_ENDPOINT, self.clone()).append_query(
+        let tracks: Vec<_> = tracks.into_iter().collect();
+
+        let builder = CatalogItemRequestBuilder::new(Method::GET, API_TRACKS_ENDPOINT, self.clone()).append_query(
             object::TRACKS_IDS_QUERY,
+            tracks
+                .iter()
+                .map(|id| id.as_str().to_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+
+        #[cfg(feature = "cache")]
+        let builder = builder.with_result_cache(tracks_cache_hooks(tracks.iter().map(|id| id.as_owned()).collect()));
+
+        builder
+    }
+
+    /// Get Spotify catalog information for a single track in the given market, and if [track
+    /// relinking](crate::model::track#track-equality-and-track-relinking) was applied to it, the original track it is
+    /// linked from. Returns the track available in the market and the original track, or `None` if the track wasn't
+    /// relinked.
+    ///
+    /// The original track is requested without a market, since it's generally not available in the given market.
+    #[cfg(feature = "async")]
+    fn track_with_original_async<'a, M>(
+        &'a self,
+        track: Id<'a, TrackId>,
+        market: M,
+    ) -> impl Future<Output = Result<(FullTrack, Option<FullTrack>)>> + Send + 'a
+    where
+        Self: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+        M: Into<Market>,
+    {
+        let market = market.into();
+
+        async move {
+            let track = self.track(track).market(market).send_async().await?;
+            let original = match track.linked_from() {
+                Some(linked_from) => Some(self.track(linked_from.id.clone()).send_async().await?),
+                None => None,
+            };
+
+            Ok((track, original))
+        }
+    }
+
+    /// Get Spotify catalog information for a single track in the given market, and if track relinking was applied to
+    /// it, the original track it is linked from.
+    ///
+    /// See [track_with_original_async](Self::track_with_original_async) for details.
+    #[cfg(feature = "sync")]
+    fn track_with_original_sync<M>(&self, track: Id<'_, TrackId>, market: M) -> Result<(FullTrack, Option<FullTrack>)>
+    where
+        Self: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+        M: Into<Market>,
+    {
+        let track = self.track(track).market(market).send_sync()?;
+        let original = match track.linked_from() {
+            Some(linked_from) => Some(self.track(linked_from.id.clone()).send_sync()?),
+            None => None,
+        };
+
+        Ok((track, original))
+    }
+
+    /// Get audio features for a single track identified by its unique Spotify ID.
+    fn track_audio_features<'a>(&'a self, track: Id<'a, TrackId>) -> RequestBuilder<Self, AudioFeatures> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_AUDIO_FEATURES_ENDPOINT, track.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(Error::NonexistentTrack(
+                track.as_owned(),
+            )));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(Error::NonexistentTrack(
+                track.as_owned(),
+            )));
+        }
+
+        builder
+    }
+
+    /// Get audio features for multiple tracks based on their Spotify IDs.
+    ///
+    /// Up to 100 IDs may be given. The returned audio features are in the same order as the given IDs. In case some IDs
+    /// cannot be found, their corresponding audio features will be `None`.
+    fn tracks_audio_features<'a, I>(
+        &'a self,
+        tracks: I,
+    ) -> RequestBuilder<Self, object::AudioFeaturesResponse, (), Vec<Option<AudioFeatures>>>
+    where
+        I: IntoIterator<Item = Id<'a, TrackId>>,
+    {
+        RequestBuilder::new(Method::GET, API_AUDIO_FEATURES_ENDPOINT, self.clone()).append_query(
+            object::AUDIO_FEATURES_IDS_QUERY,
             tracks
                 .into_iter()
                 .map(|id| id.as_str().to_owned())
@@ -72,16 +219,440 @@ where
         )
     }
 
+    /// Get a detailed audio analysis for a single track identified by its unique Spotify ID. The analysis describes the
+    /// track's structure and musical content, including its rhythm, pitch and timbre.
+    fn track_audio_analysis<'a>(&'a self, track: Id<'a, TrackId>) -> RequestBuilder<Self, AudioAnalysis> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_AUDIO_ANALYSIS_ENDPOINT, track.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(
+                Error::NonexistentTrack(track.as_owned()),
+            ));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(Error::NonexistentTrack(
+                track.as_owned(),
+            )));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information for a single album identified by its unique Spotify ID.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied to the album's tracks.
+    /// The following pages of the album's tracks are requested in the same market.
+    fn album<'a>(&'a self, album: Id<'a, AlbumId>) -> CatalogItemRequestBuilder<Self, FullAlbum> {
+        let mut builder = CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_ALBUMS_ENDPOINT, album.as_str()),
+            self.clone(),
+        )
+        .with_market_hook(Box::new(|album: &mut FullAlbum, market| {
+            album.set_tracks_market(market)
+        }));
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(Error::NonexistentAlbum(
+                album.as_owned(),
+            )));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(Error::NonexistentAlbum(
+                album.as_owned(),
+            )));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information for multiple albums based on their Spotify IDs.
+    ///
+    /// Up to 20 IDs may be given. The returned albums are in the same order as the given IDs. In case some IDs cannot be
+    /// found, their corresponding albums will be `None`.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied to the albums' tracks.
+    /// The following pages of the albums' tracks are requested in the same market.
+    fn albums<'a, I>(
+        &'a self,
+        albums: I,
+    ) -> CatalogItemRequestBuilder<Self, object::AlbumsResponse, Vec<Option<FullAlbum>>>
+    where
+        I: IntoIterator<Item = Id<'a, AlbumId>>,
+    {
+        CatalogItemRequestBuilder::new(Method::GET, API_ALBUMS_ENDPOINT, self.clone())
+            .append_query(
+                object::ALBUMS_IDS_QUERY,
+                albums
+                    .into_iter()
+                    .map(|id| id.as_str().to_owned())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+            .with_market_hook(Box::new(|albums: &mut Vec<Option<FullAlbum>>, market| {
+                for album in albums.iter_mut().flatten() {
+                    album.set_tracks_market(market);
+                }
+            }))
+    }
+
+    /// Get Spotify catalog information for a single artist identified by their unique Spotify ID.
+    fn artist<'a>(&'a self, artist: Id<'a, ArtistId>) -> RequestBuilder<Self, FullArtist> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_ARTISTS_ENDPOINT, artist.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(
+                Error::NonexistentArtist(artist.as_owned()),
+            ));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(
+                Error::NonexistentArtist(artist.as_owned()),
+            ));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information for several artists based on their Spotify IDs.
+    ///
+    /// Up to 50 IDs may be given. The returned artists are in the same order as the given IDs. In case some IDs cannot
+    /// be found, their corresponding artists will be `None`.
+    fn artists<'a, I>(&'a self, artists: I) -> RequestBuilder<Self, object::ArtistsResponse, (), Vec<Option<FullArtist>>>
+    where
+        I: IntoIterator<Item = Id<'a, ArtistId>>,
+    {
+        RequestBuilder::new(Method::GET, API_ARTISTS_ENDPOINT, self.clone()).append_query(
+            object::ARTISTS_IDS_QUERY,
+            artists
+                .into_iter()
+                .map(|id| id.as_str().to_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    /// Get Spotify catalog information about an artist's top tracks in a given market country.
+    ///
+    /// The tracks are only returned if they are available in the given market, and [track
+    /// relinking](crate::model::track#track-equality-and-track-relinking) may be applied.
+    fn artist_top_tracks<'a, M>(
+        &'a self,
+        artist: Id<'a, ArtistId>,
+        market: M,
+    ) -> RequestBuilder<Self, object::TracksResponse, (), Vec<FullTrack>>
+    where
+        M: Into<Market>,
+    {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}/top-tracks", API_ARTISTS_ENDPOINT, artist.as_str()),
+            self.clone(),
+        )
+        .append_query(object::MARKET_QUERY, market.into().to_string());
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(
+                Error::NonexistentArtist(artist.as_owned()),
+            ));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(
+                Error::NonexistentArtist(artist.as_owned()),
+            ));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information about artists similar to a given artist. Similarity is based on analysis of the
+    /// Spotify community's listening history.
+    fn related_artists<'a>(
+        &'a self,
+        artist: Id<'a, ArtistId>,
+    ) -> RequestBuilder<Self, object::RelatedArtistsResponse, (), Vec<FullArtist>> {
+        let mut builder = RequestBuilder::new(
+            Method::GET,
+            format!("{}/{}/related-artists", API_ARTISTS_ENDPOINT, artist.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(
+                Error::NonexistentArtist(artist.as_owned()),
+            ));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(
+                Error::NonexistentArtist(artist.as_owned()),
+            ));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information for a single episode identified by its unique Spotify ID.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned.
+    fn episode<'a>(&'a self, episode: Id<'a, EpisodeId>) -> CatalogItemRequestBuilder<Self, FullEpisode> {
+        let mut builder = CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_EPISODES_ENDPOINT, episode.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(
+                Error::NonexistentEpisode(episode.as_owned()),
+            ));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(
+                Error::NonexistentEpisode(episode.as_owned()),
+            ));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information for multiple episodes based on their Spotify IDs.
+    ///
+    /// Up to 50 IDs may be given. The returned episodes are in the same order as the given IDs. In case some IDs cannot
+    /// be found, their corresponding episodes will be `None`.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned.
+    fn episodes<'a, I>(
+        &'a self,
+        episodes: I,
+    ) -> CatalogItemRequestBuilder<Self, object::EpisodesResponse, Vec<Option<FullEpisode>>>
+    where
+        I: IntoIterator<Item = Id<'a, EpisodeId>>,
+    {
+        CatalogItemRequestBuilder::new(Method::GET, API_EPISODES_ENDPOINT, self.clone()).append_query(
+            object::EPISODES_IDS_QUERY,
+            episodes
+                .into_iter()
+                .map(|id| id.as_str().to_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    /// Get Spotify catalog information for a single show identified by its unique Spotify ID.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned.
+    fn show<'a>(&'a self, show: Id<'a, ShowId>) -> CatalogItemRequestBuilder<Self, FullShow> {
+        let mut builder = CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_SHOWS_ENDPOINT, show.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(Error::NonexistentShow(
+                show.as_owned(),
+            )));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(Error::NonexistentShow(
+                show.as_owned(),
+            )));
+        }
+
+        builder
+    }
+
+    /// Get Spotify catalog information for multiple shows based on their Spotify IDs.
+    ///
+    /// Up to 50 IDs may be given. The returned shows are in the same order as the given IDs. In case some IDs cannot be
+    /// found, their corresponding shows will be `None`. Spotify doesn't return the shows' episodes, so the shows are
+    /// partial.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned.
+    fn shows<'a, I>(
+        &'a self,
+        shows: I,
+    ) -> CatalogItemRequestBuilder<Self, object::ShowsResponse, Vec<Option<PartialShow>>>
+    where
+        I: IntoIterator<Item = Id<'a, ShowId>>,
+    {
+        CatalogItemRequestBuilder::new(Method::GET, API_SHOWS_ENDPOINT, self.clone()).append_query(
+            object::SHOWS_IDS_QUERY,
+            shows
+                .into_iter()
+                .map(|id| id.as_str().to_owned())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    /// Get Spotify catalog information about a show's episodes.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned.
+    fn show_episodes<'a>(
+        &'a self,
+        show: Id<'a, ShowId>,
+    ) -> CatalogItemRequestBuilder<Self, ShowEpisodes, Page<ShowEpisodes, PartialEpisode>> {
+        let mut builder = CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{}/{}/episodes", API_SHOWS_ENDPOINT, show.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(Error::NonexistentShow(
+                show.as_owned(),
+            )));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(Error::NonexistentShow(
+                show.as_owned(),
+            )));
+        }
+
+        builder
+    }
+
     /// Get Spotify catalog information about albums, artists, playlists, tracks, shows or episodes that match a keyword
     /// string.
     ///
     /// This function returns a [SearchBuilder](self::SearchBuilder) that you can use to configure the various search
     /// parameters and finally send the search query and get the results back.
+    /// The results contain the first page of each kind of result; see [SearchResults](crate::model::search::SearchResults)
+    /// on how to retrieve the following pages.
     fn search<S>(&self, query: S) -> SearchBuilder<Self>
     where
-        S: Into<String>,
+        S: Into<Cow<'static, str>>,
     {
-        SearchBuilder::new(Method::GET, API_SEARCH_ENDPOINT, self.clone()).query(query.into())
+        SearchBuilder::new(Method::GET, API_SEARCH_ENDPOINT, self.clone()).query(query)
+    }
+
+    /// Get track recommendations based on seed artists, tracks and genres.
+    ///
+    /// This function returns a [RecommendationsBuilder](self::RecommendationsBuilder) that you can use to configure the
+    /// seeds and the tunable track attributes, and finally send the request and get the recommendations back. At least
+    /// one and at most five seeds in total have to be given.
+    fn recommendations(&self) -> RecommendationsBuilder<Self> {
+        RecommendationsBuilder::new(Method::GET, API_RECOMMENDATIONS_ENDPOINT, self.clone())
+    }
+
+    /// Get the genres that may be used as seeds with the [`seed_genres`-function in the recommendations
+    /// builder](RecommendationsBuilder::seed_genres).
+    fn available_genre_seeds(&self) -> RequestBuilder<Self, object::GenreSeedsResponse, (), Vec<String>> {
+        RequestBuilder::new(Method::GET, API_AVAILABLE_GENRE_SEEDS_ENDPOINT, self.clone())
+    }
+
+    /// Get the markets where Spotify is available. Useful for checking a market is supported before using it in
+    /// market-specific requests.
+    fn available_markets(&self) -> RequestBuilder<Self, object::MarketsResponse, (), Vec<CountryCode>> {
+        RequestBuilder::new(Method::GET, API_MARKETS_ENDPOINT, self.clone())
+    }
+
+    /// Get a list of categories used to tag items in Spotify's browse interface.
+    ///
+    /// The country the categories are relevant to and the language they're returned in may be specified with the
+    /// [`country`](BrowseRequestBuilder::country) and [`locale`](BrowseRequestBuilder::locale)-functions in the request
+    /// builder this function returns.
+    fn categories(&self) -> BrowseRequestBuilder<Self, Categories, Page<Categories, Category>> {
+        BrowseRequestBuilder::new(Method::GET, API_CATEGORIES_ENDPOINT, self.clone())
+    }
+
+    /// Get a list of Spotify playlists tagged with a category. The category ID is the same as returned by
+    /// [`Category::id`](crate::model::category::Category::id), for example `toplists`.
+    ///
+    /// The country the playlists are relevant to may be specified with the [`country`-function in the request builder
+    /// this function returns](BrowseRequestBuilder::country).
+    fn category_playlists<S>(
+        &self,
+        category_id: S,
+    ) -> BrowseRequestBuilder<Self, CategoryPlaylists, Page<CategoryPlaylists, PartialPlaylist>>
+    where
+        S: AsRef<str>,
+    {
+        BrowseRequestBuilder::new(
+            Method::GET,
+            format!("{API_CATEGORIES_ENDPOINT}/{}/playlists", category_id.as_ref()),
+            self.clone(),
+        )
+    }
+
+    /// Get a list of new album releases featured in Spotify.
+    ///
+    /// The country the releases are relevant to may be specified with the [`country`-function in the request builder
+    /// this function returns](BrowseRequestBuilder::country).
+    fn new_releases(&self) -> BrowseRequestBuilder<Self, NewReleases, Page<NewReleases, PartialAlbum>> {
+        BrowseRequestBuilder::new(Method::GET, API_NEW_RELEASES_ENDPOINT, self.clone())
+    }
+
+    /// Get a playlist owned by a Spotify user.
+    ///
+    /// An optional market country may be specified with the [`market`-function in the request builder this function
+    /// returns](CatalogItemRequestBuilder::market). Only content that is available in that market will be returned and
+    /// [track relinking](crate::model::track#track-equality-and-track-relinking) may be applied to the playlist's
+    /// tracks.
+    fn playlist<'a>(&'a self, playlist: Id<'a, PlaylistId>) -> CatalogItemRequestBuilder<Self, FullPlaylist> {
+        let mut builder = CatalogItemRequestBuilder::new(
+            Method::GET,
+            format!("{}/{}", API_PLAYLISTS_ENDPOINT, playlist.as_str()),
+            self.clone(),
+        );
+
+        #[cfg(feature = "async")]
+        {
+            builder = builder.with_async_response_handler(not_found_response_handler_async_fn(
+                Error::NonexistentPlaylist(playlist.as_owned()),
+            ));
+        }
+
+        #[cfg(feature = "sync")]
+        {
+            builder = builder.with_sync_response_handler(not_found_response_handler_sync_fn(
+                Error::NonexistentPlaylist(playlist.as_owned()),
+            ));
+        }
+
+        builder
     }
 
     /// Get public information about a Spotify user.
@@ -92,18 +663,284 @@ where
             self.clone(),
         )
     }
+
+    /// Get Spotify catalog information for items of any type based on their Spotify IDs, for example to resolve
+    /// whatever IDs or URIs a user has pasted. The IDs are grouped by their type and each type is requested in batches
+    /// from its batch endpoint, if it has one. Playlists and users are requested one by one. The requests are sent
+    /// concurrently.
+    ///
+    /// The returned objects are in the same order as the given IDs. In case some IDs cannot be found, they will be
+    /// omitted from the result. Users' Liked Songs [collections](crate::model::id::PlayableContext::Collection) aren't
+    /// catalog items, so they are omitted as well.
+    #[cfg(feature = "async")]
+    fn fetch_details_async<'a, I>(&'a self, ids: I) -> impl Future<Output = Result<Vec<SpotifyObject>>> + Send + 'a
+    where
+        I: IntoIterator<Item = SpotifyId<'a>>,
+        Self: super::private::BuildHttpRequestAsync + super::private::AccessTokenExpiryAsync + Send + Sync,
+    {
+        let ids = GroupedIds::new(ids);
+
+        async move {
+            let mut requests: Vec<PositionedObjectsFuture> = Vec::new();
+
+            for chunk in ids.tracks.chunks(MAX_TRACKS_PER_REQUEST) {
+                requests.push(Box::pin(async move {
+                    let tracks = self.tracks(borrowed_ids(chunk)).send_async().await?;
+                    Ok(positioned_tracks(chunk, tracks))
+                }));
+            }
+
+            for chunk in ids.episodes.chunks(MAX_EPISODES_PER_REQUEST) {
+                requests.push(Box::pin(async move {
+                    let episodes = self.episodes(borrowed_ids(chunk)).send_async().await?;
+                    Ok(positioned(chunk, episodes, SpotifyObject::Episode))
+                }));
+            }
+
+            for chunk in ids.albums.chunks(MAX_ALBUMS_PER_REQUEST) {
+                requests.push(Box::pin(async move {
+                    let albums = self.albums(borrowed_ids(chunk)).send_async().await?;
+                    Ok(positioned(chunk, albums, SpotifyObject::Album))
+                }));
+            }
+
+            for chunk in ids.artists.chunks(MAX_ARTISTS_PER_REQUEST) {
+                requests.push(Box::pin(async move {
+                    let artists = self.artists(borrowed_ids(chunk)).send_async().await?;
+                    Ok(positioned(chunk, artists, SpotifyObject::Artist))
+                }));
+            }
+
+            for chunk in ids.shows.chunks(MAX_SHOWS_PER_REQUEST) {
+                requests.push(Box::pin(async move {
+                    let shows = self.shows(borrowed_ids(chunk)).send_async().await?;
+                    Ok(positioned(chunk, shows, SpotifyObject::Show))
+                }));
+            }
+
+            for (index, id) in &ids.playlists {
+                requests.push(Box::pin(async move {
+                    let playlist = found(self.playlist(id.as_borrowed()).send_async().await)?;
+                    Ok(Vec::from_iter(
+                        playlist.map(|playlist| (*index, SpotifyObject::Playlist(playlist))),
+                    ))
+                }));
+            }
+
+            for (index, id) in &ids.users {
+                requests.push(Box::pin(async move {
+                    let user = found(self.user_profile(id.as_borrowed()).send_async().await)?;
+                    Ok(Vec::from_iter(user.map(|user| (*index, SpotifyObject::User(user)))))
+                }));
+            }
+
+            let objects = futures_util::future::try_join_all(requests).await?;
+            Ok(ordered_objects(ids.count, objects.into_iter().flatten()))
+        }
+    }
+
+    /// Get Spotify catalog information for items of any type based on their Spotify IDs. The requests are sent one at
+    /// a time.
+    ///
+    /// See [fetch_details_async](Self::fetch_details_async) for details.
+    #[cfg(feature = "sync")]
+    fn fetch_details_sync<'a, I>(&self, ids: I) -> Result<Vec<SpotifyObject>>
+    where
+        I: IntoIterator<Item = SpotifyId<'a>>,
+        Self: super::private::BuildHttpRequestSync + super::private::AccessTokenExpirySync,
+    {
+        let ids = GroupedIds::new(ids);
+        let mut objects = Vec::new();
+
+        for chunk in ids.tracks.chunks(MAX_TRACKS_PER_REQUEST) {
+            let tracks = self.tracks(borrowed_ids(chunk)).send_sync()?;
+            objects.extend(positioned_tracks(chunk, tracks));
+        }
+
+        for chunk in ids.episodes.chunks(MAX_EPISODES_PER_REQUEST) {
+            let episodes = self.episodes(borrowed_ids(chunk)).send_sync()?;
+            objects.extend(positioned(chunk, episodes, SpotifyObject::Episode));
+        }
+
+        for chunk in ids.albums.chunks(MAX_ALBUMS_PER_REQUEST) {
+            let albums = self.albums(borrowed_ids(chunk)).send_sync()?;
+            objects.extend(positioned(chunk, albums, SpotifyObject::Album));
+        }
+
+        for chunk in ids.artists.chunks(MAX_ARTISTS_PER_REQUEST) {
+            let artists = self.artists(borrowed_ids(chunk)).send_sync()?;
+            objects.extend(positioned(chunk, artists, SpotifyObject::Artist));
+        }
+
+        for chunk in ids.shows.chunks(MAX_SHOWS_PER_REQUEST) {
+            let shows = self.shows(borrowed_ids(chunk)).send_sync()?;
+            objects.extend(positioned(chunk, shows, SpotifyObject::Show));
+        }
+
+        for (index, id) in &ids.playlists {
+            if let Some(playlist) = found(self.playlist(id.as_borrowed()).send_sync())? {
+                objects.push((*index, SpotifyObject::Playlist(playlist)));
+            }
+        }
+
+        for (index, id) in &ids.users {
+            if let Some(user) = found(self.user_profile(id.as_borrowed()).send_sync())? {
+                objects.push((*index, SpotifyObject::User(user)));
+            }
+        }
+
+        Ok(ordered_objects(ids.count, objects))
+    }
+}
+
+/// A request for some of the objects in [fetch_details_async](UnscopedClient::fetch_details_async), resolving into the
+/// objects along with their positions in the original order.
+#[cfg(feature = "async")]
+type PositionedObjectsFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<(usize, SpotifyObject)>>> + Send + 'a>>;
+
+/// Spotify IDs grouped by their type, each along with its position in the original order.
+#[derive(Debug, Default)]
+struct GroupedIds {
+    count: usize,
+    tracks: Vec<(usize, Id<'static, TrackId>)>,
+    episodes: Vec<(usize, Id<'static, EpisodeId>)>,
+    albums: Vec<(usize, Id<'static, AlbumId>)>,
+    artists: Vec<(usize, Id<'static, ArtistId>)>,
+    shows: Vec<(usize, Id<'static, ShowId>)>,
+    playlists: Vec<(usize, Id<'static, PlaylistId>)>,
+    users: Vec<(usize, Id<'static, UserId>)>,
+}
+
+impl GroupedIds {
+    fn new<'a, I>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = SpotifyId<'a>>,
+    {
+        let mut grouped = Self::default();
+
+        for (index, id) in ids.into_iter().enumerate() {
+            grouped.count += 1;
+
+            match id {
+                SpotifyId::Item(PlayableItem::Track(id)) => grouped.tracks.push((index, id.as_owned())),
+                SpotifyId::Item(PlayableItem::Episode(id)) => grouped.episodes.push((index, id.as_owned())),
+                SpotifyId::Context(PlayableContext::Album(id)) => grouped.albums.push((index, id.as_owned())),
+                SpotifyId::Context(PlayableContext::Artist(id)) => grouped.artists.push((index, id.as_owned())),
+                SpotifyId::Context(PlayableContext::Show(id)) => grouped.shows.push((index, id.as_owned())),
+                SpotifyId::Context(PlayableContext::Playlist(id)) => grouped.playlists.push((index, id.as_owned())),
+                SpotifyId::User(id) => grouped.users.push((index, id.as_owned())),
+
+                // a user's Liked Songs can't be looked up from the catalog
+                SpotifyId::Context(PlayableContext::Collection(_)) => (),
+            }
+        }
+
+        grouped
+    }
+}
+
+/// Returns the IDs in the given chunk of grouped IDs without their positions.
+fn borrowed_ids<'a, T>(chunk: &'a [(usize, Id<'static, T>)]) -> impl Iterator<Item = Id<'a, T>>
+where
+    T: ItemTypeId + 'static,
+{
+    chunk.iter().map(|(_, id)| id.as_borrowed())
+}
+
+/// Pairs the objects a batch endpoint returned in the same order as the requested IDs with the IDs' positions,
+/// skipping the objects that weren't found.
+fn positioned<I, O>(
+    chunk: &[(usize, I)],
+    objects: Vec<Option<O>>,
+    to_object: fn(O) -> SpotifyObject,
+) -> Vec<(usize, SpotifyObject)> {
+    chunk
+        .iter()
+        .zip(objects)
+        .filter_map(|((index, _), object)| Some((*index, to_object(object?))))
+        .collect()
+}
+
+/// Pairs the tracks with the positions of the IDs they were requested with. Unlike the other batch endpoints, the
+/// tracks endpoint omits the tracks that weren't found, so the tracks are matched by their IDs instead.
+fn positioned_tracks(chunk: &[(usize, Id<'static, TrackId>)], tracks: Vec<FullTrack>) -> Vec<(usize, SpotifyObject)> {
+    chunk
+        .iter()
+        .filter_map(|(index, id)| {
+            let track = tracks.iter().find(|track| track.id() == *id)?;
+            Some((*index, SpotifyObject::Track(track.clone())))
+        })
+        .collect()
+}
+
+/// Turns the not found error of a single item lookup into `None`.
+fn found<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(item) => Ok(Some(item)),
+        Err(Error::NonexistentPlaylist(_) | Error::UnhandledSpotifyResponseStatusCode(404)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns the objects sorted by their positions in the original order.
+fn ordered_objects<I>(count: usize, objects: I) -> Vec<SpotifyObject>
+where
+    I: IntoIterator<Item = (usize, SpotifyObject)>,
+{
+    let mut ordered: Vec<Option<SpotifyObject>> = vec![None; count];
+
+    for (index, object) in objects {
+        ordered[index] = Some(object);
+    }
+
+    ordered.into_iter().flatten().collect()
+}
+
+/// Returns cache hooks that look up and store a single track in the client's track cache.
+#[cfg(feature = "cache")]
+fn track_cache_hooks<C>(track: Id<'static, TrackId>) -> ResultCacheHooks<C, FullTrack>
+where
+    C: TrackCacheAccess,
+{
+    ResultCacheHooks {
+        lookup: Box::new(move |client: &C, market| client.track_cache()?.get(&track, market)),
+        store: Box::new(|client: &C, market, track| {
+            if let Some(cache) = client.track_cache() {
+                cache.insert(track, market);
+            }
+        }),
+    }
 }
 
+/// Returns cache hooks that look up and store multiple tracks in the client's track cache.
+#[cfg(feature = "cache")]
+fn tracks_cache_hooks<C>(tracks: Vec<Id<'static, TrackId>>) -> ResultCacheHooks<C, Vec<FullTrack>>
+where
+    C: TrackCacheAccess,
+{
+    ResultCacheHooks {
+        lookup: Box::new(move |client: &C, market| client.track_cache()?.get_all(&tracks, market)),
+        store: Box::new(|client: &C, market, tracks| {
+            if let Some(cache) = client.track_cache() {
+                for track in tracks {
+                    cache.insert(track, market);
+                }
+            }
+        }),
+    }
+}
+
+/// Returns a response handler that returns the given error if Spotify responds with 404 Not Found.
 #[cfg(feature = "async")]
-fn track_response_handler_async_fn(track_id: Id<'static, TrackId>) -> AsyncResponseHandler {
+pub(super) fn not_found_response_handler_async_fn(not_found_error: Error) -> AsyncResponseHandler {
     Box::new(move |response| {
         Box::pin(async move {
             match response.status() {
                 StatusCode::OK => Ok(response),
 
                 StatusCode::NOT_FOUND => {
-                    warn!("Got 404 Not Found to track call");
-                    Err(Error::NonexistentTrack(track_id))
+                    warn!("Got 404 Not Found to catalog item call");
+                    Err(not_found_error)
                 }
 
                 other => Err(Error::UnhandledSpotifyResponseStatusCode(other.as_u16())),
@@ -112,16 +949,46 @@ fn track_response_handler_async_fn(track_id: Id<'static, TrackId>) -> AsyncRespo
     })
 }
 
+/// Returns a response handler that returns the given error if Spotify responds with 404 Not Found.
 #[cfg(feature = "sync")]
-fn track_response_handler_sync_fn(track_id: Id<'static, TrackId>) -> SyncResponseHandler {
+pub(super) fn not_found_response_handler_sync_fn(not_found_error: Error) -> SyncResponseHandler {
     Box::new(move |response| match response.status() {
         StatusCode::OK => Ok(response),
 
         StatusCode::NOT_FOUND => {
-            warn!("Got 404 Not Found to track call");
-            Err(Error::NonexistentTrack(track_id))
+            warn!("Got 404 Not Found to catalog item call");
+            Err(not_found_error)
         }
 
         other => Err(Error::UnhandledSpotifyResponseStatusCode(other.as_u16())),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::id::IdFromKnownKind;
+
+    #[test]
+    fn ids_are_grouped_by_type_with_their_positions() {
+        let ids = [
+            "spotify:track:2pDPOMX0kWA7kcPBcDCQBu",
+            "spotify:album:0tGPJ0bkWOUmH7MEOR77qc",
+            "spotify:user:spotify:collection",
+            "spotify:track:3n3Ppam7vgaVa1iaRUc9Lp",
+            "spotify:user:spotify",
+        ]
+        .map(|uri| SpotifyId::from_uri(uri).unwrap());
+
+        let grouped = GroupedIds::new(ids);
+        let tracks: Vec<_> = grouped.tracks.iter().map(|(index, id)| (*index, id.as_str())).collect();
+
+        assert_eq!(grouped.count, 5);
+        assert_eq!(tracks, [(0, "2pDPOMX0kWA7kcPBcDCQBu"), (3, "3n3Ppam7vgaVa1iaRUc9Lp")]);
+        assert_eq!(grouped.albums.len(), 1);
+        assert_eq!(grouped.albums[0].0, 1);
+        assert_eq!(grouped.users.len(), 1);
+        assert_eq!(grouped.users[0].0, 4);
+        assert!(grouped.artists.is_empty() && grouped.playlists.is_empty());
+    }
+}
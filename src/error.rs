@@ -2,14 +2,14 @@
 
 use std::borrow::Cow;
 #[cfg(any(feature = "async", feature = "sync"))]
-use std::convert::Infallible;
+use std::{convert::Infallible, time::Duration};
 
 use thiserror::Error;
 
 use crate::model::ItemType;
 #[cfg(any(feature = "async", feature = "sync"))]
 use crate::model::{
-    error::AuthenticationErrorKind,
+    error::{AuthenticationErrorKind, PlayerError},
     id::{AlbumId, ArtistId, EpisodeId, Id, PlaylistId, ShowId, TrackId},
 };
 
@@ -34,6 +34,23 @@ pub enum Error {
     #[error("The authorization code is invalid")]
     InvalidAuthorizationCode,
 
+    /// The user denied the application's authorization request, or Spotify otherwise redirected back with an error
+    /// instead of an authorization code. The error string from the callback URL is included.
+    #[error("The authorization request was denied: {0}")]
+    AuthorizationDenied(String),
+
+    /// The local redirect server used to
+    /// [finalize an authorization code client](crate::client::authorization_code::IncompleteAuthorizationCodeUserClient)
+    /// failed to start or to receive the redirect request.
+    #[cfg(feature = "redirect-server")]
+    #[error("The local redirect server failed: {0}")]
+    RedirectServer(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// The local redirect server didn't receive the authorization callback within the given timeout.
+    #[cfg(feature = "redirect-server")]
+    #[error("The authorization callback was not received in time")]
+    RedirectTimeout,
+
     /// The access token expired and was not automatically refreshed, due to automatic token refreshind being disabled
     /// or it being impossible ([ImplicitGrantUserClient](crate::client::implicit_grant::ImplicitGrantUserClient)
     /// does not support refreshing its access token).
@@ -50,9 +67,14 @@ pub enum Error {
     #[error("The client ID and/or secret is invalid")]
     InvalidClient(String),
 
+    /// The environment variable a client's credentials were to be read from is not set, or its value is not valid
+    /// Unicode. The variable's name is included.
+    #[error("Environment variable {0} is not set or is not valid Unicode")]
+    MissingEnvironmentVariable(String),
+
     /// Request rate limit was hit. The required wait time is included.
-    #[error("Request rate limit hit; retry after {0} seconds")]
-    RateLimit(u64),
+    #[error("Request rate limit hit; retry after {0:?}")]
+    RateLimit(Duration),
 
     /// The required scope for the endpoint being called has not been granted by the user.
     #[error("The required scope for the endpoint has not been granted by the user")]
@@ -78,6 +100,12 @@ pub enum Error {
     #[error("A Spotify Premium account is required")]
     PremiumRequired,
 
+    /// A player control was forbidden for the reason included. Player controls that fail due to a
+    /// [restriction](Self::Restricted) or a [missing Spotify Premium account](Self::PremiumRequired) are returned as
+    /// their own errors instead.
+    #[error("The player control failed: {0:?}")]
+    PlayerRestricted(PlayerError),
+
     /// No device is currently active in the user's account, the active device didn't respond to the playback in a \
     /// timely manner or the given device could not be activated for playback.
     ///
@@ -89,6 +117,11 @@ pub enum Error {
     )]
     NoActiveDevice,
 
+    /// Nothing is playing in the user's account, or the playing item is not a public track (e.g. an episode or an ad is
+    /// playing, or the user has a private session enabled).
+    #[error("Nothing is playing or the playing item is not a track")]
+    NoPlayingTrack,
+
     /// The given track ID doesn't refer to any Spotify track.
     #[error("Nonexistent track ID: {0}")]
     NonexistentTrack(Id<'static, TrackId>),
@@ -113,6 +146,44 @@ pub enum Error {
     #[error("Nonexistent episode ID: {0}")]
     NonexistentEpisode(Id<'static, EpisodeId>),
 
+    /// Spotify has no lyrics for the given track, such as when the track is instrumental. The track ID is included.
+    #[cfg(feature = "lyrics")]
+    #[error("No lyrics available for track ID: {0}")]
+    LyricsUnavailable(Id<'static, TrackId>),
+
+    /// A [recommendations request](crate::client::request_builder::RecommendationsBuilder) was given too few or too
+    /// many seeds. The total number of seed artists, tracks and genres has to be between 1 and 5. The given number of
+    /// seeds is included.
+    #[error("Recommendations require between 1 and 5 seeds in total, {0} given")]
+    InvalidRecommendationSeedCount(usize),
+
+    /// A [search](crate::client::request_builder::SearchBuilder) was given a page size outside the allowed range. The
+    /// limit has to be between 1 and 50. The given limit is included.
+    #[error("Search limit has to be between 1 and 50, {0} given")]
+    InvalidSearchLimit(u32),
+
+    /// A [search](crate::client::request_builder::SearchBuilder) was given an offset outside the allowed range. The
+    /// offset has to be at most 1000. The given offset is included.
+    #[error("Search offset has to be at most 1000, {0} given")]
+    InvalidSearchOffset(u32),
+
+    /// A [play request](crate::client::request_builder::PlayItemsRequestBuilder) was given too many items to play.
+    /// Spotify accepts at most 100 items in a single play request. The given number of items is included.
+    #[error("Playing items accepts at most 100 items, {0} given")]
+    InvalidPlayItemsCount(usize),
+
+    /// A [context play request](crate::client::request_builder::PlayContextRequestBuilder) was given an offset, but
+    /// the context doesn't support one. Artist contexts can only be played from their start. The context URI is
+    /// included.
+    #[error("Playing the context {0} doesn't support an offset")]
+    UnsupportedPlayOffset(String),
+
+    /// A [playlist cover upload](crate::client::request_builder::PlaylistCoverRequestBuilder) was given an image that
+    /// is too large. Spotify accepts base64-encoded images of at most 256 KB. The size of the encoded image in bytes
+    /// is included.
+    #[error("Playlist cover images have to be at most 256 KB when base64-encoded, {0} bytes given")]
+    PlaylistCoverTooLarge(usize),
+
     /// Spotify returned a 429 Too Many Requests, but the Retry-After header could not be parsed as an integer. This is
     /// likely an issue on Spotify's side.
     #[error("Missing or invalid Retry-After header in 429 rate-limit response")]
@@ -126,10 +197,21 @@ pub enum Error {
     #[error("Unhandled Spotify API response status code {0}")]
     UnhandledSpotifyResponseStatusCode(u16),
 
+    /// Spotify returned an error response with a body that isn't JSON, such as an HTML error page returned during
+    /// outages or maintenance. The response's status code and content type are included.
+    #[error("Spotify returned a non-JSON error response with status code {status} and content type {content_type}")]
+    NonJsonResponse { status: u16, content_type: String },
+
     /// Spotify returned an unexpected empty response (HTTP 204 No Content)
     #[error("Spotify returned an unexpected empty response (HTTP 204 No Content)")]
     EmptyResponse,
 
+    /// A response from Spotify contained fields the library's model doesn't cover. Only returned by clients in
+    /// [strict deserialization mode](crate::client::deserialization::DeserializationMode::Strict). Contains the paths
+    /// to the unknown fields in the response.
+    #[error("Spotify's response contained unknown fields: {}", .0.join(", "))]
+    UnexpectedResponseFields(Vec<String>),
+
     /// Parsing a string to a Spotify [ID](crate::model::id::Id) failed.
     #[error(transparent)]
     InvalidSpotifyId(#[from] IdError),
@@ -141,10 +223,20 @@ pub enum Error {
     #[error(transparent)]
     Conversion(#[from] ConversionError),
 
+    /// A request to Spotify didn't complete within the timeout set in the client builder. The request may be retried.
+    #[error("The request timed out: {0}")]
+    Timeout(#[source] reqwest::Error),
+
     /// A catch-all for errors from reqwest. Getting this error back likely means something went wrong with sending a
     /// request or receiving and decoding a response.
     #[error(transparent)]
-    HttpError(#[from] reqwest::Error),
+    HttpError(reqwest::Error),
+
+    /// Deserializing a response from Spotify failed. Only returned by clients in [strict deserialization
+    /// mode](crate::client::deserialization::DeserializationMode::Strict); otherwise deserialization errors are
+    /// returned as [HttpError](Self::HttpError).
+    #[error(transparent)]
+    ResponseDeserialization(#[from] serde_json::Error),
 }
 
 /// Error type for parsing a Spotify [ID](crate::model::id::Id).
@@ -170,15 +262,57 @@ pub enum IdError {
     MalformedString(String),
 }
 
-/// Error when converting serialized objects into model objects fails.
+/// Error type for parsing a [Scope](crate::scope::Scope) from a string. The unknown scope string is included.
+#[derive(Debug, Error)]
+#[error("Unknown scope: {0}")]
+pub struct ScopeParseError(pub String);
+
+/// Error when converting serialized objects into model objects fails. If the conversion failed due to another error,
+/// such as converting one of the object's items failing, that error is available through
+/// [source](std::error::Error::source).
 #[derive(Debug)]
 #[non_exhaustive]
-pub struct ConversionError(pub(crate) Cow<'static, str>);
+pub struct ConversionError {
+    message: Cow<'static, str>,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl ConversionError {
+    pub(crate) fn new(message: Cow<'static, str>) -> Self {
+        Self { message, source: None }
+    }
+
+    pub(crate) fn with_source<E>(message: Cow<'static, str>, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Self {
+            message,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|source| source as _)
+    }
+}
 
-impl std::error::Error for ConversionError {}
 impl std::fmt::Display for ConversionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "object conversion failed: {}", self.0)
+        write!(f, "object conversion failed: {}", self.message)
+    }
+}
+
+#[cfg(any(feature = "async", feature = "sync"))]
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Error::Timeout(err)
+        } else {
+            Error::HttpError(err)
+        }
     }
 }
 
@@ -13,8 +13,8 @@
 //! - Every OAuth authorization flow Spotify supports is implemented.
 //! - Supports multiple simultaneous user clients.
 //! - Automatically refreshes access tokens when they expire, where applicable.
-//! - Reacts to API rate limits using either Tokio's or async-std's sleep functions at your discretion when using an
-//!   asynchronous client. Synchronous clients block the running thread.
+//! - Reacts to API rate limits using either Tokio's, async-std's or smol's sleep functions at your discretion when using
+//!   an asynchronous client. Synchronous clients block the running thread.
 //!
 //! # Usage
 //!
@@ -28,10 +28,15 @@
 //!     structure with minimal dependencies on `serde` and `thiserror`.
 //! - `tokio_sleep` (default): react to API rate limits using Tokio's sleep function.
 //! - `async_std_sleep`: react to API rate limits using async-std's sleep function.
-//!   - In case both `tokio_sleep` and `async_std_sleep` are enabled, Tokio's sleep function will be used.
-//!   - In case neither are enabled, the library will return a [rate limit error](crate::error::Error::RateLimit) when
+//! - `smol_sleep`: react to API rate limits using smol's timer.
+//!   - In case multiple sleep features are enabled, the sleep function is picked in the order: Tokio, async-std, smol.
+//!   - In case none are enabled, the library will return a [rate limit error](crate::error::Error::RateLimit) when
 //!     it occurs.
 //!   - These features are meaningless unless the `async` feature is also enabled.
+//! - `lyrics`: enable fetching time-synced track lyrics from an unofficial Spotify endpoint. The endpoint is
+//!   undocumented and may break at any time.
+//! - `redirect-server`: enable finalizing an authorization code client with a local server that receives Spotify's
+//!   redirect callback.
 
 #[cfg(any(feature = "async", feature = "sync"))]
 pub mod client;
@@ -42,6 +47,10 @@ pub mod scope;
 
 mod util;
 
+#[cfg(test)]
+#[path = "../tests/support/mod.rs"]
+mod test_support;
+
 pub(crate) mod private {
     pub trait Sealed {}
 }
@@ -53,20 +62,25 @@ pub mod prelude {
     //! kind), IDs etc. Their common functionality is grouped into traits. All such traits are re-exported here for
     //! convenience.
 
-    #[cfg(feature = "async")]
-    pub use crate::client::{request_builder::AsyncRequestBuilder, AccessTokenRefreshAsync};
     #[cfg(any(feature = "async", feature = "sync"))]
     pub use crate::client::{request_builder::BaseRequestBuilder, ScopedClient, UnscopedClient};
     #[cfg(feature = "sync")]
     pub use crate::client::{request_builder::SyncRequestBuilder, AccessTokenRefreshSync};
+    #[cfg(feature = "async")]
+    pub use crate::client::{
+        request_builder::{AsyncPageRequestBuilder, AsyncRequestBuilder},
+        AccessTokenRefreshAsync,
+    };
     pub use crate::{
         model::{
             album::{CommonAlbumInformation, FullAlbumInformation, NonLocalAlbumInformation},
             artist::{CommonArtistInformation, FullArtistInformation, NonLocalArtistInformation},
             id::{IdFromBare, IdFromKnownKind, IdTrait},
+            playlist::{CommonPlaylistInformation, FullPlaylistInformation},
             search::ToTypesString,
             track::{CommonTrackInformation, FullTrackInformation, NonLocalTrackInformation, RelinkedTrackEquality},
             user::{CommonUserInformation, CurrentUserInformation, PrivateUserInformation},
+            ImageExt,
         },
         scope::ToScopesString,
     };
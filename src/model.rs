@@ -5,21 +5,35 @@
 
 pub mod album;
 pub mod artist;
+pub mod audio;
+pub mod category;
+pub mod episode;
 pub mod error;
 pub mod id;
+pub mod library;
+#[cfg(feature = "lyrics")]
+pub mod lyrics;
 pub mod playback;
+pub mod playlist;
+pub mod recommendations;
 pub mod search;
+pub mod show;
+pub mod top;
 pub mod track;
 pub mod user;
 
 mod country_code;
 pub(crate) mod object_type;
-mod page;
+pub(crate) mod page;
+#[cfg(test)]
+pub(crate) mod test_responses;
 
 use std::{fmt, str::FromStr};
 
-pub use country_code::CountryCode;
-pub use page::Page;
+#[cfg(feature = "cache")]
+pub(crate) use country_code::FROM_TOKEN_MARKET;
+pub use country_code::{CountryCode, Market};
+pub use page::{CursorPage, Page};
 use serde::{Deserialize, Serialize};
 
 use crate::error::IdError;
@@ -40,6 +54,68 @@ pub struct ImageDimensions {
     pub height: u32,
 }
 
+/// Functions for selecting an image from a list of images, such as the ones returned by an object's `images`-function.
+///
+/// Spotify doesn't always report the dimensions of an image. Images without dimensions are only selected if none of
+/// the images have dimensions.
+pub trait ImageExt: crate::private::Sealed {
+    /// Returns the largest image.
+    fn largest(&self) -> Option<&Image>;
+    /// Returns the smallest image.
+    fn smallest(&self) -> Option<&Image>;
+    /// Returns the image whose width is closest to the given width.
+    fn closest_to(&self, width: u32) -> Option<&Image>;
+}
+
+impl Image {
+    /// Returns the image's width, if known.
+    pub fn width(&self) -> Option<u32> {
+        self.dimensions.as_ref().map(|dimensions| dimensions.width)
+    }
+
+    /// Returns the image's height, if known.
+    pub fn height(&self) -> Option<u32> {
+        self.dimensions.as_ref().map(|dimensions| dimensions.height)
+    }
+}
+
+impl ImageDimensions {
+    /// Returns the image's area in pixels.
+    pub fn area(&self) -> u64 {
+        u64::from(self.width) * u64::from(self.height)
+    }
+}
+
+impl crate::private::Sealed for [Image] {}
+
+impl ImageExt for [Image] {
+    fn largest(&self) -> Option<&Image> {
+        select_image(self, |dimensions| std::cmp::Reverse(dimensions.area()))
+    }
+
+    fn smallest(&self) -> Option<&Image> {
+        select_image(self, ImageDimensions::area)
+    }
+
+    fn closest_to(&self, width: u32) -> Option<&Image> {
+        select_image(self, |dimensions| dimensions.width.abs_diff(width))
+    }
+}
+
+/// Returns the image with dimensions that has the smallest key, or the first image if none of them have dimensions.
+fn select_image<K, F>(images: &[Image], key: F) -> Option<&Image>
+where
+    K: Ord,
+    F: Fn(&ImageDimensions) -> K,
+{
+    images
+        .iter()
+        .filter_map(|image| image.dimensions.as_ref().map(|dimensions| (key(dimensions), image)))
+        .min_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, image)| image)
+        .or_else(|| images.first())
+}
+
 /// A content restriction.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Restrictions {
@@ -57,6 +133,80 @@ pub enum DatePrecision {
     Day,
 }
 
+/// A release date with a varying precision. The month and the day are only available if the date's precision
+/// includes them.
+///
+/// Some very old albums are reported with the year `0000`, which is represented as the year 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReleaseDate {
+    /// The release year.
+    pub year: i32,
+    /// The release month, from 1 to 12, if known.
+    pub month: Option<u8>,
+    /// The release day of the month, from 1 to 31, if known.
+    pub day: Option<u8>,
+}
+
+impl ReleaseDate {
+    /// Parses a release date from a string in the `YYYY`, `YYYY-MM` or `YYYY-MM-DD` format according to the given
+    /// precision. Components beyond the precision are ignored and components that are missing, zero or otherwise
+    /// unparseable are left empty. An unparseable year is treated as the year 0.
+    pub fn parse(date: &str, precision: DatePrecision) -> Self {
+        let mut components = date.splitn(3, '-');
+
+        let year = components.next().and_then(|year| year.parse().ok()).unwrap_or(0);
+        let mut month = components
+            .next()
+            .and_then(|month| month.parse().ok())
+            .filter(|&month| month > 0);
+        let mut day = components
+            .next()
+            .and_then(|day| day.parse().ok())
+            .filter(|&day| day > 0);
+
+        match precision {
+            DatePrecision::Year => {
+                month = None;
+                day = None;
+            }
+
+            DatePrecision::Month => day = None,
+            DatePrecision::Day => {}
+        }
+
+        if month.is_none() {
+            day = None;
+        }
+
+        Self { year, month, day }
+    }
+
+    /// Returns the date's precision based on which components are available.
+    pub fn precision(&self) -> DatePrecision {
+        match (self.month, self.day) {
+            (Some(_), Some(_)) => DatePrecision::Day,
+            (Some(_), None) => DatePrecision::Month,
+            _ => DatePrecision::Year,
+        }
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+
+        if let Some(month) = self.month {
+            write!(f, "-{month:02}")?;
+
+            if let Some(day) = self.day {
+                write!(f, "-{day:02}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Known external URLs for an object.
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExternalUrls {
@@ -80,6 +230,7 @@ pub struct ExternalIds {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Copyright {
     pub text: String,
+    #[serde(rename = "type")]
     pub copyright_type: CopyrightType,
 }
 
@@ -141,3 +292,245 @@ impl FromStr for ItemType {
         }
     }
 }
+
+/// An object of any type in the Spotify catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SpotifyObject {
+    /// A track.
+    Track(track::FullTrack),
+    /// An album.
+    Album(album::FullAlbum),
+    /// An artist.
+    Artist(artist::FullArtist),
+    /// A playlist.
+    Playlist(playlist::FullPlaylist),
+    /// A podcast show.
+    Show(show::PartialShow),
+    /// A podcast episode.
+    Episode(episode::FullEpisode),
+    /// A Spotify user.
+    User(user::PublicUser),
+}
+
+impl SpotifyObject {
+    /// Returns the object's item type.
+    pub fn item_type(&self) -> ItemType {
+        match self {
+            SpotifyObject::Track(_) => ItemType::Track,
+            SpotifyObject::Album(_) => ItemType::Album,
+            SpotifyObject::Artist(_) => ItemType::Artist,
+            SpotifyObject::Playlist(_) => ItemType::Playlist,
+            SpotifyObject::Show(_) => ItemType::Show,
+            SpotifyObject::Episode(_) => ItemType::Episode,
+            SpotifyObject::User(_) => ItemType::User,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::Value;
+
+    use super::{
+        album::FullAlbum,
+        test_responses::{FULL_ALBUM_RESPONSE, FULL_TRACK_RESPONSE, PRIVATE_USER_RESPONSE, RELINKED_TRACK_RESPONSE},
+        track::FullTrack,
+        user::PrivateUser,
+        DatePrecision, Image, ImageExt, ReleaseDate,
+    };
+    use crate::client::deserialization::object_fields;
+
+    /// Collects the paths to every field in the given JSON value, along with the fields strict deserialization allows
+    /// to be [omitted](crate::client::deserialization::ObjectFields::omitted) from the object the field is in. Array
+    /// elements are collapsed into a single `[]` path segment.
+    fn field_paths(
+        value: &Value,
+        field: Option<&str>,
+        path: &str,
+        paths: &mut BTreeMap<String, &'static [&'static str]>,
+    ) {
+        match value {
+            Value::Object(fields) => {
+                let omitted = object_fields(field, fields).map_or(&[][..], |fields| fields.omitted);
+
+                for (key, value) in fields {
+                    let path = if path.is_empty() {
+                        key.to_owned()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+
+                    paths.insert(path.clone(), omitted);
+                    field_paths(value, Some(key), &path, paths);
+                }
+            }
+
+            Value::Array(items) => {
+                for item in items {
+                    field_paths(item, field, &format!("{path}[]"), paths);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Deserializes the given API response into a model object, serializes the object back and returns the paths to
+    /// the fields that were in the response but not in the serialized object. Every stripped field has to be one of the
+    /// fields strict deserialization allows to be omitted from the object the field is in.
+    fn stripped_fields<T>(response: &str) -> Vec<String>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let response: Value = serde_json::from_str(response).unwrap();
+        let object: T = serde_json::from_value(response.clone()).unwrap();
+        let serialized = serde_json::to_value(object).unwrap();
+
+        let mut response_paths = BTreeMap::new();
+        let mut serialized_paths = BTreeMap::new();
+        field_paths(&response, None, "", &mut response_paths);
+        field_paths(&serialized, None, "", &mut serialized_paths);
+
+        let mut stripped = Vec::new();
+        for (path, omitted) in response_paths {
+            if !serialized_paths.contains_key(&path) {
+                let field = path.rsplit(['.', ']']).next().unwrap_or_default();
+                assert!(
+                    omitted.contains(&field),
+                    "{path} is stripped but not omitted from its object"
+                );
+                stripped.push(path);
+            }
+        }
+
+        stripped
+    }
+
+    // the API links and URIs of objects are redundant with their IDs, so every object strips them
+
+    #[test]
+    fn full_track_stripped_fields() {
+        assert_eq!(
+            stripped_fields::<FullTrack>(FULL_TRACK_RESPONSE),
+            [
+                "album.artists[].href",
+                "album.artists[].uri",
+                "album.href",
+                "album.total_tracks",
+                "album.uri",
+                "artists[].href",
+                "artists[].uri",
+                "href",
+                "uri",
+            ]
+        );
+    }
+
+    #[test]
+    fn full_album_stripped_fields() {
+        assert_eq!(
+            stripped_fields::<FullAlbum>(FULL_ALBUM_RESPONSE),
+            [
+                "artists[].href",
+                "artists[].uri",
+                "href",
+                "total_tracks",
+                "tracks.href",
+                "tracks.items[].artists[].href",
+                "tracks.items[].artists[].uri",
+                "tracks.items[].href",
+                "tracks.items[].uri",
+                "uri",
+            ]
+        );
+    }
+
+    #[test]
+    fn private_user_stripped_fields() {
+        assert_eq!(
+            stripped_fields::<PrivateUser>(PRIVATE_USER_RESPONSE),
+            ["followers.href", "href", "uri"]
+        );
+    }
+
+    #[test]
+    fn serialized_objects_keep_bare_ids() {
+        let response: Value = serde_json::from_str(RELINKED_TRACK_RESPONSE).unwrap();
+        let track: FullTrack = serde_json::from_value(response.clone()).unwrap();
+        let serialized = serde_json::to_value(&track).unwrap();
+
+        for pointer in [
+            "/id",
+            "/linked_from/id",
+            "/album/id",
+            "/album/artists/0/id",
+            "/artists/0/id",
+        ] {
+            assert!(response.pointer(pointer).is_some(), "{pointer}");
+            assert_eq!(serialized.pointer(pointer), response.pointer(pointer), "{pointer}");
+        }
+
+        assert_eq!(serde_json::from_value::<FullTrack>(serialized).unwrap(), track);
+    }
+
+    #[test]
+    fn images_selected_by_dimensions() {
+        let images: Vec<Image> = serde_json::from_str(
+            r#"[
+                { "url": "unknown", "width": null, "height": null },
+                { "url": "medium", "width": 300, "height": 300 },
+                { "url": "large", "width": 640, "height": 640 },
+                { "url": "small", "width": 64, "height": 64 }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(images[0].width(), None);
+        assert_eq!(images.largest().map(|image| image.url.as_str()), Some("large"));
+        assert_eq!(images.smallest().map(|image| image.url.as_str()), Some("small"));
+        assert_eq!(images.closest_to(200).map(|image| image.url.as_str()), Some("medium"));
+    }
+
+    #[test]
+    fn images_without_dimensions_selected_last() {
+        let images: Vec<Image> =
+            serde_json::from_str(r#"[{ "url": "unknown", "width": null, "height": null }]"#).unwrap();
+
+        assert_eq!(images.largest().map(|image| image.url.as_str()), Some("unknown"));
+        assert_eq!(images.closest_to(300).map(|image| image.url.as_str()), Some("unknown"));
+        assert!(Vec::<Image>::new().smallest().is_none());
+    }
+
+    #[test]
+    fn release_date_parsed_according_to_precision() {
+        let parse = ReleaseDate::parse;
+        let date = |year, month, day| ReleaseDate { year, month, day };
+
+        assert_eq!(parse("2021", DatePrecision::Year), date(2021, None, None));
+        assert_eq!(parse("2021-05", DatePrecision::Month), date(2021, Some(5), None));
+        assert_eq!(parse("2021-05-21", DatePrecision::Day), date(2021, Some(5), Some(21)));
+        assert_eq!(parse("2021-05-21", DatePrecision::Year), date(2021, None, None));
+        assert_eq!(parse("2021", DatePrecision::Day), date(2021, None, None));
+    }
+
+    #[test]
+    fn release_date_with_zero_year() {
+        let release_date = ReleaseDate::parse("0000", DatePrecision::Year);
+
+        assert_eq!(release_date.year, 0);
+        assert_eq!(release_date.precision(), DatePrecision::Year);
+        assert_eq!(release_date.to_string(), "0000");
+        assert_eq!(
+            ReleaseDate::parse("0000-00-00", DatePrecision::Day),
+            ReleaseDate {
+                year: 0,
+                month: None,
+                day: None
+            }
+        );
+    }
+}
@@ -2,8 +2,8 @@
 //!
 //! Contains the three different kinds of albums; [FullAlbum], [PartialAlbum] and [LocalAlbum].
 //!
-//! - [FullAlbum]: may contain all possible information about an album. Generally retrieved from the album- and
-//!   albums-endpoints (TODO: make links once implemented)
+//! - [FullAlbum]: may contain all possible information about an album. Generally retrieved from the
+//!   [album-](crate::client::UnscopedClient::album) and [albums-functions](crate::client::UnscopedClient::albums).
 //! - [PartialAlbum]: contains most information about an album. Generally retrieved as part of a response to, for
 //!   example, an artist listing (TODO: make a link to the artist endpoint once it exists).
 //! - [LocalAlbum]: contains only the basic information about an album. Only retrieved through a playlist that contains
@@ -94,8 +94,9 @@ mod private {
     #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
     pub(crate) struct NonLocalAlbumFields {
         pub(crate) album_type: AlbumType,
+        #[serde(with = "crate::model::id::bare")]
         pub(crate) id: Id<'static, AlbumId>,
-        pub(crate) release_date: String, // TODO: proper date type pls
+        pub(crate) release_date: String,
         pub(crate) release_date_precision: DatePrecision,
     }
 }
@@ -111,7 +112,7 @@ use super::{
     id::{AlbumId, Id, IdTrait},
     page::{Page, PageInformation, PageObject},
     track::{PartialTrack, TrackObject},
-    Copyright, DatePrecision, ExternalIds, ExternalUrls, Image, Restrictions,
+    Copyright, DatePrecision, ExternalIds, ExternalUrls, Image, ReleaseDate, Restrictions,
 };
 use crate::error::ConversionError;
 
@@ -155,8 +156,10 @@ pub trait NonLocalAlbumInformation: crate::private::Sealed {
     fn album_type(&self) -> AlbumType;
     /// The album's Spotify ID.
     fn id(&self) -> Id<'_, AlbumId>;
-    /// The album's release date.
-    fn release_date(&self) -> &str;
+    /// The album's release date, parsed according to its precision.
+    fn release_date(&self) -> ReleaseDate;
+    /// The album's release date as it was returned by Spotify.
+    fn release_date_str(&self) -> &str;
     /// The album's release date's precision.
     fn release_date_precision(&self) -> DatePrecision;
 }
@@ -234,7 +237,12 @@ where
         self.non_local_fields().id.as_borrowed()
     }
 
-    fn release_date(&self) -> &str {
+    fn release_date(&self) -> ReleaseDate {
+        let fields = self.non_local_fields();
+        ReleaseDate::parse(&fields.release_date, fields.release_date_precision)
+    }
+
+    fn release_date_str(&self) -> &str {
         &self.non_local_fields().release_date
     }
 
@@ -273,6 +281,20 @@ struct AlbumObjectRef<'a> {
 pub struct AlbumTracks {
     #[serde(flatten)]
     page: PageObject<TrackObject>,
+
+    // the market the album was requested in, if any, so the following pages are requested in the same market
+    #[serde(skip)]
+    market: Option<String>,
+}
+
+/// A page of new album releases.
+///
+/// This object is retrieved only through the [new_releases](crate::client::UnscopedClient::new_releases)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct NewReleases {
+    albums: PageObject<AlbumObject>,
 }
 
 /// A full album. Contains [full information](self::FullAlbumInformation), in addition to all
@@ -314,6 +336,47 @@ pub enum AlbumType {
     Compilation,
 }
 
+/// Remove duplicate albums from a list of albums, keeping the first occurrence of each album.
+///
+/// Spotify's catalog commonly contains the same album multiple times with different IDs, for example when the album has
+/// been released separately for different markets. Such albums are considered duplicates if their names, compared
+/// case-insensitively and ignoring surrounding whitespace, and their release dates are the same.
+pub fn dedup_albums(albums: Vec<PartialAlbum>) -> Vec<PartialAlbum> {
+    let mut seen = HashSet::new();
+
+    albums
+        .into_iter()
+        .filter(|album| seen.insert((album.name().trim().to_lowercase(), album.release_date_str().to_owned())))
+        .collect()
+}
+
+/// Returns whether the given URL has the market query parameter.
+fn has_market_query(url: &str) -> bool {
+    url.split_once('?')
+        .is_some_and(|(_, query)| query.split('&').any(|param| param.starts_with("market=")))
+}
+
+/// Adds the given market to a page URL as the market query parameter, if it isn't there already.
+fn with_market_query(url: String, market: Option<String>) -> String {
+    match market {
+        // Spotify usually includes the market in the page URLs but make sure it's there
+        Some(market) if !has_market_query(&url) => {
+            let separator = if url.contains('?') { '&' } else { '?' };
+            format!("{url}{separator}market={market}")
+        }
+
+        _ => url,
+    }
+}
+
+impl FullAlbum {
+    /// Set the market the album was requested in, so the following pages of its tracks are requested in the same
+    /// market.
+    pub(crate) fn set_tracks_market(&mut self, market: &str) {
+        self.full.tracks.market = Some(market.to_owned());
+    }
+}
+
 impl PartialEq for FullAlbum {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id()
@@ -380,7 +443,7 @@ impl TryFrom<AlbumObject> for Album {
 
             (None, None) => Ok(Self::Local(Box::new(LocalAlbum { common: obj.common }))),
 
-            (non_local, full) => Err(ConversionError(
+            (non_local, full) => Err(ConversionError::new(
                 format!(
                     "impossible case trying to convert AlbumObject into Album: non-local album fields is \
                      {non_local:?} while full album fields is {full:?}"
@@ -416,11 +479,13 @@ impl TryFrom<Album> for FullAlbum {
         match album {
             Album::Full(full) => Ok(*full),
 
-            Album::Partial(_) => Err(ConversionError(
+            Album::Partial(_) => Err(ConversionError::new(
                 "attempt to convert partial album into full album".into(),
             )),
 
-            Album::Local(_) => Err(ConversionError("attempt to convert local album into full album".into())),
+            Album::Local(_) => Err(ConversionError::new(
+                "attempt to convert local album into full album".into(),
+            )),
         }
     }
 }
@@ -436,7 +501,7 @@ impl TryFrom<AlbumObject> for FullAlbum {
                 full,
             }),
 
-            (non_local, full) => Err(ConversionError(
+            (non_local, full) => Err(ConversionError::new(
                 format!(
                     "attempt to convert non-full album object into full album (non-local album fields is \
                      {non_local:?}, full album fields is {full:?})"
@@ -459,7 +524,7 @@ impl TryFrom<Album> for PartialAlbum {
 
             Album::Partial(partial) => Ok(*partial),
 
-            Album::Local(_) => Err(ConversionError(
+            Album::Local(_) => Err(ConversionError::new(
                 "attempt to convert local album into partial album".into(),
             )),
         }
@@ -476,7 +541,7 @@ impl TryFrom<AlbumObject> for PartialAlbum {
                 non_local,
             })
         } else {
-            Err(ConversionError(
+            Err(ConversionError::new(
                 format!(
                     "attempt to convert local album object into partial album (non-local album fields is {:?})",
                     obj.non_local
@@ -537,6 +602,7 @@ impl crate::private::Sealed for FullAlbum {}
 impl crate::private::Sealed for PartialAlbum {}
 impl crate::private::Sealed for LocalAlbum {}
 impl crate::private::Sealed for AlbumTracks {}
+impl crate::private::Sealed for NewReleases {}
 
 impl private::CommonFields for FullAlbum {
     fn common_fields(&self) -> &CommonAlbumFields {
@@ -586,7 +652,66 @@ impl PageInformation<PartialTrack> for AlbumTracks {
     }
 
     fn next(self) -> Option<String> {
-        <PageObject<TrackObject> as PageInformation<PartialTrack>>::next(self.page)
+        let next = <PageObject<TrackObject> as PageInformation<PartialTrack>>::next(self.page)?;
+        Some(with_market_query(next, self.market))
+    }
+
+    fn previous(self) -> Option<String> {
+        let previous = <PageObject<TrackObject> as PageInformation<PartialTrack>>::previous(self.page)?;
+        Some(with_market_query(previous, self.market))
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<PartialTrack>>::total(&self.page)
+    }
+}
+
+impl From<NewReleases> for Page<NewReleases, PartialAlbum> {
+    fn from(releases: NewReleases) -> Self {
+        Page {
+            inner: releases,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl PageInformation<PartialAlbum> for NewReleases {
+    type Items = Vec<PartialAlbum>;
+
+    fn items(&self) -> Self::Items {
+        self.albums.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.albums.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::next(self.albums)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::previous(self.albums)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::offset(&self.albums)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::limit(&self.albums)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<PartialAlbum>>::total(&self.albums)
     }
 }
 
@@ -647,3 +772,200 @@ impl Serialize for LocalAlbum {
 
 // TODO: unit tests for all the various functions here. deserializing, serializing, equality between tracks, conversion
 // between tracks
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{test_responses::ARTIST_ALBUMS_RESPONSE, track::CommonTrackInformation};
+
+    #[test]
+    fn deserialize_full_album() {
+        let json = r#"{
+            "album_type": "album",
+            "artists": [
+                {
+                    "external_urls": {
+                        "spotify": "https://open.spotify.com/artist/0TnOYISbd1XYRBk9myaseg"
+                    },
+                    "href": "https://api.spotify.com/v1/artists/0TnOYISbd1XYRBk9myaseg",
+                    "id": "0TnOYISbd1XYRBk9myaseg",
+                    "name": "Test artist",
+                    "type": "artist",
+                    "uri": "spotify:artist:0TnOYISbd1XYRBk9myaseg"
+                }
+            ],
+            "copyrights": [
+                {
+                    "text": "(C) 2021 Test",
+                    "type": "C"
+                },
+                {
+                    "text": "(P) 2021 Test",
+                    "type": "P"
+                }
+            ],
+            "external_ids": {
+                "upc": "123456789012"
+            },
+            "external_urls": {
+                "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "genres": [],
+            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+            "images": [
+                {
+                    "height": 640,
+                    "url": "https://i.scdn.co/image/ab67616d0000b273",
+                    "width": 640
+                }
+            ],
+            "label": "Test label",
+            "name": "Test album",
+            "popularity": 50,
+            "release_date": "2021-05-21",
+            "release_date_precision": "day",
+            "total_tracks": 1,
+            "tracks": {
+                "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=0&limit=50",
+                "items": [
+                    {
+                        "artists": [],
+                        "disc_number": 1,
+                        "duration_ms": 180000,
+                        "explicit": false,
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu"
+                        },
+                        "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+                        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                        "is_local": false,
+                        "name": "Test track",
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track",
+                        "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+                    }
+                ],
+                "limit": 50,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 1
+            },
+            "type": "album",
+            "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+        }"#;
+
+        let album: FullAlbum = serde_json::from_str(json).unwrap();
+
+        assert_eq!(album.name(), "Test album");
+        assert_eq!(album.id().as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+        assert_eq!(album.label(), "Test label");
+        assert_eq!(album.copyrights().len(), 2);
+        assert_eq!(album.release_date_str(), "2021-05-21");
+        assert_eq!(
+            album.release_date(),
+            ReleaseDate {
+                year: 2021,
+                month: Some(5),
+                day: Some(21)
+            }
+        );
+
+        let tracks = album.tracks().take_items();
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].name(), "Test track");
+    }
+
+    #[test]
+    fn dedup_albums_by_name_and_release_date() {
+        let albums: PageObject<PartialAlbum> = serde_json::from_str(ARTIST_ALBUMS_RESPONSE).unwrap();
+
+        let deduped = dedup_albums(albums.items);
+        let ids: Vec<_> = deduped.iter().map(|album| album.id().as_str().to_owned()).collect();
+
+        assert_eq!(
+            ids,
+            [
+                "0tDsHtvN9YNuZjlqHvDY2P",
+                "2tDsHtvN9YNuZjlqHvDY2P",
+                "3tDsHtvN9YNuZjlqHvDY2P"
+            ]
+        );
+    }
+
+    fn album_tracks(next: Option<&str>, market: Option<&str>) -> AlbumTracks {
+        let mut tracks: AlbumTracks = serde_json::from_value(serde_json::json!({
+            "items": [],
+            "limit": 50,
+            "next": next,
+            "offset": 0,
+            "total": 100
+        }))
+        .unwrap();
+
+        tracks.market = market.map(str::to_owned);
+        tracks
+    }
+
+    #[test]
+    fn album_tracks_next_page_keeps_market() {
+        let tracks = album_tracks(
+            Some("https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=50&limit=50"),
+            Some("FI"),
+        );
+
+        assert_eq!(
+            tracks.next().as_deref(),
+            Some("https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=50&limit=50&market=FI")
+        );
+    }
+
+    #[test]
+    fn album_tracks_next_page_market_is_not_duplicated() {
+        let next = "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=50&limit=50&market=FI";
+
+        assert_eq!(album_tracks(Some(next), Some("FI")).next().as_deref(), Some(next));
+        assert_eq!(album_tracks(None, Some("FI")).next(), None);
+    }
+
+    #[test]
+    fn deserialize_new_releases() {
+        let releases: NewReleases = serde_json::from_value(serde_json::json!({
+            "albums": {
+                "href": "https://api.spotify.com/v1/browse/new-releases?offset=0&limit=1",
+                "items": [{
+                    "album_type": "single",
+                    "artists": [],
+                    "available_markets": ["FI"],
+                    "external_urls": {},
+                    "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                    "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                    "images": [],
+                    "name": "New single",
+                    "release_date": "2024-03-01",
+                    "release_date_precision": "day",
+                    "total_tracks": 1,
+                    "type": "album",
+                    "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+                }],
+                "limit": 1,
+                "next": "https://api.spotify.com/v1/browse/new-releases?offset=1&limit=1",
+                "offset": 0,
+                "previous": null,
+                "total": 100
+            }
+        }))
+        .unwrap();
+
+        let page: Page<NewReleases, PartialAlbum> = releases.into();
+
+        assert_eq!(page.total(), 100);
+        assert_eq!(page.items()[0].name(), "New single");
+        assert_eq!(
+            page.inner.next().as_deref(),
+            Some("https://api.spotify.com/v1/browse/new-releases?offset=1&limit=1")
+        );
+    }
+}
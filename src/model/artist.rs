@@ -2,8 +2,8 @@
 //!
 //! Contains the three different kinds of artists; [FullArtist], [PartialArtist] and [LocalArtist].
 //!
-//! - [FullArtist]: may contain all possible information about an artist. Generally retrieved from the artist- and
-//!   artists-endpoints (TODO: make links once implemented)
+//! - [FullArtist]: may contain all possible information about an artist. Generally retrieved from the
+//!   [artist-](crate::client::UnscopedClient::artist) and [artists-functions](crate::client::UnscopedClient::artists).
 //! - [PartialArtist]: contains most information about an artist. Generally retrieved as part of a response to, for
 //!   example, a [track listing](crate::client::UnscopedClient::track).
 //! - [LocalArtist]: contains only the basic information about an artist. Only retrieved through a playlist that
@@ -26,6 +26,7 @@ mod private {
     use crate::model::{
         id::{ArtistId, Id},
         object_type::{object_type_serialize, TypeArtist},
+        user::Followers,
         ExternalUrls, Image,
     };
 
@@ -69,7 +70,9 @@ mod private {
 
     #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
     pub(crate) struct FullArtistFields {
-        // followers: Followers,
+        // artists embedded in other objects may have their followers missing or null
+        #[serde(default, deserialize_with = "crate::util::null_as_default::deserialize")]
+        pub(crate) followers: Followers,
         pub(crate) genres: Vec<String>,
         pub(crate) images: Vec<Image>,
         pub(crate) popularity: u32,
@@ -77,6 +80,7 @@ mod private {
 
     #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
     pub(crate) struct NonLocalArtistFields {
+        #[serde(with = "crate::model::id::bare")]
         pub(crate) id: Id<'static, ArtistId>,
     }
 }
@@ -86,6 +90,7 @@ use serde::{Deserialize, Serialize, Serializer};
 pub(crate) use self::private::{ArtistObject, CommonArtistFields, FullArtistFields, NonLocalArtistFields};
 use super::{
     id::{ArtistId, Id, IdTrait},
+    user::Followers,
     ExternalUrls, Image,
 };
 use crate::error::ConversionError;
@@ -100,6 +105,8 @@ pub trait CommonArtistInformation: crate::private::Sealed {
 
 /// Functions for retrieving information only in full artists.
 pub trait FullArtistInformation: crate::private::Sealed {
+    /// Information about the artist's followers.
+    fn followers(&self) -> Followers;
     /// Genres the artist is associated with.
     fn genres(&self) -> &[String];
     /// Images for the artist.
@@ -131,6 +138,10 @@ impl<T> FullArtistInformation for T
 where
     T: private::FullFields + crate::private::Sealed,
 {
+    fn followers(&self) -> Followers {
+        self.full_fields().followers
+    }
+
     fn genres(&self) -> &[String] {
         &self.full_fields().genres
     }
@@ -155,6 +166,7 @@ where
 
 /// An enum that encompasses all artist types.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "ArtistObject")]
 pub enum Artist {
     Full(Box<FullArtist>),
     Partial(Box<PartialArtist>),
@@ -268,7 +280,7 @@ impl TryFrom<ArtistObject> for Artist {
 
             (None, None) => Ok(Self::Local(Box::new(LocalArtist { common: obj.common }))),
 
-            (non_local, full) => Err(ConversionError(
+            (non_local, full) => Err(ConversionError::new(
                 format!(
                     "impossible case trying to convert ArtistObject into Artist: non-local artist fields is \
                      {non_local:?} while full artist fields is {full:?}"
@@ -304,11 +316,11 @@ impl TryFrom<Artist> for FullArtist {
         match artist {
             Artist::Full(full) => Ok(*full),
 
-            Artist::Partial(_) => Err(ConversionError(
+            Artist::Partial(_) => Err(ConversionError::new(
                 "attempt to convert partial artist into full artist".into(),
             )),
 
-            Artist::Local(_) => Err(ConversionError(
+            Artist::Local(_) => Err(ConversionError::new(
                 "attempt to convert local artist into full artist".into(),
             )),
         }
@@ -326,7 +338,7 @@ impl TryFrom<ArtistObject> for FullArtist {
                 full,
             }),
 
-            (non_local, full) => Err(ConversionError(
+            (non_local, full) => Err(ConversionError::new(
                 format!(
                     "attempt to convert non-full artist object into full artist (non-local artist fields is \
                      {non_local:?}, full artist fields is {full:?})"
@@ -349,7 +361,7 @@ impl TryFrom<Artist> for PartialArtist {
 
             Artist::Partial(partial) => Ok(*partial),
 
-            Artist::Local(_) => Err(ConversionError(
+            Artist::Local(_) => Err(ConversionError::new(
                 "attempt to convert local artist into partial artist".into(),
             )),
         }
@@ -366,7 +378,7 @@ impl TryFrom<ArtistObject> for PartialArtist {
                 non_local,
             })
         } else {
-            Err(ConversionError(
+            Err(ConversionError::new(
                 format!(
                     "attempt to convert local artist object into partial artist (non-local artist fields is {:?})",
                     obj.non_local
@@ -520,3 +532,147 @@ impl Serialize for LocalArtist {
 
 // TODO: unit tests for all the various functions here. deserializing, serializing, equality between tracks, conversion
 // between tracks
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL_ARTIST: &str = r#"{
+        "external_urls": {
+            "spotify": "https://open.spotify.com/artist/0TnOYISbd1XYRBk9myaseg"
+        },
+        "followers": {
+            "href": null,
+            "total": 1337
+        },
+        "genres": ["dance pop", "pop"],
+        "href": "https://api.spotify.com/v1/artists/0TnOYISbd1XYRBk9myaseg",
+        "id": "0TnOYISbd1XYRBk9myaseg",
+        "images": [
+            {
+                "height": 640,
+                "url": "https://i.scdn.co/image/ab6761610000e5eb",
+                "width": 640
+            }
+        ],
+        "name": "Test artist",
+        "popularity": 80,
+        "type": "artist",
+        "uri": "spotify:artist:0TnOYISbd1XYRBk9myaseg"
+    }"#;
+
+    const SIMPLIFIED_ARTIST: &str = r#"{
+        "external_urls": {
+            "spotify": "https://open.spotify.com/artist/0TnOYISbd1XYRBk9myaseg"
+        },
+        "href": "https://api.spotify.com/v1/artists/0TnOYISbd1XYRBk9myaseg",
+        "id": "0TnOYISbd1XYRBk9myaseg",
+        "name": "Test artist",
+        "type": "artist",
+        "uri": "spotify:artist:0TnOYISbd1XYRBk9myaseg"
+    }"#;
+
+    const LOCAL_ARTIST: &str = r#"{
+        "external_urls": {},
+        "href": null,
+        "id": null,
+        "name": "Test artist",
+        "type": "artist",
+        "uri": null
+    }"#;
+
+    #[test]
+    fn deserialize_full_artist() {
+        let artist: FullArtist = serde_json::from_str(FULL_ARTIST).unwrap();
+
+        assert_eq!(artist.name(), "Test artist");
+        assert_eq!(artist.id().as_str(), "0TnOYISbd1XYRBk9myaseg");
+        assert_eq!(artist.followers().total, 1337);
+        assert_eq!(artist.genres(), ["dance pop", "pop"]);
+        assert_eq!(artist.images().len(), 1);
+        assert_eq!(artist.popularity(), 80);
+    }
+
+    #[test]
+    fn deserialize_full_artist_without_followers() {
+        let mut json: serde_json::Value = serde_json::from_str(FULL_ARTIST).unwrap();
+        json.as_object_mut().unwrap().remove("followers");
+        let artist: FullArtist = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(artist.followers(), Followers::new(0));
+        assert_eq!(artist.popularity(), 80);
+
+        json["followers"] = serde_json::Value::Null;
+        let artist: FullArtist = serde_json::from_value(json).unwrap();
+
+        assert_eq!(artist.followers().total, 0);
+    }
+
+    #[test]
+    fn deserialize_partial_artist() {
+        let artist: Artist = serde_json::from_str::<ArtistObject>(SIMPLIFIED_ARTIST)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert!(matches!(artist, Artist::Partial(_)));
+    }
+
+    #[test]
+    fn full_artist_payload_deserializes_into_full_variant() {
+        let artist: Artist = serde_json::from_str(FULL_ARTIST).unwrap();
+
+        let Artist::Full(full) = artist else {
+            panic!("expected a full artist, got {artist:?}");
+        };
+
+        assert_eq!(full.genres(), ["dance pop", "pop"]);
+        assert_eq!(full.popularity(), 80);
+    }
+
+    #[test]
+    fn simplified_artist_payload_deserializes_into_partial_variant() {
+        let artist: Artist = serde_json::from_str(SIMPLIFIED_ARTIST).unwrap();
+
+        let Artist::Partial(partial) = artist else {
+            panic!("expected a partial artist, got {artist:?}");
+        };
+
+        assert_eq!(partial.name(), "Test artist");
+        assert_eq!(partial.id().as_str(), "0TnOYISbd1XYRBk9myaseg");
+    }
+
+    #[test]
+    fn local_artist_payload_deserializes_into_local_variant() {
+        let artist: Artist = serde_json::from_str(LOCAL_ARTIST).unwrap();
+        assert!(matches!(artist, Artist::Local(_)));
+    }
+
+    #[test]
+    fn simplified_artist_payload_is_not_full() {
+        assert!(serde_json::from_str::<FullArtist>(SIMPLIFIED_ARTIST).is_err());
+        assert!(serde_json::from_str::<PartialArtist>(SIMPLIFIED_ARTIST).is_ok());
+    }
+
+    #[test]
+    fn serialized_artists_keep_their_kind() {
+        let full: Artist = serde_json::from_str(FULL_ARTIST).unwrap();
+        let partial: Artist = serde_json::from_str(SIMPLIFIED_ARTIST).unwrap();
+
+        let full_json = serde_json::to_value(&full).unwrap();
+        assert_eq!(full_json["genres"], serde_json::json!(["dance pop", "pop"]));
+        assert_eq!(full_json["popularity"], 80);
+
+        let partial_json = serde_json::to_value(&partial).unwrap();
+        assert!(partial_json.get("genres").is_none());
+        assert!(partial_json.get("popularity").is_none());
+
+        assert!(matches!(
+            serde_json::from_value::<Artist>(full_json).unwrap(),
+            Artist::Full(_)
+        ));
+        assert!(matches!(
+            serde_json::from_value::<Artist>(partial_json).unwrap(),
+            Artist::Partial(_)
+        ));
+    }
+}
@@ -0,0 +1,419 @@
+//! Contains the [AudioFeatures]-object which describes the audio characteristics of a track, and the [AudioAnalysis]-
+//! object which describes a track's structure and musical content in detail.
+//!
+//! Audio features are retrieved with the [`track_audio_features`-function for a single
+//! track](crate::client::UnscopedClient::track_audio_features) and the [`tracks_audio_features`-function for multiple
+//! tracks](crate::client::UnscopedClient::tracks_audio_features). Audio analysis is retrieved with the
+//! [`track_audio_analysis`-function](crate::client::UnscopedClient::track_audio_analysis).
+
+use std::{borrow::Cow, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use super::id::{Id, IdTrait, TrackId};
+use crate::util::duration_millis;
+
+/// Audio features of a single track.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioFeatures {
+    #[serde(with = "crate::model::id::bare")]
+    id: Id<'static, TrackId>,
+    #[serde(rename = "duration_ms", with = "duration_millis")]
+    duration: Duration,
+
+    danceability: f32,
+    energy: f32,
+    key: i8,
+    loudness: f32,
+    mode: u8,
+    speechiness: f32,
+    acousticness: f32,
+    instrumentalness: f32,
+    liveness: f32,
+    valence: f32,
+    tempo: f32,
+    time_signature: u8,
+}
+
+/// A detailed low-level audio analysis of a single track, describing its rhythm, pitch and timbre.
+///
+/// All times in the analysis are in seconds from the start of the track.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioAnalysis {
+    bars: Vec<TimeInterval>,
+    beats: Vec<TimeInterval>,
+    tatums: Vec<TimeInterval>,
+    sections: Vec<AudioSection>,
+    segments: Vec<AudioSegment>,
+}
+
+/// A time interval in an [audio analysis](AudioAnalysis), such as a bar, a beat or a tatum.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeInterval {
+    start: f32,
+    duration: f32,
+    confidence: f32,
+}
+
+/// A section in an [audio analysis](AudioAnalysis). Sections are large variations in rhythm or timbre, such as a chorus
+/// or a verse.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AudioSection {
+    start: f32,
+    duration: f32,
+    confidence: f32,
+    loudness: f32,
+    tempo: f32,
+    tempo_confidence: f32,
+    key: i8,
+    key_confidence: f32,
+    mode: i8,
+    mode_confidence: f32,
+    time_signature: u8,
+    time_signature_confidence: f32,
+}
+
+/// A segment in an [audio analysis](AudioAnalysis). Segments are short sounds that are relatively uniform in timbre and
+/// harmony, such as a single note or a chord.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioSegment {
+    start: f32,
+    duration: f32,
+    confidence: f32,
+    loudness_start: f32,
+    loudness_max: f32,
+    loudness_max_time: f32,
+    #[serde(default)]
+    loudness_end: f32,
+    pitches: Vec<f32>,
+    timbre: Vec<f32>,
+}
+
+impl AudioFeatures {
+    /// The Spotify ID of the track these audio features describe.
+    pub fn id(&self) -> Id<'_, TrackId> {
+        self.id.as_borrowed()
+    }
+
+    /// The Spotify URI of the track these audio features describe.
+    pub fn uri(&self) -> Cow<'_, str> {
+        self.id.as_uri()
+    }
+
+    /// The track's duration.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// How suitable the track is for dancing, from 0.0 (least danceable) to 1.0 (most danceable).
+    pub fn danceability(&self) -> f32 {
+        self.danceability
+    }
+
+    /// A perceptual measure of the track's intensity and activity, from 0.0 to 1.0.
+    pub fn energy(&self) -> f32 {
+        self.energy
+    }
+
+    /// The key the track is in, using standard [pitch class notation](https://en.wikipedia.org/wiki/Pitch_class).
+    /// E.g. 0 = C, 1 = C♯/D♭, 2 = D and so on. -1 if no key was detected.
+    pub fn key(&self) -> i8 {
+        self.key
+    }
+
+    /// The overall loudness of the track in decibels, typically between -60 and 0.
+    pub fn loudness(&self) -> f32 {
+        self.loudness
+    }
+
+    /// The modality of the track. 1 is major and 0 is minor.
+    pub fn mode(&self) -> u8 {
+        self.mode
+    }
+
+    /// How much spoken words are present in the track, from 0.0 to 1.0.
+    pub fn speechiness(&self) -> f32 {
+        self.speechiness
+    }
+
+    /// A confidence measure of whether the track is acoustic, from 0.0 to 1.0.
+    pub fn acousticness(&self) -> f32 {
+        self.acousticness
+    }
+
+    /// A prediction of whether the track contains no vocals, from 0.0 to 1.0.
+    pub fn instrumentalness(&self) -> f32 {
+        self.instrumentalness
+    }
+
+    /// A prediction of whether the track was performed live, from 0.0 to 1.0.
+    pub fn liveness(&self) -> f32 {
+        self.liveness
+    }
+
+    /// The musical positiveness conveyed by the track, from 0.0 (negative) to 1.0 (positive).
+    pub fn valence(&self) -> f32 {
+        self.valence
+    }
+
+    /// The estimated tempo of the track in beats per minute.
+    pub fn tempo(&self) -> f32 {
+        self.tempo
+    }
+
+    /// The estimated time signature of the track, i.e. how many beats are in each bar. Ranges from 3 to 7.
+    pub fn time_signature(&self) -> u8 {
+        self.time_signature
+    }
+}
+
+impl AudioAnalysis {
+    /// The bars in the track. A bar (or measure) is a segment of time defined as a given number of beats.
+    pub fn bars(&self) -> &[TimeInterval] {
+        &self.bars
+    }
+
+    /// The beats in the track. A beat is the basic time unit of a piece of music.
+    pub fn beats(&self) -> &[TimeInterval] {
+        &self.beats
+    }
+
+    /// The tatums in the track. A tatum represents the lowest regular pulse train that a listener intuitively infers
+    /// from the timing of perceived musical events.
+    pub fn tatums(&self) -> &[TimeInterval] {
+        &self.tatums
+    }
+
+    /// The sections in the track.
+    pub fn sections(&self) -> &[AudioSection] {
+        &self.sections
+    }
+
+    /// The segments in the track.
+    pub fn segments(&self) -> &[AudioSegment] {
+        &self.segments
+    }
+}
+
+impl TimeInterval {
+    /// The starting point of the interval in seconds.
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+
+    /// The duration of the interval in seconds.
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// The confidence of the interval's reliability, from 0.0 to 1.0.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
+
+impl AudioSection {
+    /// The starting point of the section in seconds.
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+
+    /// The duration of the section in seconds.
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// The confidence of the section's reliability, from 0.0 to 1.0.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    /// The overall loudness of the section in decibels, typically between -60 and 0.
+    pub fn loudness(&self) -> f32 {
+        self.loudness
+    }
+
+    /// The estimated tempo of the section in beats per minute.
+    pub fn tempo(&self) -> f32 {
+        self.tempo
+    }
+
+    /// The confidence of the tempo's reliability, from 0.0 to 1.0.
+    pub fn tempo_confidence(&self) -> f32 {
+        self.tempo_confidence
+    }
+
+    /// The estimated key of the section, using standard [pitch class
+    /// notation](https://en.wikipedia.org/wiki/Pitch_class). -1 if no key was detected.
+    pub fn key(&self) -> i8 {
+        self.key
+    }
+
+    /// The confidence of the key's reliability, from 0.0 to 1.0.
+    pub fn key_confidence(&self) -> f32 {
+        self.key_confidence
+    }
+
+    /// The modality of the section. 1 is major, 0 is minor and -1 if no modality was detected.
+    pub fn mode(&self) -> i8 {
+        self.mode
+    }
+
+    /// The confidence of the modality's reliability, from 0.0 to 1.0.
+    pub fn mode_confidence(&self) -> f32 {
+        self.mode_confidence
+    }
+
+    /// The estimated time signature of the section, i.e. how many beats are in each bar.
+    pub fn time_signature(&self) -> u8 {
+        self.time_signature
+    }
+
+    /// The confidence of the time signature's reliability, from 0.0 to 1.0.
+    pub fn time_signature_confidence(&self) -> f32 {
+        self.time_signature_confidence
+    }
+}
+
+impl AudioSegment {
+    /// The starting point of the segment in seconds.
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+
+    /// The duration of the segment in seconds.
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// The confidence of the segment's reliability, from 0.0 to 1.0.
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+
+    /// The loudness at the start of the segment in decibels.
+    pub fn loudness_start(&self) -> f32 {
+        self.loudness_start
+    }
+
+    /// The peak loudness of the segment in decibels.
+    pub fn loudness_max(&self) -> f32 {
+        self.loudness_max
+    }
+
+    /// The offset of the peak loudness from the start of the segment in seconds.
+    pub fn loudness_max_time(&self) -> f32 {
+        self.loudness_max_time
+    }
+
+    /// The loudness at the end of the segment in decibels.
+    pub fn loudness_end(&self) -> f32 {
+        self.loudness_end
+    }
+
+    /// The segment's pitch content as 12 values corresponding to the 12 pitch classes, from C to B, each from 0.0 to
+    /// 1.0 relative to the strongest pitch in the segment.
+    pub fn pitches(&self) -> &[f32] {
+        &self.pitches
+    }
+
+    /// The segment's timbre as 12 unbounded values centered around 0.0, describing the quality of the sound.
+    pub fn timbre(&self) -> &[f32] {
+        &self.timbre
+    }
+}
+
+impl PartialEq for AudioFeatures {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for AudioFeatures {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_audio_features() {
+        let json = r#"{
+            "acousticness": 0.00242,
+            "analysis_url": "https://api.spotify.com/v1/audio-analysis/2takcwOaAZWiXQijPHIx7B",
+            "danceability": 0.585,
+            "duration_ms": 237040,
+            "energy": 0.842,
+            "id": "2takcwOaAZWiXQijPHIx7B",
+            "instrumentalness": 0.00686,
+            "key": 9,
+            "liveness": 0.0866,
+            "loudness": -5.883,
+            "mode": 0,
+            "speechiness": 0.0556,
+            "tempo": 118.211,
+            "time_signature": 4,
+            "track_href": "https://api.spotify.com/v1/tracks/2takcwOaAZWiXQijPHIx7B",
+            "type": "audio_features",
+            "uri": "spotify:track:2takcwOaAZWiXQijPHIx7B",
+            "valence": 0.428
+        }"#;
+
+        let features: AudioFeatures = serde_json::from_str(json).unwrap();
+
+        assert_eq!(features.id().as_str(), "2takcwOaAZWiXQijPHIx7B");
+        assert_eq!(features.uri(), "spotify:track:2takcwOaAZWiXQijPHIx7B");
+        assert_eq!(features.duration(), Duration::from_millis(237040));
+        assert_eq!(features.key(), 9);
+        assert_eq!(features.mode(), 0);
+        assert_eq!(features.time_signature(), 4);
+        assert_eq!(features.danceability(), 0.585);
+        assert_eq!(features.loudness(), -5.883);
+        assert_eq!(features.tempo(), 118.211);
+    }
+
+    #[test]
+    fn deserialize_audio_analysis() {
+        let json = r#"{
+            "meta": { "analyzer_version": "4.0.0", "status_code": 0 },
+            "track": { "duration": 207.95985, "tempo": 118.211 },
+            "bars": [{ "start": 0.49567, "duration": 2.18749, "confidence": 0.925 }],
+            "beats": [{ "start": 0.49567, "duration": 0.52502, "confidence": 0.836 }],
+            "tatums": [{ "start": 0.49567, "duration": 0.26251, "confidence": 0.729 }],
+            "sections": [{
+                "start": 0,
+                "duration": 6.97092,
+                "confidence": 1,
+                "loudness": -14.938,
+                "tempo": 113.178,
+                "tempo_confidence": 0.647,
+                "key": 9,
+                "key_confidence": 0.297,
+                "mode": -1,
+                "mode_confidence": 0.471,
+                "time_signature": 4,
+                "time_signature_confidence": 1
+            }],
+            "segments": [{
+                "start": 0.70154,
+                "duration": 0.19891,
+                "confidence": 0.435,
+                "loudness_start": -23.053,
+                "loudness_max_time": 0.07305,
+                "loudness_max": -14.25,
+                "loudness_end": 0,
+                "pitches": [0.212, 0.141, 0.294, 0.15, 0.083, 0.144, 0.127, 0.113, 0.197, 1, 0.164, 0.057],
+                "timbre": [42.115, 64.373, -0.233, 9.928, -27.151, -38.932, 12.052, 2.327, -4.105, -2.839, 2.498, 0.592]
+            }]
+        }"#;
+
+        let analysis: AudioAnalysis = serde_json::from_str(json).unwrap();
+
+        assert_eq!(analysis.bars()[0].duration(), 2.18749);
+        assert_eq!(analysis.beats()[0].confidence(), 0.836);
+        assert_eq!(analysis.tatums()[0].start(), 0.49567);
+        assert_eq!(analysis.sections()[0].mode(), -1);
+        assert_eq!(analysis.sections()[0].key(), 9);
+        assert_eq!(analysis.segments()[0].pitches().len(), 12);
+        assert_eq!(analysis.segments()[0].timbre()[0], 42.115);
+    }
+}
@@ -0,0 +1,258 @@
+//! Contains the [Category]-object used to tag items in Spotify's browse interface.
+//!
+//! Categories are retrieved with the [`categories`-function](crate::client::UnscopedClient::categories), and the
+//! playlists tagged with a category with the [`category_playlists`-function in
+//! UnscopedClient](crate::client::UnscopedClient::category_playlists).
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    page::{Page, PageInformation, PageObject},
+    playlist::{PartialPlaylist, PlaylistObject},
+    Image,
+};
+
+/// A category used to tag items in Spotify's browse interface, such as "Top Lists" or "Mood".
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+pub struct Category {
+    id: String,
+    name: String,
+    icons: Vec<Image>,
+}
+
+/// A page of categories.
+///
+/// This object is retrieved only through the [categories](crate::client::UnscopedClient::categories)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct Categories {
+    categories: PageObject<Category>,
+}
+
+/// A page of playlists tagged with a category.
+///
+/// This object is retrieved only through the
+/// [category_playlists](crate::client::UnscopedClient::category_playlists)-function. You won't be interacting objects
+/// of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct CategoryPlaylists {
+    playlists: PageObject<PlaylistObject>,
+}
+
+impl Category {
+    /// The category's ID. Unlike most other IDs, category IDs aren't Spotify IDs but arbitrary strings, such as
+    /// `toplists`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The category's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The category's icons in various sizes.
+    pub fn icons(&self) -> &[Image] {
+        &self.icons
+    }
+}
+
+impl PartialEq for Category {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl From<Categories> for Page<Categories, Category> {
+    fn from(categories: Categories) -> Self {
+        Page {
+            inner: categories,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl From<CategoryPlaylists> for Page<CategoryPlaylists, PartialPlaylist> {
+    fn from(playlists: CategoryPlaylists) -> Self {
+        Page {
+            inner: playlists,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for Categories {}
+impl crate::private::Sealed for CategoryPlaylists {}
+
+impl PageInformation<Category> for Categories {
+    type Items = Vec<Category>;
+
+    fn items(&self) -> Self::Items {
+        self.categories.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.categories.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<Category> as PageInformation<Category>>::next(self.categories)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<Category> as PageInformation<Category>>::previous(self.categories)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<Category> as PageInformation<Category>>::offset(&self.categories)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<Category> as PageInformation<Category>>::limit(&self.categories)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<Category> as PageInformation<Category>>::total(&self.categories)
+    }
+}
+
+impl PageInformation<PartialPlaylist> for CategoryPlaylists {
+    type Items = Vec<PartialPlaylist>;
+
+    fn items(&self) -> Self::Items {
+        self.playlists.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.playlists.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::next(self.playlists)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::previous(self.playlists)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::offset(&self.playlists)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::limit(&self.playlists)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::total(&self.playlists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{id::IdTrait, playlist::CommonPlaylistInformation};
+
+    #[test]
+    fn deserialize_categories_page() {
+        let json = r#"{
+            "categories": {
+                "href": "https://api.spotify.com/v1/browse/categories?offset=0&limit=2",
+                "items": [
+                    {
+                        "href": "https://api.spotify.com/v1/browse/categories/toplists",
+                        "icons": [
+                            {
+                                "height": 275,
+                                "url": "https://t.scdn.co/media/derived/toplists_11160599e6a04ac5d6f2757f5511778f_0_0_275_275.jpg",
+                                "width": 275
+                            }
+                        ],
+                        "id": "toplists",
+                        "name": "Top Lists"
+                    },
+                    {
+                        "href": "https://api.spotify.com/v1/browse/categories/0JQ5DAqbMKFQ00XGBls6ym",
+                        "icons": [],
+                        "id": "0JQ5DAqbMKFQ00XGBls6ym",
+                        "name": "Hip-Hop"
+                    }
+                ],
+                "limit": 2,
+                "next": "https://api.spotify.com/v1/browse/categories?offset=2&limit=2",
+                "offset": 0,
+                "previous": null,
+                "total": 52
+            }
+        }"#;
+
+        let page: Page<Categories, Category> = serde_json::from_str::<Categories>(json).unwrap().into();
+        let categories = page.take_items();
+
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories[0].id(), "toplists");
+        assert_eq!(categories[0].name(), "Top Lists");
+        assert_eq!(categories[0].icons()[0].dimensions.as_ref().unwrap().width, 275);
+        assert_eq!(categories[1].name(), "Hip-Hop");
+        assert!(categories[1].icons().is_empty());
+    }
+
+    #[test]
+    fn deserialize_category_playlists_page() {
+        let json = r#"{
+            "message": "Popular playlists",
+            "playlists": {
+                "href": "https://api.spotify.com/v1/browse/categories/toplists/playlists?offset=0&limit=1",
+                "items": [
+                    {
+                        "collaborative": false,
+                        "description": "The hottest 50.",
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/playlist/37i9dQZF1DXcBWIGoYBM5M"
+                        },
+                        "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DXcBWIGoYBM5M",
+                        "id": "37i9dQZF1DXcBWIGoYBM5M",
+                        "images": [],
+                        "name": "Today's Top Hits",
+                        "owner": {
+                            "display_name": "Spotify",
+                            "external_urls": {
+                                "spotify": "https://open.spotify.com/user/spotify"
+                            },
+                            "href": "https://api.spotify.com/v1/users/spotify",
+                            "id": "spotify",
+                            "type": "user",
+                            "uri": "spotify:user:spotify"
+                        },
+                        "primary_color": null,
+                        "public": null,
+                        "snapshot_id": "MTY2OTY0NjAwMCwwMDAwMDAwMGQ0MWQ4Y2Q5OGYwMGIyMDRlOTgwMDk5OGVjZjg0Mjdl",
+                        "tracks": {
+                            "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DXcBWIGoYBM5M/tracks",
+                            "total": 50
+                        },
+                        "type": "playlist",
+                        "uri": "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M"
+                    }
+                ],
+                "limit": 1,
+                "next": null,
+                "offset": 0,
+                "previous": null,
+                "total": 1
+            }
+        }"#;
+
+        let page: Page<CategoryPlaylists, PartialPlaylist> =
+            serde_json::from_str::<CategoryPlaylists>(json).unwrap().into();
+        let playlists = page.take_items();
+
+        assert_eq!(playlists.len(), 1);
+        assert_eq!(playlists[0].id().as_str(), "37i9dQZF1DXcBWIGoYBM5M");
+        assert_eq!(playlists[0].name(), "Today's Top Hits");
+    }
+}
@@ -1,8 +1,8 @@
-//! Contains the [CountryCode] enum.
+//! Contains the [CountryCode] and [Market] enums.
 
 use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use serde::{de::IntoDeserializer, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Represents all possible two-letter country codes.
 ///
@@ -269,8 +269,353 @@ pub enum CountryCode {
     XK,
 }
 
+impl CountryCode {
+    /// Returns the country's common English name, such as "Finland" for [FI](CountryCode::FI).
+    ///
+    /// The name is meant for displaying to users. The [Display](fmt::Display)-implementation and serialization still
+    /// use the two-letter country code.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CountryCode::AD => "Andorra",
+            CountryCode::AE => "United Arab Emirates",
+            CountryCode::AF => "Afghanistan",
+            CountryCode::AG => "Antigua and Barbuda",
+            CountryCode::AI => "Anguilla",
+            CountryCode::AL => "Albania",
+            CountryCode::AM => "Armenia",
+            CountryCode::AO => "Angola",
+            CountryCode::AQ => "Antarctica",
+            CountryCode::AR => "Argentina",
+            CountryCode::AS => "American Samoa",
+            CountryCode::AT => "Austria",
+            CountryCode::AU => "Australia",
+            CountryCode::AW => "Aruba",
+            CountryCode::AX => "Åland Islands",
+            CountryCode::AZ => "Azerbaijan",
+            CountryCode::BA => "Bosnia and Herzegovina",
+            CountryCode::BB => "Barbados",
+            CountryCode::BD => "Bangladesh",
+            CountryCode::BE => "Belgium",
+            CountryCode::BF => "Burkina Faso",
+            CountryCode::BG => "Bulgaria",
+            CountryCode::BH => "Bahrain",
+            CountryCode::BI => "Burundi",
+            CountryCode::BJ => "Benin",
+            CountryCode::BL => "Saint Barthélemy",
+            CountryCode::BM => "Bermuda",
+            CountryCode::BN => "Brunei",
+            CountryCode::BO => "Bolivia",
+            CountryCode::BQ => "Caribbean Netherlands",
+            CountryCode::BR => "Brazil",
+            CountryCode::BS => "Bahamas",
+            CountryCode::BT => "Bhutan",
+            CountryCode::BV => "Bouvet Island",
+            CountryCode::BW => "Botswana",
+            CountryCode::BY => "Belarus",
+            CountryCode::BZ => "Belize",
+            CountryCode::CA => "Canada",
+            CountryCode::CC => "Cocos (Keeling) Islands",
+            CountryCode::CD => "Democratic Republic of the Congo",
+            CountryCode::CF => "Central African Republic",
+            CountryCode::CG => "Republic of the Congo",
+            CountryCode::CH => "Switzerland",
+            CountryCode::CI => "Côte d'Ivoire",
+            CountryCode::CK => "Cook Islands",
+            CountryCode::CL => "Chile",
+            CountryCode::CM => "Cameroon",
+            CountryCode::CN => "China",
+            CountryCode::CO => "Colombia",
+            CountryCode::CR => "Costa Rica",
+            CountryCode::CU => "Cuba",
+            CountryCode::CV => "Cape Verde",
+            CountryCode::CW => "Curaçao",
+            CountryCode::CX => "Christmas Island",
+            CountryCode::CY => "Cyprus",
+            CountryCode::CZ => "Czechia",
+            CountryCode::DE => "Germany",
+            CountryCode::DJ => "Djibouti",
+            CountryCode::DK => "Denmark",
+            CountryCode::DM => "Dominica",
+            CountryCode::DO => "Dominican Republic",
+            CountryCode::DZ => "Algeria",
+            CountryCode::EC => "Ecuador",
+            CountryCode::EE => "Estonia",
+            CountryCode::EG => "Egypt",
+            CountryCode::EH => "Western Sahara",
+            CountryCode::ER => "Eritrea",
+            CountryCode::ES => "Spain",
+            CountryCode::ET => "Ethiopia",
+            CountryCode::FI => "Finland",
+            CountryCode::FJ => "Fiji",
+            CountryCode::FK => "Falkland Islands",
+            CountryCode::FM => "Micronesia",
+            CountryCode::FO => "Faroe Islands",
+            CountryCode::FR => "France",
+            CountryCode::GA => "Gabon",
+            CountryCode::GB => "United Kingdom",
+            CountryCode::GD => "Grenada",
+            CountryCode::GE => "Georgia",
+            CountryCode::GF => "French Guiana",
+            CountryCode::GG => "Guernsey",
+            CountryCode::GH => "Ghana",
+            CountryCode::GI => "Gibraltar",
+            CountryCode::GL => "Greenland",
+            CountryCode::GM => "Gambia",
+            CountryCode::GN => "Guinea",
+            CountryCode::GP => "Guadeloupe",
+            CountryCode::GQ => "Equatorial Guinea",
+            CountryCode::GR => "Greece",
+            CountryCode::GS => "South Georgia and the South Sandwich Islands",
+            CountryCode::GT => "Guatemala",
+            CountryCode::GU => "Guam",
+            CountryCode::GW => "Guinea-Bissau",
+            CountryCode::GY => "Guyana",
+            CountryCode::HK => "Hong Kong",
+            CountryCode::HM => "Heard Island and McDonald Islands",
+            CountryCode::HN => "Honduras",
+            CountryCode::HR => "Croatia",
+            CountryCode::HT => "Haiti",
+            CountryCode::HU => "Hungary",
+            CountryCode::ID => "Indonesia",
+            CountryCode::IE => "Ireland",
+            CountryCode::IL => "Israel",
+            CountryCode::IM => "Isle of Man",
+            CountryCode::IN => "India",
+            CountryCode::IO => "British Indian Ocean Territory",
+            CountryCode::IQ => "Iraq",
+            CountryCode::IR => "Iran",
+            CountryCode::IS => "Iceland",
+            CountryCode::IT => "Italy",
+            CountryCode::JE => "Jersey",
+            CountryCode::JM => "Jamaica",
+            CountryCode::JO => "Jordan",
+            CountryCode::JP => "Japan",
+            CountryCode::KE => "Kenya",
+            CountryCode::KG => "Kyrgyzstan",
+            CountryCode::KH => "Cambodia",
+            CountryCode::KI => "Kiribati",
+            CountryCode::KM => "Comoros",
+            CountryCode::KN => "Saint Kitts and Nevis",
+            CountryCode::KP => "North Korea",
+            CountryCode::KR => "South Korea",
+            CountryCode::KW => "Kuwait",
+            CountryCode::KY => "Cayman Islands",
+            CountryCode::KZ => "Kazakhstan",
+            CountryCode::LA => "Laos",
+            CountryCode::LB => "Lebanon",
+            CountryCode::LC => "Saint Lucia",
+            CountryCode::LI => "Liechtenstein",
+            CountryCode::LK => "Sri Lanka",
+            CountryCode::LR => "Liberia",
+            CountryCode::LS => "Lesotho",
+            CountryCode::LT => "Lithuania",
+            CountryCode::LU => "Luxembourg",
+            CountryCode::LV => "Latvia",
+            CountryCode::LY => "Libya",
+            CountryCode::MA => "Morocco",
+            CountryCode::MC => "Monaco",
+            CountryCode::MD => "Moldova",
+            CountryCode::ME => "Montenegro",
+            CountryCode::MF => "Saint Martin",
+            CountryCode::MG => "Madagascar",
+            CountryCode::MH => "Marshall Islands",
+            CountryCode::MK => "North Macedonia",
+            CountryCode::ML => "Mali",
+            CountryCode::MM => "Myanmar",
+            CountryCode::MN => "Mongolia",
+            CountryCode::MO => "Macao",
+            CountryCode::MP => "Northern Mariana Islands",
+            CountryCode::MQ => "Martinique",
+            CountryCode::MR => "Mauritania",
+            CountryCode::MS => "Montserrat",
+            CountryCode::MT => "Malta",
+            CountryCode::MU => "Mauritius",
+            CountryCode::MV => "Maldives",
+            CountryCode::MW => "Malawi",
+            CountryCode::MX => "Mexico",
+            CountryCode::MY => "Malaysia",
+            CountryCode::MZ => "Mozambique",
+            CountryCode::NA => "Namibia",
+            CountryCode::NC => "New Caledonia",
+            CountryCode::NE => "Niger",
+            CountryCode::NF => "Norfolk Island",
+            CountryCode::NG => "Nigeria",
+            CountryCode::NI => "Nicaragua",
+            CountryCode::NL => "Netherlands",
+            CountryCode::NO => "Norway",
+            CountryCode::NP => "Nepal",
+            CountryCode::NR => "Nauru",
+            CountryCode::NU => "Niue",
+            CountryCode::NZ => "New Zealand",
+            CountryCode::OM => "Oman",
+            CountryCode::PA => "Panama",
+            CountryCode::PE => "Peru",
+            CountryCode::PF => "French Polynesia",
+            CountryCode::PG => "Papua New Guinea",
+            CountryCode::PH => "Philippines",
+            CountryCode::PK => "Pakistan",
+            CountryCode::PL => "Poland",
+            CountryCode::PM => "Saint Pierre and Miquelon",
+            CountryCode::PN => "Pitcairn Islands",
+            CountryCode::PR => "Puerto Rico",
+            CountryCode::PS => "Palestine",
+            CountryCode::PT => "Portugal",
+            CountryCode::PW => "Palau",
+            CountryCode::PY => "Paraguay",
+            CountryCode::QA => "Qatar",
+            CountryCode::RE => "Réunion",
+            CountryCode::RO => "Romania",
+            CountryCode::RS => "Serbia",
+            CountryCode::RU => "Russia",
+            CountryCode::RW => "Rwanda",
+            CountryCode::SA => "Saudi Arabia",
+            CountryCode::SB => "Solomon Islands",
+            CountryCode::SC => "Seychelles",
+            CountryCode::SD => "Sudan",
+            CountryCode::SE => "Sweden",
+            CountryCode::SG => "Singapore",
+            CountryCode::SH => "Saint Helena, Ascension and Tristan da Cunha",
+            CountryCode::SI => "Slovenia",
+            CountryCode::SJ => "Svalbard and Jan Mayen",
+            CountryCode::SK => "Slovakia",
+            CountryCode::SL => "Sierra Leone",
+            CountryCode::SM => "San Marino",
+            CountryCode::SN => "Senegal",
+            CountryCode::SO => "Somalia",
+            CountryCode::SR => "Suriname",
+            CountryCode::SS => "South Sudan",
+            CountryCode::ST => "São Tomé and Príncipe",
+            CountryCode::SV => "El Salvador",
+            CountryCode::SX => "Sint Maarten",
+            CountryCode::SY => "Syria",
+            CountryCode::SZ => "Eswatini",
+            CountryCode::TC => "Turks and Caicos Islands",
+            CountryCode::TD => "Chad",
+            CountryCode::TF => "French Southern Territories",
+            CountryCode::TG => "Togo",
+            CountryCode::TH => "Thailand",
+            CountryCode::TJ => "Tajikistan",
+            CountryCode::TK => "Tokelau",
+            CountryCode::TL => "Timor-Leste",
+            CountryCode::TM => "Turkmenistan",
+            CountryCode::TN => "Tunisia",
+            CountryCode::TO => "Tonga",
+            CountryCode::TR => "Turkey",
+            CountryCode::TT => "Trinidad and Tobago",
+            CountryCode::TV => "Tuvalu",
+            CountryCode::TW => "Taiwan",
+            CountryCode::TZ => "Tanzania",
+            CountryCode::UA => "Ukraine",
+            CountryCode::UG => "Uganda",
+            CountryCode::UM => "United States Minor Outlying Islands",
+            CountryCode::US => "United States",
+            CountryCode::UY => "Uruguay",
+            CountryCode::UZ => "Uzbekistan",
+            CountryCode::VA => "Vatican City",
+            CountryCode::VC => "Saint Vincent and the Grenadines",
+            CountryCode::VE => "Venezuela",
+            CountryCode::VG => "British Virgin Islands",
+            CountryCode::VI => "United States Virgin Islands",
+            CountryCode::VN => "Vietnam",
+            CountryCode::VU => "Vanuatu",
+            CountryCode::WF => "Wallis and Futuna",
+            CountryCode::WS => "Samoa",
+            CountryCode::YE => "Yemen",
+            CountryCode::YT => "Mayotte",
+            CountryCode::ZA => "South Africa",
+            CountryCode::ZM => "Zambia",
+            CountryCode::ZW => "Zimbabwe",
+            CountryCode::XK => "Kosovo",
+        }
+    }
+}
+
 impl fmt::Display for CountryCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(self, f)
     }
 }
+
+/// The market value Spotify resolves to the country of the user the request's access token belongs to.
+pub(crate) const FROM_TOKEN_MARKET: &str = "from_token";
+
+/// A market to request content in.
+///
+/// Every function taking a market accepts a [CountryCode] as well, which is converted into a
+/// [Country](Market::Country) market.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Market {
+    /// The given country.
+    Country(CountryCode),
+    /// The country associated with the user account the request's access token belongs to. Only applicable with
+    /// user-authenticated clients.
+    FromToken,
+}
+
+impl From<CountryCode> for Market {
+    fn from(country: CountryCode) -> Self {
+        Market::Country(country)
+    }
+}
+
+impl fmt::Display for Market {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Market::Country(country) => fmt::Display::fmt(country, f),
+            Market::FromToken => f.write_str(FROM_TOKEN_MARKET),
+        }
+    }
+}
+
+impl Serialize for Market {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Market {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let market = String::deserialize(deserializer)?;
+
+        match market.as_str() {
+            FROM_TOKEN_MARKET => Ok(Market::FromToken),
+            country => CountryCode::deserialize(country.into_deserializer()).map(Market::Country),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CountryCode, Market};
+
+    #[test]
+    fn country_code_has_name() {
+        assert_eq!(CountryCode::FI.name(), "Finland");
+        assert_eq!(CountryCode::GB.name(), "United Kingdom");
+        assert_eq!(CountryCode::CI.name(), "Côte d'Ivoire");
+        assert_eq!(CountryCode::XK.name(), "Kosovo");
+    }
+
+    #[test]
+    fn country_code_displays_and_serializes_as_code() {
+        assert_eq!(CountryCode::XK.to_string(), "XK");
+        assert_eq!(serde_json::to_string(&CountryCode::FI).unwrap(), "\"FI\"");
+    }
+
+    #[test]
+    fn market_displays_and_serializes_as_query_value() {
+        assert_eq!(Market::from(CountryCode::FI).to_string(), "FI");
+        assert_eq!(Market::FromToken.to_string(), "from_token");
+        assert_eq!(serde_json::to_string(&Market::FromToken).unwrap(), "\"from_token\"");
+        assert_eq!(
+            serde_json::from_str::<Market>("\"SE\"").unwrap(),
+            Market::Country(CountryCode::SE)
+        );
+    }
+}
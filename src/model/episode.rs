@@ -0,0 +1,485 @@
+//! Everything related to podcast episodes.
+//!
+//! Contains the two different kinds of episodes; [FullEpisode] and [PartialEpisode].
+//!
+//! - [FullEpisode]: may contain all possible information about an episode, including the show it belongs to. Generally
+//!   retrieved from the [episode-](crate::client::UnscopedClient::episode) and
+//!   [episodes-functions](crate::client::UnscopedClient::episodes).
+//! - [PartialEpisode]: contains most information about an episode, except the show it belongs to. Generally retrieved
+//!   as part of a listing of a show's episodes.
+//!
+//! The episode object Spotify returns from the API is not directly available. The two episode objects may be serialized
+//! to get almost all of the original API response back. The model strips certain unnecessary or redundant fields from
+//! the response.
+//!
+//! # Episode equality
+//!
+//! Two episodes are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        model::{
+            episode::ResumePoint,
+            id::{EpisodeId, Id},
+            object_type::{object_type_serialize, TypeEpisode},
+            show::PartialShow,
+            DatePrecision, ExternalUrls, Image, Restrictions,
+        },
+        util::duration_millis,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonEpisodeFields;
+    }
+
+    pub(super) trait FullFields {
+        fn full_fields(&self) -> &FullEpisodeFields;
+    }
+
+    /// This struct covers all the possible episode responses from Spotify's API. It is converted into either a
+    /// [FullEpisode](super::FullEpisode) or a [PartialEpisode](super::PartialEpisode), depending on which fields are
+    /// set.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct EpisodeObject {
+        /// Fields available in every episode
+        #[serde(flatten)]
+        pub(crate) common: CommonEpisodeFields,
+
+        /// Fields only in full episodes
+        #[serde(flatten)]
+        pub(crate) full: Option<FullEpisodeFields>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonEpisodeFields {
+        // basic information
+        pub(crate) name: String,
+        pub(crate) description: String,
+        #[serde(default)]
+        pub(crate) html_description: String,
+        #[serde(rename = "duration_ms", with = "duration_millis")]
+        pub(crate) duration: Duration,
+        pub(crate) explicit: bool,
+        pub(crate) audio_preview_url: Option<String>,
+        pub(crate) release_date: String,
+        pub(crate) release_date_precision: DatePrecision,
+        #[serde(default)]
+        pub(crate) languages: Vec<String>,
+        #[serde(default)]
+        pub(crate) is_externally_hosted: bool,
+        #[serde(default)]
+        pub(crate) images: Vec<Image>,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        #[serde(with = "crate::model::id::bare")]
+        pub(crate) id: Id<'static, EpisodeId>,
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypeEpisode,
+
+        // only available when the user has granted the user-read-playback-position scope
+        #[serde(default)]
+        pub(crate) resume_point: Option<ResumePoint>,
+
+        // only available when a market has been specified in the request
+        #[serde(default)]
+        pub(crate) is_playable: Option<bool>,
+        #[serde(default)]
+        pub(crate) restrictions: Option<Restrictions>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct FullEpisodeFields {
+        pub(crate) show: PartialShow,
+    }
+}
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+pub(crate) use self::private::EpisodeObject;
+use self::private::{CommonEpisodeFields, FullEpisodeFields};
+use super::{
+    id::{EpisodeId, Id, IdTrait},
+    show::PartialShow,
+    DatePrecision, ExternalUrls, Image, Restrictions,
+};
+use crate::{error::ConversionError, util::duration_millis};
+
+/// Functions for retrieving information that is common to every episode type.
+pub trait CommonEpisodeInformation: crate::private::Sealed {
+    /// The episode's name.
+    fn name(&self) -> &str;
+    /// The episode's description without HTML tags.
+    fn description(&self) -> &str;
+    /// The episode's description, possibly containing HTML tags.
+    fn html_description(&self) -> &str;
+    /// The episode's duration.
+    fn duration(&self) -> Duration;
+    /// Whether or not the episode has explicit content.
+    fn explicit(&self) -> bool;
+    /// A link to a 30 second preview of the episode, if available.
+    fn audio_preview_url(&self) -> Option<&str>;
+    /// The date the episode was released.
+    fn release_date(&self) -> &str;
+    /// The precision of the episode's [release date](Self::release_date).
+    fn release_date_precision(&self) -> DatePrecision;
+    /// The languages used in the episode as ISO 639 codes.
+    fn languages(&self) -> &[String];
+    /// Whether or not the episode is hosted outside of Spotify's CDN.
+    fn is_externally_hosted(&self) -> bool;
+    /// The cover art for the episode in various sizes.
+    fn images(&self) -> &[Image];
+    /// The external URLs for the episode.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The episode's Spotify ID.
+    fn id(&self) -> Id<'_, EpisodeId>;
+    /// The user's most recent position in the episode. Only available when the user has granted the
+    /// [UserReadPlaybackPosition](crate::scope::Scope::UserReadPlaybackPosition) scope.
+    fn resume_point(&self) -> Option<ResumePoint>;
+    /// Whether or not the episode is playable in the market the episode was requested in. Only available when a market
+    /// was specified in the request.
+    fn is_playable(&self) -> Option<bool>;
+    /// The reason the episode is restricted in the market the episode was requested in. Only available when a market
+    /// was specified in the request and the episode is restricted.
+    fn restrictions(&self) -> Option<&Restrictions>;
+}
+
+/// Functions for retrieving information only in full episodes.
+pub trait FullEpisodeInformation: crate::private::Sealed {
+    /// The show the episode belongs to.
+    fn show(&self) -> &PartialShow;
+}
+
+impl<T> CommonEpisodeInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn description(&self) -> &str {
+        &self.common_fields().description
+    }
+
+    fn html_description(&self) -> &str {
+        &self.common_fields().html_description
+    }
+
+    fn duration(&self) -> Duration {
+        self.common_fields().duration
+    }
+
+    fn explicit(&self) -> bool {
+        self.common_fields().explicit
+    }
+
+    fn audio_preview_url(&self) -> Option<&str> {
+        self.common_fields().audio_preview_url.as_deref()
+    }
+
+    fn release_date(&self) -> &str {
+        &self.common_fields().release_date
+    }
+
+    fn release_date_precision(&self) -> DatePrecision {
+        self.common_fields().release_date_precision
+    }
+
+    fn languages(&self) -> &[String] {
+        &self.common_fields().languages
+    }
+
+    fn is_externally_hosted(&self) -> bool {
+        self.common_fields().is_externally_hosted
+    }
+
+    fn images(&self) -> &[Image] {
+        &self.common_fields().images
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn id(&self) -> Id<'_, EpisodeId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn resume_point(&self) -> Option<ResumePoint> {
+        self.common_fields().resume_point
+    }
+
+    fn is_playable(&self) -> Option<bool> {
+        self.common_fields().is_playable
+    }
+
+    fn restrictions(&self) -> Option<&Restrictions> {
+        self.common_fields().restrictions.as_ref()
+    }
+}
+
+impl<T> FullEpisodeInformation for T
+where
+    T: private::FullFields + crate::private::Sealed,
+{
+    fn show(&self) -> &PartialShow {
+        &self.full_fields().show
+    }
+}
+
+/// This struct's only purpose is to make serializing more efficient by holding only references to its data. When
+/// attempting to serialize an episode object, its fields will be passed as references to this object which is then
+/// serialized. This avoids having to clone the entire episode in order to reconstruct an EpisodeObject.
+#[derive(Serialize)]
+struct EpisodeObjectRef<'a> {
+    #[serde(flatten)]
+    common: &'a CommonEpisodeFields,
+    #[serde(flatten)]
+    full: Option<&'a FullEpisodeFields>,
+}
+
+/// A full episode. Contains [full information](self::FullEpisodeInformation), in addition to all
+/// [common](self::CommonEpisodeInformation) information about an episode.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(try_from = "EpisodeObject")]
+pub struct FullEpisode {
+    common: CommonEpisodeFields,
+    full: FullEpisodeFields,
+}
+
+/// A partial episode. Contains all [common](self::CommonEpisodeInformation) information about an episode.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(from = "EpisodeObject")]
+pub struct PartialEpisode {
+    common: CommonEpisodeFields,
+}
+
+/// The user's most recent position in an episode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumePoint {
+    fully_played: bool,
+    #[serde(rename = "resume_position_ms", with = "duration_millis")]
+    resume_position: Duration,
+}
+
+impl ResumePoint {
+    /// Whether or not the user has fully played the episode.
+    pub fn fully_played(&self) -> bool {
+        self.fully_played
+    }
+
+    /// The user's most recent position in the episode.
+    pub fn resume_position(&self) -> Duration {
+        self.resume_position
+    }
+}
+
+impl PartialEq for FullEpisode {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialEpisode {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialEpisode> for FullEpisode {
+    fn eq(&self, other: &PartialEpisode) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullEpisode> for PartialEpisode {
+    fn eq(&self, other: &FullEpisode) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl TryFrom<EpisodeObject> for FullEpisode {
+    type Error = ConversionError;
+
+    fn try_from(obj: EpisodeObject) -> Result<Self, Self::Error> {
+        if let Some(full) = obj.full {
+            Ok(FullEpisode {
+                common: obj.common,
+                full,
+            })
+        } else {
+            Err(ConversionError::new(
+                "attempt to convert partial episode object into full episode".into(),
+            ))
+        }
+    }
+}
+
+impl From<EpisodeObject> for PartialEpisode {
+    fn from(obj: EpisodeObject) -> Self {
+        PartialEpisode { common: obj.common }
+    }
+}
+
+impl From<FullEpisode> for PartialEpisode {
+    fn from(full: FullEpisode) -> Self {
+        PartialEpisode { common: full.common }
+    }
+}
+
+impl From<FullEpisode> for EpisodeObject {
+    fn from(value: FullEpisode) -> Self {
+        Self {
+            common: value.common,
+            full: Some(value.full),
+        }
+    }
+}
+
+impl From<PartialEpisode> for EpisodeObject {
+    fn from(value: PartialEpisode) -> Self {
+        Self {
+            common: value.common,
+            full: None,
+        }
+    }
+}
+
+impl crate::private::Sealed for FullEpisode {}
+impl crate::private::Sealed for PartialEpisode {}
+
+impl private::CommonFields for FullEpisode {
+    fn common_fields(&self) -> &CommonEpisodeFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialEpisode {
+    fn common_fields(&self) -> &CommonEpisodeFields {
+        &self.common
+    }
+}
+
+impl private::FullFields for FullEpisode {
+    fn full_fields(&self) -> &FullEpisodeFields {
+        &self.full
+    }
+}
+
+impl Serialize for FullEpisode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        EpisodeObjectRef {
+            common: &self.common,
+            full: Some(&self.full),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for PartialEpisode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        EpisodeObjectRef {
+            common: &self.common,
+            full: None,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::show::CommonShowInformation;
+
+    const FULL_EPISODE: &str = r#"{
+        "audio_preview_url": "https://podz-content.spotifycdn.com/audio/clips/preview.mp3",
+        "description": "A test episode.",
+        "html_description": "<p>A test episode.</p>",
+        "duration_ms": 1686230,
+        "explicit": false,
+        "external_urls": {
+            "spotify": "https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ"
+        },
+        "href": "https://api.spotify.com/v1/episodes/512ojhOuo1ktJprKbVcKyQ",
+        "id": "512ojhOuo1ktJprKbVcKyQ",
+        "images": [
+            {
+                "height": 640,
+                "url": "https://i.scdn.co/image/ab6765630000ba8a",
+                "width": 640
+            }
+        ],
+        "is_externally_hosted": false,
+        "is_playable": true,
+        "languages": ["en"],
+        "name": "Test episode",
+        "release_date": "2023-03-01",
+        "release_date_precision": "day",
+        "resume_point": {
+            "fully_played": false,
+            "resume_position_ms": 60000
+        },
+        "type": "episode",
+        "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ",
+        "show": {
+            "external_urls": {
+                "spotify": "https://open.spotify.com/show/38bS44xjbVVZ3No3ByF1dJ"
+            },
+            "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+            "id": "38bS44xjbVVZ3No3ByF1dJ",
+            "description": "A test show.",
+            "explicit": false,
+            "images": [],
+            "media_type": "audio",
+            "name": "Test show",
+            "publisher": "Test publisher",
+            "total_episodes": 1,
+            "type": "show",
+            "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+        }
+    }"#;
+
+    #[test]
+    fn deserialize_full_episode() {
+        let episode: FullEpisode = serde_json::from_str(FULL_EPISODE).unwrap();
+
+        assert_eq!(episode.name(), "Test episode");
+        assert_eq!(episode.id().as_str(), "512ojhOuo1ktJprKbVcKyQ");
+        assert_eq!(episode.duration(), Duration::from_millis(1686230));
+        assert_eq!(episode.release_date(), "2023-03-01");
+        assert_eq!(episode.release_date_precision(), DatePrecision::Day);
+        assert_eq!(episode.images().len(), 1);
+        assert_eq!(episode.is_playable(), Some(true));
+
+        let resume_point = episode.resume_point().unwrap();
+        assert!(!resume_point.fully_played());
+        assert_eq!(resume_point.resume_position(), Duration::from_secs(60));
+
+        assert_eq!(episode.show().name(), "Test show");
+        assert_eq!(episode.show().id().as_str(), "38bS44xjbVVZ3No3ByF1dJ");
+    }
+
+    #[test]
+    fn partial_episode_is_not_full() {
+        let mut json: serde_json::Value = serde_json::from_str(FULL_EPISODE).unwrap();
+        json.as_object_mut().unwrap().remove("show");
+
+        let partial: PartialEpisode = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(partial.id().as_str(), "512ojhOuo1ktJprKbVcKyQ");
+        assert!(partial.resume_point().is_some());
+
+        assert!(serde_json::from_value::<FullEpisode>(json).is_err());
+    }
+}
@@ -21,6 +21,9 @@ pub(crate) struct ApiErrorResponse {
 pub(crate) struct ApiError {
     pub status: u16,
     pub message: ApiErrorMessage,
+    // only player errors have a reason
+    #[serde(default)]
+    pub reason: Option<PlayerError>,
 }
 
 // TODO: can this be made crate-public?
@@ -36,6 +39,51 @@ pub enum AuthenticationErrorKind {
     InvalidScope,
 }
 
+/// The reason a player control failed, as reported by Spotify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum PlayerError {
+    /// There is no previous track in the context.
+    NoPrevTrack,
+    /// There is no next track in the context.
+    NoNextTrack,
+    /// The requested track doesn't exist.
+    NoSpecificTrack,
+    /// The playback is already paused.
+    AlreadyPaused,
+    /// The playback isn't paused.
+    NotPaused,
+    /// The playback isn't on the local device.
+    NotPlayingLocally,
+    /// No track is currently playing.
+    NotPlayingTrack,
+    /// No context is currently playing.
+    NotPlayingContext,
+    /// The current context is endless, so shuffle and repeat cannot be applied.
+    EndlessContext,
+    /// The action is disallowed for the current context.
+    ContextDisallow,
+    /// The track is already playing.
+    AlreadyPlaying,
+    /// The user is rate limited due to too frequent track playing.
+    RateLimited,
+    /// Remote control of the device is disallowed.
+    RemoteControlDisallow,
+    /// The device isn't controllable.
+    DeviceNotControllable,
+    /// Controlling the device's volume is disallowed.
+    #[serde(alias = "VOLUME_CONTROL_DISABLED")]
+    VolumeControlDisallow,
+    /// There is no active device.
+    NoActiveDevice,
+    /// The user doesn't have a Spotify Premium account.
+    PremiumRequired,
+    /// Spotify didn't specify the reason, or the reason isn't known to the library.
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub(crate) enum ApiErrorMessage {
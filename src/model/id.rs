@@ -348,8 +348,36 @@
 //! let context_id = SpotifyId::Context(Id::<UserId>::from_bare("1337420asdasd").unwrap().into());
 //! assert!(matches!(context_id, SpotifyId::Context(_)));
 //! ```
+//!
+//! # Serialization
+//!
+//! All ID types serialize into the canonical URI form. [Id] deserializes from any of the three formats, while
+//! [SpotifyId], [PlayableItem] and [PlayableContext] deserialize only from URIs and URLs. For working with other APIs
+//! that use bare IDs, the [bare]-module serializes and deserializes an [Id] strictly as a bare ID:
+//!
+//! ```
+//! # use ferrispot::model::id::*;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Favourite {
+//!     #[serde(with = "ferrispot::model::id::bare")]
+//!     track: Id<'static, TrackId>,
+//! }
+//!
+//! let favourite: Favourite = serde_json::from_str(r#"{"track":"2pDPOMX0kWA7kcPBcDCQBu"}"#).unwrap();
+//! assert_eq!(
+//!     serde_json::to_string(&favourite).unwrap(),
+//!     r#"{"track":"2pDPOMX0kWA7kcPBcDCQBu"}"#
+//! );
+//! ```
 
-use std::{borrow::Cow, fmt, marker::PhantomData};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
 
 use serde::{
     de::{self, Visitor},
@@ -365,6 +393,35 @@ const URI_PREFIX: &str = "spotify:";
 const URI_COLLECTION_SUFFIX: &str = ":collection";
 const URL_COLLECTION_SUFFIX: &str = "/collection";
 
+/// Serializes and deserializes an [Id] as a bare ID, for use with `#[serde(with = "ferrispot::model::id::bare")]`.
+///
+/// The ID's kind is given by the [Id]'s type parameter. Deserializing fails if the input isn't a valid bare ID, such as
+/// when it is an URI or an URL.
+pub mod bare {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::{Id, IdFromBare, IdTrait, ItemTypeId};
+
+    /// Serializes the [Id] as a bare ID.
+    pub fn serialize<S, T>(id: &Id<'_, T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: ItemTypeId + 'static,
+    {
+        serializer.serialize_str(id.as_str())
+    }
+
+    /// Deserializes a bare ID into an [Id].
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Id<'static, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: ItemTypeId,
+    {
+        let bare = String::deserialize(deserializer)?;
+        Id::from_bare(bare).map_err(de::Error::custom)
+    }
+}
+
 mod private {
     pub trait Sealed {}
 }
@@ -468,8 +525,11 @@ where
 /// Common type that contains a single Spotify ID of a certain kind. The generic type parameter `T` is used to signify
 /// which kind of ID it contains.
 ///
+/// IDs compare and hash equal if their bare IDs are equal, regardless of whether they were parsed from an URI, an URL
+/// or a bare ID. IDs are ordered lexicographically by their bare IDs.
+///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Id<'a, T>
 where
     T: ItemTypeId,
@@ -493,7 +553,7 @@ enum IdKind {
 /// Common type for all Spotify IDs.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SpotifyId<'a> {
     /// A playable item in the Spotify catalog. See [PlayableItem].
     Item(PlayableItem<'a>),
@@ -506,7 +566,7 @@ pub enum SpotifyId<'a> {
 /// Common type for all individually playable IDs.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PlayableItem<'a> {
     /// A track.
     Track(Id<'a, TrackId>),
@@ -517,7 +577,7 @@ pub enum PlayableItem<'a> {
 /// Common type for all playable context IDs.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PlayableContext<'a> {
     /// An artist.
     Artist(Id<'a, ArtistId>),
@@ -534,45 +594,83 @@ pub enum PlayableContext<'a> {
 /// Signifies a track ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TrackId;
 
 /// Signifies an episode ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EpisodeId;
 
 /// Signifies an artist ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ArtistId;
 
 /// Signifies an album ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlbumId;
 
 /// Signifies a playlist ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PlaylistId;
 
 /// Signifies a show ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ShowId;
 
 /// Signifies a user ID.
 ///
 /// See the [module-level docs](self) for information on how to work with IDs.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UserId;
 
+impl<T> PartialEq for Id<'_, T>
+where
+    T: ItemTypeId + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<T> Eq for Id<'_, T> where T: ItemTypeId + 'static {}
+
+impl<T> Hash for Id<'_, T>
+where
+    T: ItemTypeId + 'static,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<T> PartialOrd for Id<'_, T>
+where
+    T: ItemTypeId + 'static,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<'_, T>
+where
+    T: ItemTypeId + 'static,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
 impl<T> private::Sealed for Id<'_, T> where T: ItemTypeId {}
 impl private::Sealed for TrackId {}
 impl private::Sealed for EpisodeId {}
@@ -828,12 +926,14 @@ where
 
     fn as_uri(&'a self) -> Cow<'a, str> {
         match &self.kind {
-            IdKind::Uri { .. } => match &self.value {
+            // the original value can only be reused as-is if it ends in the ID, since e.g. collection URIs have
+            // additional content after the ID
+            IdKind::Uri { id_index, id_len } if id_index + id_len == self.value.len() => match &self.value {
                 Cow::Borrowed(b) => Cow::Borrowed(b),
                 Cow::Owned(o) => Cow::Borrowed(o),
             },
 
-            IdKind::Url { id_index, id_len } => Cow::Owned(format!(
+            IdKind::Uri { id_index, id_len } | IdKind::Url { id_index, id_len } => Cow::Owned(format!(
                 "spotify:{}:{}",
                 T::ITEM_TYPE,
                 &self.value[*id_index..*id_index + id_len]
@@ -845,12 +945,12 @@ where
 
     fn as_url(&'a self) -> Cow<'a, str> {
         match &self.kind {
-            IdKind::Url { .. } => match &self.value {
+            IdKind::Url { id_index, id_len } if id_index + id_len == self.value.len() => match &self.value {
                 Cow::Borrowed(b) => Cow::Borrowed(b),
                 Cow::Owned(o) => Cow::Borrowed(o),
             },
 
-            IdKind::Uri { id_index, id_len } => Cow::Owned(format!(
+            IdKind::Url { id_index, id_len } | IdKind::Uri { id_index, id_len } => Cow::Owned(format!(
                 "https://open.spotify.com/{}/{}",
                 T::ITEM_TYPE,
                 &self.value[*id_index..*id_index + id_len]
@@ -1191,6 +1291,215 @@ impl<'a> From<Id<'a, UserId>> for PlayableContext<'a> {
     }
 }
 
+impl SpotifyId<'_> {
+    /// Returns the item type of the ID.
+    fn item_type(&self) -> ItemType {
+        match self {
+            SpotifyId::Item(PlayableItem::Track(_)) => ItemType::Track,
+            SpotifyId::Item(PlayableItem::Episode(_)) => ItemType::Episode,
+            SpotifyId::Context(PlayableContext::Artist(_)) => ItemType::Artist,
+            SpotifyId::Context(PlayableContext::Album(_)) => ItemType::Album,
+            SpotifyId::Context(PlayableContext::Playlist(_)) => ItemType::Playlist,
+            SpotifyId::Context(PlayableContext::Show(_)) => ItemType::Show,
+            SpotifyId::Context(PlayableContext::Collection(_)) => ItemType::Collection,
+            SpotifyId::User(_) => ItemType::User,
+        }
+    }
+}
+
+impl<'a> SpotifyId<'a> {
+    /// Parses each of the given Spotify URLs or URIs into an ID, returning the result for every input in the same
+    /// order. Unlike parsing the inputs one by one with `?`, a malformed input doesn't stop the remaining inputs from
+    /// being parsed.
+    ///
+    /// ```
+    /// # use ferrispot::model::id::SpotifyId;
+    /// let results = SpotifyId::parse_many([
+    ///     "spotify:track:2pDPOMX0kWA7kcPBcDCQBu",
+    ///     "not an ID",
+    ///     "https://open.spotify.com/album/0tGPJ0bkWOUmH7MEOR77qc",
+    /// ]);
+    ///
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_err());
+    /// assert!(results[2].is_ok());
+    /// ```
+    pub fn parse_many<I, C>(inputs: I) -> Vec<Result<Self, IdError>>
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'a, str>>,
+    {
+        inputs.into_iter().map(Self::from_url_or_uri).collect()
+    }
+
+    /// Parses each of the given Spotify URLs or URIs into an ID like [parse_many](Self::parse_many), but returns only
+    /// the successfully parsed IDs and silently skips the malformed inputs.
+    pub fn parse_many_ok<I, C>(inputs: I) -> Vec<Self>
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'a, str>>,
+    {
+        inputs
+            .into_iter()
+            .filter_map(|input| Self::from_url_or_uri(input).ok())
+            .collect()
+    }
+}
+
+impl<'a> TryFrom<SpotifyId<'a>> for Id<'a, TrackId> {
+    type Error = IdError;
+
+    fn try_from(id: SpotifyId<'a>) -> Result<Self, Self::Error> {
+        match id {
+            SpotifyId::Item(PlayableItem::Track(id)) => Ok(id),
+            other => Err(IdError::WrongItemType(other.item_type())),
+        }
+    }
+}
+
+impl<'a> TryFrom<SpotifyId<'a>> for Id<'a, EpisodeId> {
+    type Error = IdError;
+
+    fn try_from(id: SpotifyId<'a>) -> Result<Self, Self::Error> {
+        match id {
+            SpotifyId::Item(PlayableItem::Episode(id)) => Ok(id),
+            other => Err(IdError::WrongItemType(other.item_type())),
+        }
+    }
+}
+
+impl<'a> TryFrom<SpotifyId<'a>> for Id<'a, ArtistId> {
+    type Error = IdError;
+
+    fn try_from(id: SpotifyId<'a>) -> Result<Self, Self::Error> {
+        match id {
+            SpotifyId::Context(PlayableContext::Artist(id)) => Ok(id),
+            other => Err(IdError::WrongItemType(other.item_type())),
+        }
+    }
+}
+
+impl<'a> TryFrom<SpotifyId<'a>> for Id<'a, AlbumId> {
+    type Error = IdError;
+
+    fn try_from(id: SpotifyId<'a>) -> Result<Self, Self::Error> {
+        match id {
+            SpotifyId::Context(PlayableContext::Album(id)) => Ok(id),
+            other => Err(IdError::WrongItemType(other.item_type())),
+        }
+    }
+}
+
+impl<'a> TryFrom<SpotifyId<'a>> for Id<'a, PlaylistId> {
+    type Error = IdError;
+
+    fn try_from(id: SpotifyId<'a>) -> Result<Self, Self::Error> {
+        match id {
+            SpotifyId::Context(PlayableContext::Playlist(id)) => Ok(id),
+            other => Err(IdError::WrongItemType(other.item_type())),
+        }
+    }
+}
+
+impl<'a> TryFrom<SpotifyId<'a>> for Id<'a, ShowId> {
+    type Error = IdError;
+
+    fn try_from(id: SpotifyId<'a>) -> Result<Self, Self::Error> {
+        match id {
+            SpotifyId::Context(PlayableContext::Show(id)) => Ok(id),
+            other => Err(IdError::WrongItemType(other.item_type())),
+        }
+    }
+}
+
+impl<'a> TryFrom<SpotifyId<'a>> for Id<'a, UserId> {
+    type Error = IdError;
+
+    fn try_from(id: SpotifyId<'a>) -> Result<Self, Self::Error> {
+        match id {
+            SpotifyId::User(id) => Ok(id),
+            other => Err(IdError::WrongItemType(other.item_type())),
+        }
+    }
+}
+
+// the string conversions accept the same inputs as deserializing: Ids may be parsed from URLs, URIs and bare IDs,
+// everything else only from URLs and URIs
+
+impl<T> TryFrom<String> for Id<'static, T>
+where
+    T: ItemTypeId,
+{
+    type Error = IdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.starts_with(URI_PREFIX) || value.starts_with(URL_PREFIX) {
+            Self::from_url_or_uri(value)
+        } else {
+            Self::from_bare(value)
+        }
+    }
+}
+
+impl<T> TryFrom<&str> for Id<'static, T>
+where
+    T: ItemTypeId,
+{
+    type Error = IdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_owned())
+    }
+}
+
+impl TryFrom<String> for SpotifyId<'static> {
+    type Error = IdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_url_or_uri(value)
+    }
+}
+
+impl TryFrom<&str> for SpotifyId<'static> {
+    type Error = IdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_url_or_uri(value.to_owned())
+    }
+}
+
+impl TryFrom<String> for PlayableItem<'static> {
+    type Error = IdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_url_or_uri(value)
+    }
+}
+
+impl TryFrom<&str> for PlayableItem<'static> {
+    type Error = IdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_url_or_uri(value.to_owned())
+    }
+}
+
+impl TryFrom<String> for PlayableContext<'static> {
+    type Error = IdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_url_or_uri(value)
+    }
+}
+
+impl TryFrom<&str> for PlayableContext<'static> {
+    type Error = IdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_url_or_uri(value.to_owned())
+    }
+}
+
 impl<'a> Serialize for SpotifyId<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1226,20 +1535,22 @@ impl<'a> Serialize for PlayableContext<'a> {
             PlayableContext::Album(album_id) => album_id.serialize(serializer),
             PlayableContext::Playlist(playlist_id) => playlist_id.serialize(serializer),
             PlayableContext::Show(show_id) => show_id.serialize(serializer),
-            PlayableContext::Collection(user_id) => user_id.serialize(serializer),
+            PlayableContext::Collection(_) => serializer.serialize_str(&self.as_uri()),
         }
     }
 }
 
 impl<'a, T> Serialize for Id<'a, T>
 where
-    T: ItemTypeId,
+    T: ItemTypeId + 'static,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.value)
+        // always serialize into the canonical URI form so the same ID serializes identically regardless of which form
+        // it was originally parsed from
+        serializer.serialize_str(&self.as_uri())
     }
 }
 
@@ -2244,4 +2555,245 @@ mod tests {
         let id: Id<'static, UserId> = serde_json::from_str("\"https://open.spotify.com/user/1337420asdasd\"").unwrap();
         assert!(matches!(id.as_str(), "1337420asdasd"));
     }
+
+    // =============
+    // serialization
+    // =============
+
+    #[test]
+    fn serialize_id_from_uri_and_url_identically() {
+        let from_uri = Id::<TrackId>::from_uri("spotify:track:2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let from_url = Id::<TrackId>::from_url("https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+
+        let uri_json = serde_json::to_string(&from_uri).unwrap();
+        let url_json = serde_json::to_string(&from_url).unwrap();
+
+        assert_eq!(uri_json, "\"spotify:track:2pDPOMX0kWA7kcPBcDCQBu\"");
+        assert_eq!(uri_json, url_json);
+    }
+
+    #[test]
+    fn serialize_id_from_bare_as_uri() {
+        let id = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, "\"spotify:track:2pDPOMX0kWA7kcPBcDCQBu\"");
+    }
+
+    #[test]
+    fn serialized_playable_context_roundtrips() {
+        let id = PlayableContext::from_url("https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"spotify:album:0tDsHtvN9YNuZjlqHvDY2P\"");
+
+        let deserialized: PlayableContext = serde_json::from_str(&json).unwrap();
+        assert!(matches!(deserialized, PlayableContext::Album(_)));
+        assert_eq!(deserialized.as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+    }
+
+    #[test]
+    fn serialized_collection_roundtrips() {
+        let id = PlayableContext::from_uri("spotify:user:1337420asdasd:collection").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"spotify:user:1337420asdasd:collection\"");
+
+        let deserialized: PlayableContext = serde_json::from_str(&json).unwrap();
+        assert!(matches!(deserialized, PlayableContext::Collection(_)));
+        assert_eq!(deserialized.as_str(), "1337420asdasd");
+    }
+
+    // ===========================
+    // conversions from SpotifyId
+    // ===========================
+
+    #[test]
+    fn track_spotify_id_into_track_id() {
+        let spotify_id = SpotifyId::from_uri("spotify:track:2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let id = Id::<TrackId>::try_from(spotify_id).unwrap();
+
+        assert_eq!(id.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+    }
+
+    #[test]
+    fn track_spotify_id_into_album_id_fails() {
+        let spotify_id = SpotifyId::from_uri("spotify:track:2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let result = Id::<AlbumId>::try_from(spotify_id);
+
+        assert!(matches!(result, Err(IdError::WrongItemType(ItemType::Track))));
+    }
+
+    #[test]
+    fn collection_spotify_id_into_user_id_fails() {
+        let spotify_id = SpotifyId::from_uri("spotify:user:1337420asdasd:collection").unwrap();
+        let result = Id::<UserId>::try_from(spotify_id);
+
+        assert!(matches!(result, Err(IdError::WrongItemType(ItemType::Collection))));
+    }
+
+    // ===========================
+    // equality and hashing
+    // ===========================
+
+    #[test]
+    fn ids_from_uri_url_and_bare_are_equal() {
+        let from_uri = Id::<TrackId>::from_uri("spotify:track:2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let from_url = Id::<TrackId>::from_url("https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        let from_bare = Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+
+        assert_eq!(from_uri, from_url);
+        assert_eq!(from_uri, from_bare);
+        assert_ne!(from_uri, Id::<TrackId>::from_bare("3n3Ppam7vgaVa1iaRUc9Lp").unwrap());
+    }
+
+    #[test]
+    fn spotify_ids_from_uri_and_url_are_equal() {
+        let from_uri = SpotifyId::from_uri("spotify:album:0tGPJ0bkWOUmH7MEOR77qc").unwrap();
+        let from_url = SpotifyId::from_url("https://open.spotify.com/album/0tGPJ0bkWOUmH7MEOR77qc").unwrap();
+
+        assert_eq!(from_uri, from_url);
+        assert_eq!(
+            PlayableItem::from_uri("spotify:episode:2pDPOMX0kWA7kcPBcDCQBu").unwrap(),
+            PlayableItem::from_url("https://open.spotify.com/episode/2pDPOMX0kWA7kcPBcDCQBu").unwrap()
+        );
+    }
+
+    #[test]
+    fn user_and_collection_with_same_id_are_not_equal() {
+        let user = SpotifyId::from_uri("spotify:user:1337420asdasd").unwrap();
+        let collection = SpotifyId::from_uri("spotify:user:1337420asdasd:collection").unwrap();
+
+        assert_ne!(user, collection);
+    }
+
+    #[test]
+    fn ids_from_uri_and_url_hash_equal() {
+        let ids: std::collections::HashSet<_> = SpotifyId::parse_many_ok([
+            "spotify:track:2pDPOMX0kWA7kcPBcDCQBu",
+            "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu",
+            "spotify:album:2pDPOMX0kWA7kcPBcDCQBu",
+        ])
+        .into_iter()
+        .collect();
+
+        assert_eq!(ids.len(), 2);
+    }
+
+    #[test]
+    fn ids_are_ordered_by_bare_id() {
+        let mut ids = [
+            Id::<TrackId>::from_url("https://open.spotify.com/track/3n3Ppam7vgaVa1iaRUc9Lp").unwrap(),
+            Id::<TrackId>::from_bare("2pDPOMX0kWA7kcPBcDCQBu").unwrap(),
+            Id::<TrackId>::from_uri("spotify:track:0tGPJ0bkWOUmH7MEOR77qc").unwrap(),
+        ];
+        ids.sort();
+
+        assert_eq!(
+            ids.iter().map(|id| id.as_str()).collect::<Vec<_>>(),
+            vec!["0tGPJ0bkWOUmH7MEOR77qc", "2pDPOMX0kWA7kcPBcDCQBu", "3n3Ppam7vgaVa1iaRUc9Lp"]
+        );
+    }
+
+    // ===========================
+    // parsing many IDs
+    // ===========================
+
+    #[test]
+    fn parse_many_keeps_errors_in_place() {
+        let results = SpotifyId::parse_many([
+            "spotify:track:2pDPOMX0kWA7kcPBcDCQBu",
+            "spotify:nonsense:2pDPOMX0kWA7kcPBcDCQBu",
+            "https://open.spotify.com/user/1337420asdasd",
+        ]);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert!(matches!(results[1], Err(IdError::InvalidItemType(_))));
+        assert_eq!(results[2].as_ref().unwrap().as_str(), "1337420asdasd");
+    }
+
+    #[test]
+    fn parse_many_ok_skips_malformed_inputs() {
+        let ids = SpotifyId::parse_many_ok([
+            "not an ID".to_owned(),
+            "spotify:track:2pDPOMX0kWA7kcPBcDCQBu".to_owned(),
+            String::new(),
+        ]);
+
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids[0].as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+    }
+
+    // ===========================
+    // string conversions
+    // ===========================
+
+    #[test]
+    fn ids_convert_from_strings() {
+        let id = Id::<TrackId>::try_from("spotify:track:2pDPOMX0kWA7kcPBcDCQBu").unwrap();
+        assert_eq!(id.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+
+        let id = Id::<TrackId>::try_from(String::from("2pDPOMX0kWA7kcPBcDCQBu")).unwrap();
+        assert_eq!(id.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+
+        assert!(matches!(
+            Id::<AlbumId>::try_from("spotify:track:2pDPOMX0kWA7kcPBcDCQBu"),
+            Err(IdError::WrongItemType(ItemType::Track))
+        ));
+
+        let id = SpotifyId::try_from("https://open.spotify.com/album/0tGPJ0bkWOUmH7MEOR77qc").unwrap();
+        assert_eq!(id.item_type(), ItemType::Album);
+
+        let id = PlayableItem::try_from(String::from("spotify:episode:512ojhOuo1ktJprKbVcKyQ")).unwrap();
+        assert!(matches!(id, PlayableItem::Episode(_)));
+
+        let id = PlayableContext::try_from("spotify:playlist:37i9dQZF1DXcBWIGoYBM5M").unwrap();
+        assert!(matches!(id, PlayableContext::Playlist(_)));
+
+        assert!(SpotifyId::try_from("2pDPOMX0kWA7kcPBcDCQBu").is_err());
+    }
+
+    #[test]
+    fn ids_are_usable_with_serde_try_from() {
+        #[derive(Deserialize)]
+        #[serde(try_from = "String")]
+        struct FavouriteTrack(Id<'static, TrackId>);
+
+        impl TryFrom<String> for FavouriteTrack {
+            type Error = IdError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                Id::try_from(value).map(FavouriteTrack)
+            }
+        }
+
+        let track: FavouriteTrack = serde_json::from_str(r#""spotify:track:2pDPOMX0kWA7kcPBcDCQBu""#).unwrap();
+        assert_eq!(track.0.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert!(serde_json::from_str::<FavouriteTrack>(r#""spotify:album:0tGPJ0bkWOUmH7MEOR77qc""#).is_err());
+    }
+
+    // ===========================
+    // bare serialization
+    // ===========================
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct BareTrack {
+        #[serde(with = "bare")]
+        track: Id<'static, TrackId>,
+    }
+
+    #[test]
+    fn bare_ids_are_serialized_and_deserialized() {
+        let track: BareTrack = serde_json::from_str(r#"{ "track": "2pDPOMX0kWA7kcPBcDCQBu" }"#).unwrap();
+        assert_eq!(track.track.as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+
+        assert_eq!(
+            serde_json::to_string(&track).unwrap(),
+            r#"{"track":"2pDPOMX0kWA7kcPBcDCQBu"}"#
+        );
+    }
+
+    #[test]
+    fn bare_deserialization_rejects_uris() {
+        assert!(serde_json::from_str::<BareTrack>(r#"{ "track": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu" }"#).is_err());
+    }
 }
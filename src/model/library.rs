@@ -0,0 +1,550 @@
+//! Contains the items saved in the current user's library ("Your Music").
+//!
+//! - [SavedTrack]: a track saved in the user's library. Retrieved with the [`saved_tracks`-function in
+//!   ScopedClient](crate::client::ScopedClient::saved_tracks).
+//! - [SavedAlbum]: an album saved in the user's library. Retrieved with the [`saved_albums`-function in
+//!   ScopedClient](crate::client::ScopedClient::saved_albums).
+//! - [SavedEpisode]: a podcast episode saved in the user's library. Retrieved with the [`saved_episodes`-function in
+//!   ScopedClient](crate::client::ScopedClient::saved_episodes).
+//! - [SavedShow]: a podcast show saved in the user's library. Retrieved with the [`saved_shows`-function in
+//!   ScopedClient](crate::client::ScopedClient::saved_shows).
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    album::FullAlbum,
+    episode::FullEpisode,
+    page::{Page, PageInformation, PageObject},
+    show::PartialShow,
+    track::FullTrack,
+};
+
+/// A track saved in the current user's library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedTrack {
+    added_at: String, // TODO: this is an ISO 8601 timestamp
+    track: FullTrack,
+}
+
+/// A page of tracks saved in the current user's library.
+///
+/// This object is retrieved only through the [saved_tracks](crate::client::ScopedClient::saved_tracks)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct SavedTracks {
+    #[serde(flatten)]
+    page: PageObject<SavedTrack>,
+}
+
+/// An album saved in the current user's library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedAlbum {
+    added_at: String, // TODO: this is an ISO 8601 timestamp
+    album: FullAlbum,
+}
+
+/// A page of albums saved in the current user's library.
+///
+/// This object is retrieved only through the [saved_albums](crate::client::ScopedClient::saved_albums)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct SavedAlbums {
+    #[serde(flatten)]
+    page: PageObject<SavedAlbum>,
+}
+
+/// A podcast episode saved in the current user's library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedEpisode {
+    added_at: String, // TODO: this is an ISO 8601 timestamp
+    episode: FullEpisode,
+}
+
+/// A page of podcast episodes saved in the current user's library.
+///
+/// This object is retrieved only through the [saved_episodes](crate::client::ScopedClient::saved_episodes)-function.
+/// You won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct SavedEpisodes {
+    #[serde(flatten)]
+    page: PageObject<SavedEpisode>,
+}
+
+/// A podcast show saved in the current user's library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedShow {
+    added_at: String, // TODO: this is an ISO 8601 timestamp
+    show: PartialShow,
+}
+
+/// A page of podcast shows saved in the current user's library.
+///
+/// This object is retrieved only through the [saved_shows](crate::client::ScopedClient::saved_shows)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct SavedShows {
+    #[serde(flatten)]
+    page: PageObject<SavedShow>,
+}
+
+impl SavedTrack {
+    /// When the track was saved in the library.
+    pub fn added_at(&self) -> &str {
+        &self.added_at
+    }
+
+    /// The saved track.
+    pub fn track(&self) -> &FullTrack {
+        &self.track
+    }
+
+    /// Take ownership of the saved track.
+    pub fn take_track(self) -> FullTrack {
+        self.track
+    }
+}
+
+impl SavedAlbum {
+    /// When the album was saved in the library.
+    pub fn added_at(&self) -> &str {
+        &self.added_at
+    }
+
+    /// The saved album.
+    pub fn album(&self) -> &FullAlbum {
+        &self.album
+    }
+
+    /// Take ownership of the saved album.
+    pub fn take_album(self) -> FullAlbum {
+        self.album
+    }
+}
+
+impl SavedEpisode {
+    /// When the episode was saved in the library.
+    pub fn added_at(&self) -> &str {
+        &self.added_at
+    }
+
+    /// The saved episode.
+    pub fn episode(&self) -> &FullEpisode {
+        &self.episode
+    }
+
+    /// Take ownership of the saved episode.
+    pub fn take_episode(self) -> FullEpisode {
+        self.episode
+    }
+}
+
+impl SavedShow {
+    /// When the show was saved in the library.
+    pub fn added_at(&self) -> &str {
+        &self.added_at
+    }
+
+    /// The saved show.
+    pub fn show(&self) -> &PartialShow {
+        &self.show
+    }
+
+    /// Take ownership of the saved show.
+    pub fn take_show(self) -> PartialShow {
+        self.show
+    }
+}
+
+impl From<SavedTracks> for Page<SavedTracks, SavedTrack> {
+    fn from(tracks: SavedTracks) -> Self {
+        Page {
+            inner: tracks,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl From<SavedAlbums> for Page<SavedAlbums, SavedAlbum> {
+    fn from(albums: SavedAlbums) -> Self {
+        Page {
+            inner: albums,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl From<SavedEpisodes> for Page<SavedEpisodes, SavedEpisode> {
+    fn from(episodes: SavedEpisodes) -> Self {
+        Page {
+            inner: episodes,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl From<SavedShows> for Page<SavedShows, SavedShow> {
+    fn from(shows: SavedShows) -> Self {
+        Page {
+            inner: shows,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for SavedTracks {}
+impl crate::private::Sealed for SavedAlbums {}
+impl crate::private::Sealed for SavedEpisodes {}
+impl crate::private::Sealed for SavedShows {}
+
+impl PageInformation<SavedTrack> for SavedTracks {
+    type Items = Vec<SavedTrack>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<SavedTrack> as PageInformation<SavedTrack>>::total(&self.page)
+    }
+}
+
+impl PageInformation<SavedAlbum> for SavedAlbums {
+    type Items = Vec<SavedAlbum>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<SavedAlbum> as PageInformation<SavedAlbum>>::total(&self.page)
+    }
+}
+
+impl PageInformation<SavedEpisode> for SavedEpisodes {
+    type Items = Vec<SavedEpisode>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<SavedEpisode> as PageInformation<SavedEpisode>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<SavedEpisode> as PageInformation<SavedEpisode>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<SavedEpisode> as PageInformation<SavedEpisode>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<SavedEpisode> as PageInformation<SavedEpisode>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<SavedEpisode> as PageInformation<SavedEpisode>>::total(&self.page)
+    }
+}
+
+impl PageInformation<SavedShow> for SavedShows {
+    type Items = Vec<SavedShow>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<SavedShow> as PageInformation<SavedShow>>::total(&self.page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        album::{CommonAlbumInformation, FullAlbumInformation, NonLocalAlbumInformation},
+        episode::{CommonEpisodeInformation, FullEpisodeInformation},
+        id::IdTrait,
+        show::CommonShowInformation,
+        track::{CommonTrackInformation, NonLocalTrackInformation},
+    };
+
+    #[test]
+    fn deserialize_saved_tracks_page() {
+        let json = r#"{
+            "href": "https://api.spotify.com/v1/me/tracks?offset=0&limit=1",
+            "items": [
+                {
+                    "added_at": "2023-03-01T12:34:56Z",
+                    "track": {
+                        "album": {
+                            "album_type": "album",
+                            "artists": [],
+                            "external_urls": {},
+                            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                            "images": [],
+                            "name": "Test album",
+                            "release_date": "2021",
+                            "release_date_precision": "year",
+                            "type": "album"
+                        },
+                        "artists": [],
+                        "disc_number": 1,
+                        "duration_ms": 180000,
+                        "explicit": false,
+                        "external_ids": {},
+                        "external_urls": {},
+                        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                        "is_local": false,
+                        "name": "Test track",
+                        "popularity": 50,
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track"
+                    }
+                }
+            ],
+            "limit": 1,
+            "next": "https://api.spotify.com/v1/me/tracks?offset=1&limit=1",
+            "offset": 0,
+            "previous": null,
+            "total": 120
+        }"#;
+
+        let page: Page<SavedTracks, SavedTrack> = serde_json::from_str::<SavedTracks>(json).unwrap().into();
+        let saved = page.take_items();
+
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].added_at(), "2023-03-01T12:34:56Z");
+        assert_eq!(saved[0].track().id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert_eq!(saved[0].track().name(), "Test track");
+    }
+
+    #[test]
+    fn deserialize_saved_albums_page() {
+        let json = r#"{
+            "href": "https://api.spotify.com/v1/me/albums?offset=0&limit=1",
+            "items": [
+                {
+                    "added_at": "2023-03-02T08:00:00Z",
+                    "album": {
+                        "album_type": "album",
+                        "artists": [],
+                        "copyrights": [],
+                        "external_ids": {
+                            "upc": "123456789012"
+                        },
+                        "external_urls": {},
+                        "genres": [],
+                        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                        "images": [],
+                        "label": "Test label",
+                        "name": "Test album",
+                        "popularity": 50,
+                        "release_date": "2021-05-21",
+                        "release_date_precision": "day",
+                        "total_tracks": 0,
+                        "tracks": {
+                            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=0&limit=50",
+                            "items": [],
+                            "limit": 50,
+                            "next": null,
+                            "offset": 0,
+                            "previous": null,
+                            "total": 0
+                        },
+                        "type": "album"
+                    }
+                }
+            ],
+            "limit": 1,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1
+        }"#;
+
+        let page: Page<SavedAlbums, SavedAlbum> = serde_json::from_str::<SavedAlbums>(json).unwrap().into();
+        let saved = page.take_items();
+
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].added_at(), "2023-03-02T08:00:00Z");
+        assert_eq!(saved[0].album().id().as_str(), "0tDsHtvN9YNuZjlqHvDY2P");
+        assert_eq!(saved[0].album().name(), "Test album");
+        assert_eq!(saved[0].album().label(), "Test label");
+    }
+
+    #[test]
+    fn deserialize_saved_episodes_page() {
+        let json = r#"{
+            "href": "https://api.spotify.com/v1/me/episodes?offset=0&limit=1",
+            "items": [
+                {
+                    "added_at": "2023-03-03T18:00:00Z",
+                    "episode": {
+                        "audio_preview_url": null,
+                        "description": "A test episode.",
+                        "html_description": "<p>A test episode.</p>",
+                        "duration_ms": 1686230,
+                        "explicit": false,
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/episodes/512ojhOuo1ktJprKbVcKyQ",
+                        "id": "512ojhOuo1ktJprKbVcKyQ",
+                        "images": [],
+                        "is_externally_hosted": false,
+                        "is_playable": true,
+                        "languages": ["en"],
+                        "name": "Test episode",
+                        "release_date": "2023-03-01",
+                        "release_date_precision": "day",
+                        "type": "episode",
+                        "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ",
+                        "show": {
+                            "external_urls": {},
+                            "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+                            "id": "38bS44xjbVVZ3No3ByF1dJ",
+                            "description": "A test show.",
+                            "explicit": false,
+                            "images": [],
+                            "media_type": "audio",
+                            "name": "Test show",
+                            "publisher": "Test publisher",
+                            "total_episodes": 1,
+                            "type": "show",
+                            "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+                        }
+                    }
+                }
+            ],
+            "limit": 1,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1
+        }"#;
+
+        let page: Page<SavedEpisodes, SavedEpisode> = serde_json::from_str::<SavedEpisodes>(json).unwrap().into();
+        let saved = page.take_items();
+
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].added_at(), "2023-03-03T18:00:00Z");
+        assert_eq!(saved[0].episode().id().as_str(), "512ojhOuo1ktJprKbVcKyQ");
+        assert_eq!(saved[0].episode().show().name(), "Test show");
+    }
+
+    #[test]
+    fn deserialize_saved_shows_page() {
+        let json = r#"{
+            "href": "https://api.spotify.com/v1/me/shows?offset=0&limit=1",
+            "items": [
+                {
+                    "added_at": "2023-03-04T09:30:00Z",
+                    "show": {
+                        "available_markets": ["FI", "SE"],
+                        "copyrights": [],
+                        "description": "A test show.",
+                        "html_description": "<p>A test show.</p>",
+                        "explicit": false,
+                        "external_urls": {},
+                        "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+                        "id": "38bS44xjbVVZ3No3ByF1dJ",
+                        "images": [],
+                        "is_externally_hosted": null,
+                        "languages": ["en"],
+                        "media_type": "audio",
+                        "name": "Test show",
+                        "publisher": "Test publisher",
+                        "total_episodes": 1,
+                        "type": "show",
+                        "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+                    }
+                }
+            ],
+            "limit": 1,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1
+        }"#;
+
+        let page: Page<SavedShows, SavedShow> = serde_json::from_str::<SavedShows>(json).unwrap().into();
+        let saved = page.take_items();
+
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].added_at(), "2023-03-04T09:30:00Z");
+        assert_eq!(saved[0].show().id().as_str(), "38bS44xjbVVZ3No3ByF1dJ");
+        assert_eq!(saved[0].show().publisher(), "Test publisher");
+    }
+}
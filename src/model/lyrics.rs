@@ -0,0 +1,125 @@
+//! Contains time-synced track lyrics, retrieved with the
+//! [`track_lyrics`-function](crate::client::ScopedClient::track_lyrics) in [ScopedClient](crate::client::ScopedClient).
+//!
+//! The lyrics come from an unofficial, undocumented Spotify endpoint. The object model here covers only the parts of
+//! the response that are required to display the lyrics, and it may break without warning if Spotify changes the
+//! endpoint.
+
+use std::{fmt::Write, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::duration_millis_string;
+
+/// A track's lyrics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Lyrics {
+    sync_type: LyricsSyncType,
+    lines: Vec<LyricsLine>,
+}
+
+/// How the lyrics are synced to the track.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LyricsSyncType {
+    /// The lyrics aren't synced to the track. Each line's start time is zero.
+    Unsynced,
+    /// Each line's start time is known.
+    LineSynced,
+    /// Each syllable's start time is known. Only each line's start time is included in the model.
+    SyllableSynced,
+}
+
+/// A single line in a track's lyrics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LyricsLine {
+    #[serde(rename = "startTimeMs", with = "duration_millis_string")]
+    start_time: Duration,
+    words: String,
+}
+
+impl Lyrics {
+    /// How the lyrics are synced to the track.
+    pub fn sync_type(&self) -> LyricsSyncType {
+        self.sync_type
+    }
+
+    /// The lyrics' lines in order.
+    pub fn lines(&self) -> &[LyricsLine] {
+        &self.lines
+    }
+
+    /// Formats the lyrics in the [LRC format](https://en.wikipedia.org/wiki/LRC_(file_format)). Each line is prefixed
+    /// with its `[mm:ss.xx]` start time, unless the lyrics are [unsynced](LyricsSyncType::Unsynced), in which case the
+    /// lines are returned as-is.
+    pub fn to_lrc(&self) -> String {
+        let mut lrc = String::new();
+
+        for line in &self.lines {
+            if self.sync_type != LyricsSyncType::Unsynced {
+                let centis = line.start_time.as_millis() / 10;
+                let _ = write!(
+                    lrc,
+                    "[{:02}:{:02}.{:02}]",
+                    centis / 6000,
+                    centis / 100 % 60,
+                    centis % 100
+                );
+            }
+
+            lrc.push_str(&line.words);
+            lrc.push('\n');
+        }
+
+        lrc
+    }
+}
+
+impl LyricsLine {
+    /// When the line starts in the track. Zero if the lyrics are [unsynced](LyricsSyncType::Unsynced).
+    pub fn start_time(&self) -> Duration {
+        self.start_time
+    }
+
+    /// The line's words. Instrumental breaks in the lyrics may be represented with a line of a single music note
+    /// (`♪`).
+    pub fn words(&self) -> &str {
+        &self.words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lyrics_are_formatted_as_lrc() {
+        let lyrics: Lyrics = serde_json::from_str(
+            r#"{
+                "syncType": "LINE_SYNCED",
+                "lines": [
+                    { "startTimeMs": "960", "words": "First line", "syllables": [], "endTimeMs": "0" },
+                    { "startTimeMs": "65432", "words": "Second line", "syllables": [], "endTimeMs": "0" }
+                ],
+                "provider": "MusixMatch",
+                "language": "en"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(lyrics.sync_type(), LyricsSyncType::LineSynced);
+        assert_eq!(lyrics.lines()[1].start_time(), Duration::from_millis(65432));
+        assert_eq!(lyrics.to_lrc(), "[00:00.96]First line\n[01:05.43]Second line\n");
+    }
+
+    #[test]
+    fn unsynced_lyrics_are_formatted_without_timestamps() {
+        let lyrics: Lyrics = serde_json::from_str(
+            r#"{ "syncType": "UNSYNCED", "lines": [{ "startTimeMs": "0", "words": "Only line" }] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(lyrics.to_lrc(), "Only line\n");
+    }
+}
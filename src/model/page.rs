@@ -2,11 +2,13 @@
 use std::borrow::Cow;
 use std::{fmt::Debug, marker::PhantomData};
 
+#[cfg(feature = "async")]
+use futures_util::{stream, Stream, TryStreamExt};
 #[cfg(any(feature = "async", feature = "sync"))]
 use log::trace;
 #[cfg(any(feature = "async", feature = "sync"))]
 use reqwest::Method;
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 pub(crate) use self::private::PageObject;
 #[cfg(feature = "async")]
@@ -29,14 +31,11 @@ mod private {
     {
         pub items: Vec<T>,
         pub next: Option<String>,
-
-        // these fields aren't actually needed but keep them around for logging purposes
-        #[allow(dead_code)]
-        limit: usize,
-        #[allow(dead_code)]
-        offset: usize,
-        #[allow(dead_code)]
-        total: usize,
+        #[serde(default)]
+        pub previous: Option<String>,
+        pub limit: usize,
+        pub offset: usize,
+        pub total: usize,
     }
 }
 
@@ -62,6 +61,35 @@ where
 
     /// Returns the URL for the next page from this page, if it exists.
     fn next(self) -> Option<String>;
+
+    /// Returns the URL for the previous page from this page, if it exists.
+    fn previous(self) -> Option<String>;
+
+    /// Returns the offset of this page's first item among all the items.
+    fn offset(&self) -> usize;
+
+    /// Returns the maximum amount of items in this page.
+    fn limit(&self) -> usize;
+
+    /// Returns the total amount of items in every page.
+    fn total(&self) -> usize;
+}
+
+/// A page of items that is paginated with cursors instead of offsets, such as the user's [recently played
+/// tracks](crate::client::ScopedClient::recently_played) and [followed
+/// artists](crate::client::ScopedClient::followed_artists).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CursorPage<T> {
+    items: Vec<T>,
+    next: Option<String>,
+    cursors: Option<Cursors>,
+}
+
+/// The cursors of a [CursorPage].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Cursors {
+    after: Option<String>,
+    before: Option<String>,
 }
 
 /// A page of items.
@@ -122,6 +150,22 @@ where
     fn next(self) -> Option<String> {
         self.next
     }
+
+    fn previous(self) -> Option<String> {
+        self.previous
+    }
+
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn limit(&self) -> usize {
+        self.limit
+    }
+
+    fn total(&self) -> usize {
+        self.total
+    }
 }
 
 impl<TInner, TItem> Page<TInner, TItem>
@@ -138,6 +182,48 @@ where
     pub fn take_items(self) -> TInner::Items {
         self.inner.take_items()
     }
+
+    /// Return the offset of this page's first item among all the items.
+    pub fn offset(&self) -> usize {
+        self.inner.offset()
+    }
+
+    /// Return the maximum amount of items in this page.
+    pub fn limit(&self) -> usize {
+        self.inner.limit()
+    }
+
+    /// Return the total amount of items in every page.
+    pub fn total(&self) -> usize {
+        self.inner.total()
+    }
+}
+
+impl<T> CursorPage<T> {
+    /// Return the items in this page.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Return the items in this page while consuming the page.
+    pub fn take_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Return the cursor pointing to the next page, if it exists. What the cursor is depends on the endpoint:
+    ///
+    /// - For [recently played tracks](crate::client::ScopedClient::recently_played), the cursor is an Unix timestamp in
+    ///   milliseconds pointing to the next page of older tracks. Parse it and give it to the
+    ///   [`before`-function](crate::client::request_builder::RecentlyPlayedRequestBuilder::before).
+    /// - For [followed artists](crate::client::ScopedClient::followed_artists), the cursor is the ID of the last artist
+    ///   in this page. Give it to the
+    ///   [`after`-function](crate::client::request_builder::FollowedArtistsRequestBuilder::after).
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next.as_ref()?;
+
+        let cursors = self.cursors.as_ref()?;
+        cursors.before.as_deref().or(cursors.after.as_deref())
+    }
 }
 
 #[cfg(feature = "async")]
@@ -168,6 +254,105 @@ where
             Ok(None)
         }
     }
+
+    /// Return the previous page from this page, if it exists.
+    pub async fn previous_page_async<C>(self, client: &'_ C) -> crate::error::Result<Option<Page<TInner, TItem>>>
+    where
+        C: crate::client::private::BuildHttpRequestAsync
+            + crate::client::private::AccessTokenExpiryAsync
+            + Clone
+            + Send
+            + Sync,
+    {
+        if let Some(url) = self.inner.previous() {
+            let previous_page = PageRequestBuilder::new(Method::GET, url, client.clone())
+                .send_async()
+                .await?;
+            trace!("Previous page: {previous_page:?}");
+
+            Ok(Some(Page {
+                inner: previous_page,
+                phantom: PhantomData,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Collect the items in this page and every page after it by walking the pages until the last one. If `max` is
+    /// given, stop once that many items have been collected. Rate limits are respected the same way as with any other
+    /// request.
+    pub async fn all_items_async<C>(self, client: &'_ C, max: Option<usize>) -> crate::error::Result<Vec<TItem>>
+    where
+        C: crate::client::private::BuildHttpRequestAsync
+            + crate::client::private::AccessTokenExpiryAsync
+            + Clone
+            + Send
+            + Sync,
+        TItem: Send,
+    {
+        let mut items = Vec::new();
+        let mut page = Some(self);
+
+        while let Some(current) = page {
+            items.extend(current.items());
+
+            if let Some(max) = max.filter(|max| items.len() >= *max) {
+                items.truncate(max);
+                break;
+            }
+
+            page = current.next_page_async(client).await?;
+        }
+
+        Ok(items)
+    }
+
+    /// Turn this page into a stream of the items in this page and every page after it. The following pages are
+    /// requested lazily with the given client as the stream is polled. The stream ends once the last page is exhausted
+    /// or after yielding the first error.
+    ///
+    /// The [`paginate`-function](crate::client::request_builder::AsyncPageRequestBuilder::paginate) in request builders
+    /// that return pages sends the request and turns the first page into a stream in one go.
+    ///
+    /// ```no_run
+    /// # use futures_util::TryStreamExt;
+    /// # use ferrispot::prelude::*;
+    /// # async fn foo(client: ferrispot::client::authorization_code::AsyncAuthorizationCodeUserClient) {
+    /// let playlists: Vec<_> = client
+    ///     .current_user_playlists()
+    ///     .send_async()
+    ///     .await
+    ///     .unwrap()
+    ///     .into_stream(&client)
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn into_stream<C>(self, client: &C) -> impl Stream<Item = crate::error::Result<TItem>> + Send
+    where
+        C: crate::client::private::BuildHttpRequestAsync
+            + crate::client::private::AccessTokenExpiryAsync
+            + Clone
+            + Send
+            + Sync,
+        TItem: Send,
+    {
+        stream::try_unfold((self, client.clone(), true), |(page, client, first)| async move {
+            let page = if first {
+                Some(page)
+            } else {
+                page.next_page_async(&client).await?
+            };
+
+            Ok::<_, crate::error::Error>(page.map(|page| {
+                let items: Vec<_> = page.items().into_iter().map(Ok).collect();
+                (stream::iter(items), (page, client, false))
+            }))
+        })
+        .try_flatten()
+    }
 }
 
 #[cfg(feature = "sync")]
@@ -192,4 +377,46 @@ where
             Ok(None)
         }
     }
+
+    /// Return the previous page from this page, if it exists.
+    pub fn previous_page_sync<C>(self, client: &'_ C) -> crate::error::Result<Option<Page<TInner, TItem>>>
+    where
+        C: crate::client::private::BuildHttpRequestSync + crate::client::private::AccessTokenExpirySync + Clone,
+    {
+        if let Some(url) = self.inner.previous() {
+            let previous_page = PageRequestBuilder::new(Method::GET, url, client.clone()).send_sync()?;
+            trace!("Previous page: {previous_page:?}");
+
+            Ok(Some(Page {
+                inner: previous_page,
+                phantom: PhantomData,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Collect the items in this page and every page after it by walking the pages until the last one. If `max` is
+    /// given, stop once that many items have been collected. Rate limits are respected the same way as with any other
+    /// request.
+    pub fn all_items_sync<C>(self, client: &'_ C, max: Option<usize>) -> crate::error::Result<Vec<TItem>>
+    where
+        C: crate::client::private::BuildHttpRequestSync + crate::client::private::AccessTokenExpirySync + Clone,
+    {
+        let mut items = Vec::new();
+        let mut page = Some(self);
+
+        while let Some(current) = page {
+            items.extend(current.items());
+
+            if let Some(max) = max.filter(|max| items.len() >= *max) {
+                items.truncate(max);
+                break;
+            }
+
+            page = current.next_page_sync(client)?;
+        }
+
+        Ok(items)
+    }
 }
@@ -4,7 +4,13 @@ use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
-use super::{id::PlayableContext, track::FullTrack, ExternalUrls, ItemType};
+use super::{
+    episode::{CommonEpisodeInformation, FullEpisode},
+    id::{EpisodeId, Id, PlayableContext, PlayableItem, TrackId},
+    object_type::{object_type_serialize, TypeEpisode},
+    track::{FullTrack, NonLocalTrackInformation},
+    ExternalUrls, ItemType,
+};
 use crate::{prelude::IdTrait, util::duration_millis};
 
 /// A device in an user's account that may be used for playback.
@@ -18,6 +24,9 @@ pub struct Device {
     is_active: bool,
     is_private_session: bool,
     is_restricted: bool,
+    // assume devices support volume control unless told otherwise, since they always have a volume
+    #[serde(default = "supports_volume_default")]
+    supports_volume: bool,
     #[serde(rename = "type")]
     device_type: DeviceType,
 }
@@ -86,6 +95,15 @@ pub struct Context {
     uri: PlayableContext<'static>,
 }
 
+/// A track the user has recently played. Retrieved with the [`recently_played`-function in
+/// ScopedClient](crate::client::ScopedClient::recently_played).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayHistory {
+    track: FullTrack,
+    played_at: String, // TODO: this is an ISO 8601 timestamp
+    context: Option<Context>,
+}
+
 /// What actions can be taken on the current playing item.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Actions {
@@ -94,26 +112,39 @@ pub struct Actions {
 }
 
 /// Disallowed actions on the current playing item.
+///
+/// Every field is `true` if the action is disallowed, and `false` if it is allowed or Spotify didn't say otherwise. A
+/// player UI may use them to disable its controls for disallowed actions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Disallows {
+    /// Interrupting the playback.
     #[serde(default)]
     pub interrupting_playback: bool,
+    /// Pausing the playback.
     #[serde(default)]
     pub pausing: bool,
+    /// Resuming the playback.
     #[serde(default)]
     pub resuming: bool,
+    /// Seeking to a position in the playing item.
     #[serde(default)]
     pub seeking: bool,
+    /// Skipping to the next item.
     #[serde(default)]
     pub skipping_next: bool,
+    /// Skipping to the previous item.
     #[serde(default)]
     pub skipping_prev: bool,
+    /// Toggling repeating the playback's context.
     #[serde(default)]
     pub toggling_repeat_context: bool,
+    /// Toggling shuffle.
     #[serde(default)]
     pub toggling_shuffle: bool,
+    /// Toggling repeating the playing track.
     #[serde(default)]
     pub toggling_repeat_track: bool,
+    /// Transferring the playback to another device.
     #[serde(default)]
     pub transferring_playback: bool,
 }
@@ -124,12 +155,55 @@ pub struct Disallows {
 #[non_exhaustive]
 pub enum PlayingType {
     Track(FullTrack),
+    /// A podcast episode. Only returned if episodes are requested with the [`additional_types`-function in the request
+    /// builder](crate::client::request_builder::CurrentlyPlayingRequestBuilder::additional_types).
+    Episode(FullEpisode),
     // TODO:
-    // Episode
     // Ad
     // Unknown
 }
 
+/// The item types that may be requested as the currently playing item, in addition to tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurrentlyPlayingType {
+    Track,
+    Episode,
+}
+
+/// The user's playback queue. Retrieved with the [`queue`-function in
+/// ScopedClient](crate::client::ScopedClient::queue).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaybackQueue {
+    currently_playing: Option<QueueItem>,
+    queue: Vec<QueueItem>,
+}
+
+/// An item in the user's [playback queue](PlaybackQueue); a track or an episode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+// most items in a queue are tracks so boxing them wouldn't save anything
+#[allow(clippy::large_enum_variant)]
+pub enum QueueItem {
+    Track(FullTrack),
+    Episode(QueuedEpisode),
+}
+
+/// An episode in the user's [playback queue](PlaybackQueue).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedEpisode {
+    #[serde(with = "crate::model::id::bare")]
+    id: Id<'static, EpisodeId>,
+    name: String,
+    #[serde(rename = "duration_ms", with = "duration_millis")]
+    duration: Duration,
+    #[serde(default)]
+    external_urls: ExternalUrls,
+    #[serde(rename = "type", with = "object_type_serialize")]
+    item_type: TypeEpisode,
+}
+
 /// Possible item repeat states.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -160,6 +234,12 @@ impl Device {
         self.is_active
     }
 
+    /// Whether the device's volume can be controlled. If this is `false`, setting the device's volume won't have an
+    /// effect.
+    pub fn supports_volume(&self) -> bool {
+        self.supports_volume
+    }
+
     /// If this device is currently in a private session.
     pub fn is_private_session(&self) -> bool {
         self.is_private_session
@@ -177,6 +257,10 @@ impl Device {
     }
 }
 
+fn supports_volume_default() -> bool {
+    true
+}
+
 impl PartialEq for Device {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -204,6 +288,18 @@ impl PlaybackState {
         self.shuffle_state
     }
 
+    /// The actions that may be taken on the playback. Shorthand for
+    /// [`currently_playing_item().actions()`](CurrentlyPlayingItem::actions).
+    pub fn actions(&self) -> Actions {
+        self.currently_playing.actions
+    }
+
+    /// The ID of the playback's context (i.e. album, artist, playlist or show). Shorthand for
+    /// [`currently_playing_item().context()`](CurrentlyPlayingItem::context).
+    pub fn context(&self) -> Option<PlayableContext<'_>> {
+        self.currently_playing.context()
+    }
+
     /// The currently playing item.
     pub fn currently_playing_item(&self) -> &CurrentlyPlayingItem {
         &self.currently_playing
@@ -213,6 +309,21 @@ impl PlaybackState {
     pub fn take_currently_playing_item(self) -> CurrentlyPlayingItem {
         self.currently_playing
     }
+
+    /// Returns whether this playback state meaningfully differs from another playback state. A meaningful change is one
+    /// a player UI would usually react to. The states are compared by:
+    ///
+    /// - the Spotify ID of the [playing item](PublicPlayingItem::item), including whether an item is public at all
+    /// - whether the item [is playing](CurrentlyPlayingItem::is_playing) or paused
+    /// - the ID of the [playing device](Self::device)
+    ///
+    /// Any other changes, such as in the playback progress, the device's volume or the repeat and shuffle states, are
+    /// not considered meaningful.
+    pub fn meaningfully_differs_from(&self, other: &PlaybackState) -> bool {
+        self.currently_playing.playing_item_id() != other.currently_playing.playing_item_id()
+            || self.currently_playing.is_playing != other.currently_playing.is_playing
+            || self.device.id != other.device.id
+    }
 }
 
 impl CurrentlyPlayingItem {
@@ -231,6 +342,15 @@ impl CurrentlyPlayingItem {
         self.actions
     }
 
+    /// The ID of the item's playback context (i.e. album, artist, playlist or show). Will return `None` if the item or
+    /// its context isn't publicly available.
+    pub fn context(&self) -> Option<PlayableContext<'_>> {
+        self.public_playing_track
+            .as_ref()
+            .and_then(|item| item.context.as_ref())
+            .map(Context::id)
+    }
+
     /// The currently playing public item.
     pub fn public_playing_item(&self) -> Option<&PublicPlayingItem> {
         self.public_playing_track.as_ref()
@@ -240,6 +360,20 @@ impl CurrentlyPlayingItem {
     pub fn take_public_playing_item(self) -> Option<PublicPlayingItem> {
         self.public_playing_track
     }
+
+    pub(crate) fn playing_track_id(&self) -> Option<Id<'_, TrackId>> {
+        self.public_playing_track.as_ref().and_then(|item| match &item.item {
+            PlayingType::Track(track) => Some(track.id()),
+            PlayingType::Episode(_) => None,
+        })
+    }
+
+    fn playing_item_id(&self) -> Option<PlayableItem<'_>> {
+        self.public_playing_track.as_ref().map(|item| match &item.item {
+            PlayingType::Track(track) => PlayableItem::Track(track.id()),
+            PlayingType::Episode(episode) => PlayableItem::Episode(episode.id()),
+        })
+    }
 }
 
 impl PublicPlayingItem {
@@ -265,6 +399,79 @@ impl PublicPlayingItem {
     }
 }
 
+impl PlaybackQueue {
+    /// The currently playing item, if any.
+    pub fn currently_playing(&self) -> Option<&QueueItem> {
+        self.currently_playing.as_ref()
+    }
+
+    /// The items in the queue, in the order they will be played.
+    pub fn queue(&self) -> &[QueueItem] {
+        &self.queue
+    }
+
+    /// The items in the queue, in the order they will be played. Take ownership of the items.
+    pub fn take_queue(self) -> Vec<QueueItem> {
+        self.queue
+    }
+}
+
+impl QueueItem {
+    /// The item's ID. The ID can be given to, for example, the [`add_to_queue`-function in
+    /// ScopedClient](crate::client::ScopedClient::add_to_queue).
+    pub fn id(&self) -> PlayableItem<'_> {
+        match self {
+            QueueItem::Track(track) => PlayableItem::Track(track.id()),
+            QueueItem::Episode(episode) => PlayableItem::Episode(episode.id()),
+        }
+    }
+}
+
+impl QueuedEpisode {
+    /// The episode's Spotify ID.
+    pub fn id(&self) -> Id<'_, EpisodeId> {
+        self.id.as_borrowed()
+    }
+
+    /// The episode's name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The episode's duration.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The external URLs for the episode.
+    pub fn external_urls(&self) -> &ExternalUrls {
+        &self.external_urls
+    }
+}
+
+impl PlayHistory {
+    /// The played track.
+    pub fn track(&self) -> &FullTrack {
+        &self.track
+    }
+
+    /// Take ownership of the played track.
+    pub fn take_track(self) -> FullTrack {
+        self.track
+    }
+
+    /// When the track was played.
+    pub fn played_at(&self) -> &str {
+        &self.played_at
+    }
+
+    /// The context the track was played from (i.e. album, artist, playlist or show). Will return `None` if the context
+    /// isn't publicly available.
+    pub fn context(&self) -> Option<&Context> {
+        self.context.as_ref()
+    }
+}
+
 impl RepeatState {
     pub fn as_str(self) -> &'static str {
         match self {
@@ -275,12 +482,21 @@ impl RepeatState {
     }
 }
 
+impl CurrentlyPlayingType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CurrentlyPlayingType::Track => "track",
+            CurrentlyPlayingType::Episode => "episode",
+        }
+    }
+}
+
 impl Context {
     pub fn external_urls(&self) -> &ExternalUrls {
         &self.external_urls
     }
 
-    pub fn id(&self) -> PlayableContext {
+    pub fn id(&self) -> PlayableContext<'_> {
         self.uri.as_borrowed()
     }
 }
@@ -289,6 +505,131 @@ impl Context {
 mod tests {
     use super::*;
 
+    #[test]
+    fn deserialize_currently_playing_episode() {
+        let json = serde_json::json!({
+            "timestamp": 1677495200000u64,
+            "is_playing": true,
+            "actions": { "disallows": {} },
+            "context": null,
+            "progress_ms": 60000,
+            "currently_playing_type": "episode",
+            "item": {
+                "audio_preview_url": null,
+                "description": "A test episode.",
+                "html_description": "<p>A test episode.</p>",
+                "duration_ms": 1686230,
+                "explicit": false,
+                "external_urls": {},
+                "id": "512ojhOuo1ktJprKbVcKyQ",
+                "images": [],
+                "is_externally_hosted": false,
+                "languages": ["en"],
+                "name": "Test episode",
+                "release_date": "2023-03-01",
+                "release_date_precision": "day",
+                "type": "episode",
+                "show": {
+                    "external_urls": {},
+                    "id": "38bS44xjbVVZ3No3ByF1dJ",
+                    "description": "A test show.",
+                    "explicit": false,
+                    "images": [],
+                    "media_type": "audio",
+                    "name": "Test show",
+                    "publisher": "Test publisher",
+                    "total_episodes": 1,
+                    "type": "show"
+                }
+            }
+        });
+
+        let item: CurrentlyPlayingItem = serde_json::from_value(json).unwrap();
+        let playing = item.public_playing_item().unwrap();
+
+        match playing.item() {
+            PlayingType::Episode(episode) => assert_eq!(episode.id().as_str(), "512ojhOuo1ktJprKbVcKyQ"),
+            other => panic!("expected an episode, got {other:?}"),
+        }
+        assert_eq!(item.playing_track_id(), None);
+        assert!(matches!(item.playing_item_id(), Some(PlayableItem::Episode(_))));
+    }
+
+    #[test]
+    fn currently_playing_item_context_and_actions() {
+        let json = serde_json::json!({
+            "timestamp": 1677495200000u64,
+            "is_playing": true,
+            "actions": { "disallows": { "pausing": true, "skipping_next": true } },
+            "context": {
+                "external_urls": {},
+                "type": "playlist",
+                "uri": "spotify:playlist:37i9dQZF1DWZipvLjDtZYe"
+            },
+            "progress_ms": 60000,
+            "currently_playing_type": "episode",
+            "item": {
+                "audio_preview_url": null,
+                "description": "A test episode.",
+                "html_description": "<p>A test episode.</p>",
+                "duration_ms": 1686230,
+                "explicit": false,
+                "external_urls": {},
+                "id": "512ojhOuo1ktJprKbVcKyQ",
+                "images": [],
+                "is_externally_hosted": false,
+                "languages": ["en"],
+                "name": "Test episode",
+                "release_date": "2023-03-01",
+                "release_date_precision": "day",
+                "type": "episode",
+                "show": {
+                    "external_urls": {},
+                    "id": "38bS44xjbVVZ3No3ByF1dJ",
+                    "description": "A test show.",
+                    "explicit": false,
+                    "images": [],
+                    "media_type": "audio",
+                    "name": "Test show",
+                    "publisher": "Test publisher",
+                    "total_episodes": 1,
+                    "type": "show"
+                }
+            }
+        });
+
+        let item: CurrentlyPlayingItem = serde_json::from_value(json).unwrap();
+        let context = item.context().unwrap();
+        let disallows = item.actions().disallows;
+
+        assert!(matches!(context, PlayableContext::Playlist(_)));
+        assert_eq!(context.as_str(), "37i9dQZF1DWZipvLjDtZYe");
+        assert!(disallows.pausing);
+        assert!(disallows.skipping_next);
+        assert!(!disallows.resuming);
+        assert!(!disallows.skipping_prev);
+    }
+
+    #[test]
+    fn deserialize_device() {
+        let json = r#"{
+            "id": "0d1841b0976bae2a3a310dd74c0f3df354899bc8",
+            "is_active": true,
+            "is_private_session": false,
+            "is_restricted": false,
+            "name": "Living room",
+            "type": "Speaker",
+            "volume_percent": 100,
+            "supports_volume": false
+        }"#;
+
+        let device: Device = serde_json::from_str(json).unwrap();
+
+        assert!(device.is_active());
+        assert!(!device.supports_volume());
+        assert_eq!(device.device_type(), DeviceType::Speaker);
+    }
+
     #[test]
     fn deserialize_context_for_playlist() {
         let json = r#"{
@@ -322,4 +663,149 @@ mod tests {
         assert!(matches!(context.uri, PlayableContext::Collection(_)));
         assert_eq!("1337420", context.uri.as_str());
     }
+
+    #[test]
+    fn deserialize_recently_played_page() {
+        let json = r#"{
+            "items": [
+                {
+                    "track": {
+                        "album": {
+                            "album_type": "album",
+                            "artists": [],
+                            "external_urls": {},
+                            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                            "images": [],
+                            "name": "Test album",
+                            "release_date": "2021",
+                            "release_date_precision": "year",
+                            "type": "album"
+                        },
+                        "artists": [],
+                        "disc_number": 1,
+                        "duration_ms": 180000,
+                        "explicit": false,
+                        "external_ids": {},
+                        "external_urls": {},
+                        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                        "is_local": false,
+                        "name": "Test track",
+                        "popularity": 50,
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track"
+                    },
+                    "played_at": "2023-03-01T12:00:00.000Z",
+                    "context": {
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/playlist/37i9dQZF1DWZipvLjDtZYe"
+                        },
+                        "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DWZipvLjDtZYe",
+                        "type": "playlist",
+                        "uri": "spotify:playlist:37i9dQZF1DWZipvLjDtZYe"
+                    }
+                }
+            ],
+            "next": "https://api.spotify.com/v1/me/player/recently-played?before=1677672000000&limit=1",
+            "cursors": {
+                "after": "1677672000000",
+                "before": "1677672000000"
+            },
+            "limit": 1,
+            "href": "https://api.spotify.com/v1/me/player/recently-played?limit=1"
+        }"#;
+
+        let page: crate::model::CursorPage<PlayHistory> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(page.next_cursor(), Some("1677672000000"));
+        assert_eq!(page.items()[0].played_at(), "2023-03-01T12:00:00.000Z");
+        assert!(matches!(
+            page.items()[0].context().unwrap().id(),
+            PlayableContext::Playlist(_)
+        ));
+    }
+
+    #[test]
+    fn last_recently_played_page_has_no_next_cursor() {
+        let json = r#"{
+            "items": [],
+            "next": null,
+            "cursors": null,
+            "limit": 20,
+            "href": "https://api.spotify.com/v1/me/player/recently-played"
+        }"#;
+
+        let page: crate::model::CursorPage<PlayHistory> = serde_json::from_str(json).unwrap();
+
+        assert!(page.items().is_empty());
+        assert_eq!(page.next_cursor(), None);
+    }
+
+    #[test]
+    fn deserialize_playback_queue() {
+        let json = r#"{
+            "currently_playing": {
+                "album": {
+                    "album_type": "album",
+                    "artists": [],
+                    "external_urls": {},
+                    "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                    "images": [],
+                    "name": "Test album",
+                    "release_date": "2021",
+                    "release_date_precision": "year",
+                    "type": "album"
+                },
+                "artists": [],
+                "disc_number": 1,
+                "duration_ms": 180000,
+                "explicit": false,
+                "external_ids": {},
+                "external_urls": {},
+                "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                "is_local": false,
+                "name": "Test track",
+                "popularity": 50,
+                "preview_url": null,
+                "track_number": 1,
+                "type": "track"
+            },
+            "queue": [
+                {
+                    "description": "A test episode.",
+                    "duration_ms": 1800000,
+                    "external_urls": {
+                        "spotify": "https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ"
+                    },
+                    "href": "https://api.spotify.com/v1/episodes/512ojhOuo1ktJprKbVcKyQ",
+                    "id": "512ojhOuo1ktJprKbVcKyQ",
+                    "name": "Test episode",
+                    "type": "episode",
+                    "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ"
+                }
+            ]
+        }"#;
+
+        let queue: PlaybackQueue = serde_json::from_str(json).unwrap();
+
+        let Some(QueueItem::Track(track)) = queue.currently_playing() else {
+            panic!("currently playing item is not a track");
+        };
+        assert_eq!(track.id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+
+        let [QueueItem::Episode(episode)] = queue.queue() else {
+            panic!("queue doesn't contain exactly one episode");
+        };
+        assert_eq!(episode.name(), "Test episode");
+        assert_eq!(episode.duration(), Duration::from_secs(1800));
+        assert_eq!(queue.queue()[0].id().as_uri(), "spotify:episode:512ojhOuo1ktJprKbVcKyQ");
+    }
+
+    #[test]
+    fn deserialize_empty_playback_queue() {
+        let queue: PlaybackQueue = serde_json::from_str(r#"{ "currently_playing": null, "queue": [] }"#).unwrap();
+
+        assert!(queue.currently_playing().is_none());
+        assert!(queue.queue().is_empty());
+    }
 }
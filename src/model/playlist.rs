@@ -0,0 +1,767 @@
+//! Everything related to playlists.
+//!
+//! Contains the two different kinds of playlists; [FullPlaylist] and [PartialPlaylist].
+//!
+//! - [FullPlaylist]: may contain all possible information about a playlist, including its tracks. Generally retrieved
+//!   from the [playlist-function](crate::client::UnscopedClient::playlist).
+//! - [PartialPlaylist]: contains most information about a playlist, except its followers and tracks. Generally
+//!   retrieved as part of a response to, for example, a listing of an user's playlists.
+//!
+//! Additionally, there is the [Playlist] enum that encompasses both kinds of playlists.
+//!
+//! The playlist object Spotify returns from the API is not directly available. The two playlist objects, or the
+//! [Playlist] enum, may be serialized to get almost all of the original API response back. The model strips certain
+//! unnecessary or redundant fields from the response.
+//!
+//! # Playlist equality
+//!
+//! Two playlists are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use serde::{Deserialize, Serialize};
+
+    use crate::model::{
+        id::{Id, PlaylistId},
+        object_type::{object_type_serialize, TypePlaylist},
+        playlist::PlaylistTracks,
+        user::{Followers, PublicUser},
+        ExternalUrls, Image,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonPlaylistFields;
+    }
+
+    pub(super) trait FullFields {
+        fn full_fields(&self) -> &FullPlaylistFields;
+    }
+
+    /// This struct covers all the possible playlist responses from Spotify's API. It has a function that converts it
+    /// into a [Playlist], depending on which fields are set.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct PlaylistObject {
+        /// Fields available in every playlist
+        #[serde(flatten)]
+        pub(crate) common: CommonPlaylistFields,
+
+        /// Fields only in full playlists
+        #[serde(flatten)]
+        pub(crate) full: Option<FullPlaylistFields>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonPlaylistFields {
+        // basic information
+        pub(crate) name: String,
+        pub(crate) description: Option<String>,
+        pub(crate) owner: PublicUser,
+        pub(crate) collaborative: bool,
+        pub(crate) public: Option<bool>,
+        pub(crate) snapshot_id: String,
+        #[serde(default)]
+        pub(crate) primary_color: Option<String>,
+        #[serde(with = "crate::model::id::bare")]
+        pub(crate) id: Id<'static, PlaylistId>,
+        // Spotify may return null for playlists that don't have any images
+        #[serde(default, deserialize_with = "crate::util::null_as_default::deserialize")]
+        pub(crate) images: Vec<Image>,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypePlaylist,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct FullPlaylistFields {
+        pub(crate) followers: Followers,
+        pub(crate) tracks: PlaylistTracks,
+    }
+}
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+pub(crate) use self::private::PlaylistObject;
+use self::private::{CommonPlaylistFields, FullPlaylistFields};
+use super::{
+    id::{Id, IdTrait, PlaylistId, UserId},
+    page::{Page, PageInformation, PageObject},
+    track::{Track, TrackObject},
+    user::{CommonUserInformation, Followers, PublicUser},
+    ExternalUrls, Image,
+};
+use crate::error::ConversionError;
+
+/// Functions for retrieving information that is common to every playlist type.
+pub trait CommonPlaylistInformation: crate::private::Sealed {
+    /// The playlist's name.
+    fn name(&self) -> &str;
+    /// The playlist's description, if it has one.
+    fn description(&self) -> Option<&str>;
+    /// The user who owns the playlist.
+    fn owner(&self) -> &PublicUser;
+    /// Whether or not the playlist is collaborative, i.e. other users than the owner may modify the playlist.
+    fn collaborative(&self) -> bool;
+    /// The playlist's public status. `Some(true)` means the playlist is public, `Some(false)` means the playlist is
+    /// private and `None` means the playlist's status is not relevant.
+    fn public(&self) -> Option<bool>;
+    /// The playlist's snapshot ID, i.e. the version identifier of the playlist.
+    fn snapshot_id(&self) -> &str;
+    /// The playlist's primary color as a hex color code, e.g. `#FFFFFF`, if it has one.
+    fn primary_color(&self) -> Option<&str>;
+    /// The playlist's Spotify ID.
+    fn id(&self) -> Id<'_, PlaylistId>;
+    /// The images for the playlist.
+    fn images(&self) -> &[Image];
+    /// The external URLs for the playlist.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The access the given user has to the playlist, based on the playlist's owner and collaborative status.
+    fn access(&self, current_user_id: &Id<'_, UserId>) -> PlaylistAccess;
+    /// Whether or not the given user may edit the playlist, i.e. the user owns the playlist or the playlist is
+    /// collaborative.
+    fn can_current_user_edit(&self, current_user_id: &Id<'_, UserId>) -> bool;
+}
+
+/// Functions for retrieving information only in full playlists.
+pub trait FullPlaylistInformation: crate::private::Sealed {
+    /// Information about the playlist's followers.
+    fn followers(&self) -> Followers;
+    /// The items in the playlist.
+    fn tracks(&self) -> Page<PlaylistTracks, PlaylistItem>;
+}
+
+impl<T> CommonPlaylistInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn description(&self) -> Option<&str> {
+        self.common_fields().description.as_deref()
+    }
+
+    fn owner(&self) -> &PublicUser {
+        &self.common_fields().owner
+    }
+
+    fn collaborative(&self) -> bool {
+        self.common_fields().collaborative
+    }
+
+    fn public(&self) -> Option<bool> {
+        self.common_fields().public
+    }
+
+    fn snapshot_id(&self) -> &str {
+        &self.common_fields().snapshot_id
+    }
+
+    fn primary_color(&self) -> Option<&str> {
+        self.common_fields().primary_color.as_deref()
+    }
+
+    fn id(&self) -> Id<'_, PlaylistId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn images(&self) -> &[Image] {
+        &self.common_fields().images
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn access(&self, current_user_id: &Id<'_, UserId>) -> PlaylistAccess {
+        if self.owner().id().as_str() == current_user_id.as_str() {
+            PlaylistAccess::Owner
+        } else if self.collaborative() {
+            PlaylistAccess::Collaborator
+        } else {
+            PlaylistAccess::ReadOnly
+        }
+    }
+
+    fn can_current_user_edit(&self, current_user_id: &Id<'_, UserId>) -> bool {
+        self.access(current_user_id) != PlaylistAccess::ReadOnly
+    }
+}
+
+impl<T> FullPlaylistInformation for T
+where
+    T: private::FullFields + crate::private::Sealed,
+{
+    fn followers(&self) -> Followers {
+        self.full_fields().followers
+    }
+
+    fn tracks(&self) -> Page<PlaylistTracks, PlaylistItem> {
+        Page {
+            inner: self.full_fields().tracks.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// An enum that encompasses all playlist types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Playlist {
+    Full(Box<FullPlaylist>),
+    Partial(Box<PartialPlaylist>),
+}
+
+/// The access an user has to a playlist.
+///
+/// Note that a public playlist doesn't grant any additional access; only the owner and, for collaborative playlists,
+/// the collaborators may edit a playlist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistAccess {
+    /// The user owns the playlist and may edit it.
+    Owner,
+    /// The user doesn't own the playlist, but may edit it since the playlist is collaborative.
+    Collaborator,
+    /// The user may not edit the playlist.
+    ReadOnly,
+}
+
+/// This struct's only purpose is to make serializing more efficient by holding only references to its data. When
+/// attempting to serialize a playlist object, its fields will be passed as references to this object which is then
+/// serialized. This avoids having to clone the entire playlist in order to reconstruct a PlaylistObject.
+#[derive(Serialize)]
+struct PlaylistObjectRef<'a> {
+    #[serde(flatten)]
+    common: &'a CommonPlaylistFields,
+    #[serde(flatten)]
+    full: Option<&'a FullPlaylistFields>,
+}
+
+/// A full playlist. Contains [full information](self::FullPlaylistInformation), in addition to all
+/// [common](self::CommonPlaylistInformation) information about a playlist.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(try_from = "PlaylistObject")]
+pub struct FullPlaylist {
+    common: CommonPlaylistFields,
+    full: FullPlaylistFields,
+}
+
+/// A partial playlist. Contains all [common](self::CommonPlaylistInformation) information about a playlist.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(try_from = "PlaylistObject")]
+pub struct PartialPlaylist {
+    common: CommonPlaylistFields,
+}
+
+/// An item in a playlist.
+///
+/// Playlist items are usually tracks, but may also be local tracks. The track may also be missing altogether if it is
+/// no longer available.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistItem {
+    added_at: Option<String>, // TODO: this is an ISO 8601 timestamp
+    added_by: Option<PublicUser>,
+    is_local: bool,
+    track: Option<TrackObject>,
+}
+
+/// A page of items in a playlist.
+///
+/// This object is retrieved only through the [tracks](FullPlaylistInformation::tracks)-function. You won't be
+/// interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct PlaylistTracks {
+    #[serde(flatten)]
+    page: PageObject<PlaylistItem>,
+}
+
+/// A page of the current user's playlists.
+///
+/// This object is retrieved only through the
+/// [current_user_playlists](crate::client::ScopedClient::current_user_playlists)-function. You won't be interacting
+/// objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct CurrentUserPlaylists {
+    #[serde(flatten)]
+    page: PageObject<PlaylistObject>,
+}
+
+impl PartialEq for FullPlaylist {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialPlaylist {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialPlaylist> for FullPlaylist {
+    fn eq(&self, other: &PartialPlaylist) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullPlaylist> for PartialPlaylist {
+    fn eq(&self, other: &FullPlaylist) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PlaylistItem {
+    /// When the item was added to the playlist. Very old playlists may not have this information.
+    pub fn added_at(&self) -> Option<&str> {
+        self.added_at.as_deref()
+    }
+
+    /// The user who added the item to the playlist. Very old playlists may not have this information.
+    pub fn added_by(&self) -> Option<&PublicUser> {
+        self.added_by.as_ref()
+    }
+
+    /// Whether or not the item is a local track.
+    pub fn is_local(&self) -> bool {
+        self.is_local
+    }
+
+    /// The track in this item. Returns `None` if the track is no longer available.
+    pub fn track(&self) -> Option<Track> {
+        self.track.clone().and_then(|track| track.try_into().ok())
+    }
+
+    /// The track in this item. Take ownership of the track. Returns `None` if the track is no longer available.
+    pub fn take_track(self) -> Option<Track> {
+        self.track.and_then(|track| track.try_into().ok())
+    }
+}
+
+impl TryFrom<PlaylistObject> for Playlist {
+    type Error = ConversionError;
+
+    fn try_from(obj: PlaylistObject) -> Result<Self, Self::Error> {
+        match obj.full {
+            Some(full) => Ok(Self::Full(Box::new(FullPlaylist {
+                common: obj.common,
+                full,
+            }))),
+
+            None => Ok(Self::Partial(Box::new(PartialPlaylist { common: obj.common }))),
+        }
+    }
+}
+
+impl From<FullPlaylist> for Playlist {
+    fn from(full: FullPlaylist) -> Self {
+        Self::Full(Box::new(full))
+    }
+}
+
+impl From<PartialPlaylist> for Playlist {
+    fn from(partial: PartialPlaylist) -> Self {
+        Self::Partial(Box::new(partial))
+    }
+}
+
+impl TryFrom<Playlist> for FullPlaylist {
+    type Error = ConversionError;
+
+    fn try_from(playlist: Playlist) -> Result<Self, Self::Error> {
+        match playlist {
+            Playlist::Full(full) => Ok(*full),
+
+            Playlist::Partial(_) => Err(ConversionError::new(
+                "attempt to convert partial playlist into full playlist".into(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<PlaylistObject> for FullPlaylist {
+    type Error = ConversionError;
+
+    fn try_from(obj: PlaylistObject) -> Result<Self, Self::Error> {
+        if let Some(full) = obj.full {
+            Ok(FullPlaylist {
+                common: obj.common,
+                full,
+            })
+        } else {
+            Err(ConversionError::new(
+                "attempt to convert partial playlist object into full playlist".into(),
+            ))
+        }
+    }
+}
+
+impl From<Playlist> for PartialPlaylist {
+    fn from(playlist: Playlist) -> Self {
+        match playlist {
+            Playlist::Full(full) => PartialPlaylist { common: full.common },
+            Playlist::Partial(partial) => *partial,
+        }
+    }
+}
+
+impl From<PlaylistObject> for PartialPlaylist {
+    fn from(obj: PlaylistObject) -> Self {
+        PartialPlaylist { common: obj.common }
+    }
+}
+
+impl From<FullPlaylist> for PlaylistObject {
+    fn from(value: FullPlaylist) -> Self {
+        Self {
+            common: value.common,
+            full: Some(value.full),
+        }
+    }
+}
+
+impl From<PartialPlaylist> for PlaylistObject {
+    fn from(value: PartialPlaylist) -> Self {
+        Self {
+            common: value.common,
+            full: None,
+        }
+    }
+}
+
+impl crate::private::Sealed for FullPlaylist {}
+impl crate::private::Sealed for PartialPlaylist {}
+impl crate::private::Sealed for PlaylistTracks {}
+impl crate::private::Sealed for CurrentUserPlaylists {}
+
+impl private::CommonFields for FullPlaylist {
+    fn common_fields(&self) -> &CommonPlaylistFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialPlaylist {
+    fn common_fields(&self) -> &CommonPlaylistFields {
+        &self.common
+    }
+}
+
+impl private::FullFields for FullPlaylist {
+    fn full_fields(&self) -> &FullPlaylistFields {
+        &self.full
+    }
+}
+
+impl PageInformation<PlaylistItem> for PlaylistTracks {
+    type Items = Vec<PlaylistItem>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PlaylistItem> as PageInformation<PlaylistItem>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<PlaylistItem> as PageInformation<PlaylistItem>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PlaylistItem> as PageInformation<PlaylistItem>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PlaylistItem> as PageInformation<PlaylistItem>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PlaylistItem> as PageInformation<PlaylistItem>>::total(&self.page)
+    }
+}
+
+impl PageInformation<PartialPlaylist> for CurrentUserPlaylists {
+    type Items = Vec<PartialPlaylist>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<PlaylistObject> as PageInformation<PartialPlaylist>>::total(&self.page)
+    }
+}
+
+impl From<CurrentUserPlaylists> for Page<CurrentUserPlaylists, PartialPlaylist> {
+    fn from(playlists: CurrentUserPlaylists) -> Self {
+        Page {
+            inner: playlists,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl Serialize for Playlist {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Playlist::Full(full_playlist) => full_playlist.serialize(serializer),
+            Playlist::Partial(partial_playlist) => partial_playlist.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for FullPlaylist {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PlaylistObjectRef {
+            common: &self.common,
+            full: Some(&self.full),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for PartialPlaylist {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PlaylistObjectRef {
+            common: &self.common,
+            full: None,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        id::{IdFromBare, IdFromKnownKind},
+        test_responses::CURRENT_USER_PLAYLISTS_RESPONSE,
+        track::{CommonTrackInformation, NonLocalTrackInformation},
+    };
+
+    const FULL_PLAYLIST: &str = r##"{
+        "collaborative": false,
+        "description": "A playlist for testing.",
+        "external_urls": {
+            "spotify": "https://open.spotify.com/playlist/37i9dQZF1DWZipvLjDtZYe"
+        },
+        "followers": {
+            "href": null,
+            "total": 420
+        },
+        "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DWZipvLjDtZYe",
+        "id": "37i9dQZF1DWZipvLjDtZYe",
+        "images": [],
+        "name": "Test playlist",
+        "owner": {
+            "display_name": "Spotify",
+            "external_urls": {
+                "spotify": "https://open.spotify.com/user/spotify"
+            },
+            "href": "https://api.spotify.com/v1/users/spotify",
+            "id": "spotify",
+            "type": "user",
+            "uri": "spotify:user:spotify"
+        },
+        "primary_color": "#FFFFFF",
+        "public": true,
+        "snapshot_id": "MTY3NzQ5NTIwMCwwMDAwMDAwMGQ0MWQ4Y2Q5OGYwMGIyMDRlOTgwMDk5OGVjZjg0Mjdl",
+        "tracks": {
+            "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DWZipvLjDtZYe/tracks?offset=0&limit=100",
+            "items": [
+                {
+                    "added_at": "2023-02-27T11:00:00Z",
+                    "added_by": {
+                        "external_urls": {
+                            "spotify": "https://open.spotify.com/user/spotify"
+                        },
+                        "href": "https://api.spotify.com/v1/users/spotify",
+                        "id": "spotify",
+                        "type": "user",
+                        "uri": "spotify:user:spotify"
+                    },
+                    "is_local": false,
+                    "track": {
+                        "album": {
+                            "album_type": "album",
+                            "artists": [],
+                            "external_urls": {},
+                            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                            "images": [],
+                            "name": "Test album",
+                            "release_date": "2021",
+                            "release_date_precision": "year",
+                            "type": "album"
+                        },
+                        "artists": [],
+                        "disc_number": 1,
+                        "duration_ms": 180000,
+                        "explicit": false,
+                        "external_ids": {},
+                        "external_urls": {},
+                        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                        "is_local": false,
+                        "name": "Test track",
+                        "popularity": 50,
+                        "preview_url": null,
+                        "track_number": 1,
+                        "type": "track"
+                    }
+                },
+                {
+                    "added_at": "2023-02-27T11:00:00Z",
+                    "added_by": null,
+                    "is_local": false,
+                    "track": null
+                }
+            ],
+            "limit": 100,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 2
+        },
+        "type": "playlist",
+        "uri": "spotify:playlist:37i9dQZF1DWZipvLjDtZYe"
+    }"##;
+
+    #[test]
+    fn deserialize_full_playlist() {
+        let playlist: FullPlaylist = serde_json::from_str(FULL_PLAYLIST).unwrap();
+
+        assert_eq!(playlist.name(), "Test playlist");
+        assert_eq!(playlist.id().as_str(), "37i9dQZF1DWZipvLjDtZYe");
+        assert_eq!(playlist.owner().id().as_str(), "spotify");
+        assert_eq!(playlist.followers().total, 420);
+        assert_eq!(playlist.public(), Some(true));
+        assert!(!playlist.collaborative());
+        assert_eq!(
+            playlist.snapshot_id(),
+            "MTY3NzQ5NTIwMCwwMDAwMDAwMGQ0MWQ4Y2Q5OGYwMGIyMDRlOTgwMDk5OGVjZjg0Mjdl"
+        );
+        assert_eq!(playlist.primary_color(), Some("#FFFFFF"));
+
+        let items = playlist.tracks().take_items();
+        assert_eq!(items.len(), 2);
+
+        let Some(Track::Full(track)) = items[0].track() else {
+            panic!("first playlist item is not a full track");
+        };
+
+        assert_eq!(track.name(), "Test track");
+        assert_eq!(track.id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert!(items[1].track().is_none());
+    }
+
+    #[test]
+    fn deserialize_partial_playlist() {
+        let json = r#"{
+            "collaborative": true,
+            "description": null,
+            "external_urls": {},
+            "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DWZipvLjDtZYe",
+            "id": "37i9dQZF1DWZipvLjDtZYe",
+            "images": null,
+            "name": "Test playlist",
+            "owner": {
+                "display_name": "Spotify",
+                "external_urls": {},
+                "href": "https://api.spotify.com/v1/users/spotify",
+                "id": "spotify",
+                "type": "user",
+                "uri": "spotify:user:spotify"
+            },
+            "primary_color": null,
+            "public": false,
+            "snapshot_id": "MTY3NzQ5NTIwMA==",
+            "tracks": {
+                "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DWZipvLjDtZYe/tracks",
+                "total": 2
+            },
+            "type": "playlist",
+            "uri": "spotify:playlist:37i9dQZF1DWZipvLjDtZYe"
+        }"#;
+
+        let playlist: Playlist = serde_json::from_str::<PlaylistObject>(json).unwrap().try_into().unwrap();
+
+        let Playlist::Partial(playlist) = playlist else {
+            panic!("playlist is not a partial playlist");
+        };
+
+        assert!(playlist.collaborative());
+        assert!(playlist.images().is_empty());
+        assert_eq!(playlist.description(), None);
+        assert_eq!(playlist.snapshot_id(), "MTY3NzQ5NTIwMA==");
+        assert_eq!(playlist.primary_color(), None);
+    }
+
+    /// Returns the current user's playlists; one owned by the user, one the user collaborates in and one the user
+    /// follows.
+    fn current_user_playlists() -> Vec<PartialPlaylist> {
+        serde_json::from_str::<PageObject<PartialPlaylist>>(CURRENT_USER_PLAYLISTS_RESPONSE)
+            .unwrap()
+            .items
+    }
+
+    #[test]
+    fn owned_playlist_is_editable() {
+        let playlist = &current_user_playlists()[0];
+        let user: Id<UserId> = Id::from_bare("testuser").unwrap();
+
+        assert_eq!(playlist.access(&user), PlaylistAccess::Owner);
+        assert!(playlist.can_current_user_edit(&user));
+    }
+
+    #[test]
+    fn collaborative_playlist_not_owned_is_editable() {
+        let playlist = &current_user_playlists()[1];
+        let user: Id<UserId> = Id::from_uri("spotify:user:testuser").unwrap();
+
+        assert_eq!(playlist.access(&user), PlaylistAccess::Collaborator);
+        assert!(playlist.can_current_user_edit(&user));
+    }
+
+    #[test]
+    fn public_playlist_not_owned_is_not_editable() {
+        let playlist = &current_user_playlists()[2];
+        let user: Id<UserId> = Id::from_bare("testuser").unwrap();
+
+        assert_eq!(playlist.access(&user), PlaylistAccess::ReadOnly);
+        assert!(!playlist.can_current_user_edit(&user));
+    }
+}
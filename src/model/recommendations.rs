@@ -0,0 +1,191 @@
+//! Contains the [Recommendations]-object returned by the [recommendations-function in
+//! UnscopedClient](crate::client::UnscopedClient::recommendations).
+
+use serde::{Deserialize, Serialize};
+
+use super::track::FullTrack;
+
+/// Track recommendations generated from a set of seed artists, tracks and genres.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recommendations {
+    #[serde(default, deserialize_with = "crate::util::null_as_default::deserialize")]
+    tracks: Vec<FullTrack>,
+    seeds: Vec<RecommendationSeed>,
+}
+
+/// Information about a single seed used to generate recommendations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationSeed {
+    id: String,
+    #[serde(rename = "type")]
+    seed_type: RecommendationSeedType,
+    initial_pool_size: u32,
+    after_filtering_size: u32,
+    after_relinking_size: u32,
+}
+
+/// A recommendation seed's type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RecommendationSeedType {
+    #[serde(alias = "artist")]
+    Artist,
+    #[serde(alias = "track")]
+    Track,
+    #[serde(alias = "genre")]
+    Genre,
+}
+
+impl Recommendations {
+    /// The recommended tracks.
+    ///
+    /// The tracks may be empty if the tunable attributes filtered out every track. In that case, the
+    /// [seeds](Self::seeds) tell how many tracks each seed had [before](RecommendationSeed::initial_pool_size) and
+    /// [after](RecommendationSeed::after_filtering_size) filtering.
+    pub fn tracks(&self) -> &[FullTrack] {
+        &self.tracks
+    }
+
+    /// Take the recommended tracks.
+    pub fn take_tracks(self) -> Vec<FullTrack> {
+        self.tracks
+    }
+
+    /// The seeds the recommendations were generated from.
+    pub fn seeds(&self) -> &[RecommendationSeed] {
+        &self.seeds
+    }
+}
+
+impl RecommendationSeed {
+    /// The seed's ID. For artist and track seeds, this is the artist's or track's Spotify ID. For genre seeds, this is
+    /// the genre's name.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The seed's type.
+    pub fn seed_type(&self) -> RecommendationSeedType {
+        self.seed_type
+    }
+
+    /// The number of recommended tracks available for this seed.
+    pub fn initial_pool_size(&self) -> u32 {
+        self.initial_pool_size
+    }
+
+    /// The number of tracks available after the tunable attribute filters have been applied.
+    pub fn after_filtering_size(&self) -> u32 {
+        self.after_filtering_size
+    }
+
+    /// The number of tracks available after relinking for regional availability.
+    pub fn after_relinking_size(&self) -> u32 {
+        self.after_relinking_size
+    }
+
+    /// The number of tracks the tunable attribute filters removed from this seed's pool. If this is close to the
+    /// [initial pool size](Self::initial_pool_size), the filters are likely too strict.
+    pub fn filtered_out_size(&self) -> u32 {
+        self.initial_pool_size.saturating_sub(self.after_filtering_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{id::IdTrait, track::NonLocalTrackInformation};
+
+    #[test]
+    fn deserialize_recommendations() {
+        let json = r#"{
+            "seeds": [
+                {
+                    "initialPoolSize": 250,
+                    "afterFilteringSize": 250,
+                    "afterRelinkingSize": 250,
+                    "id": "4NHQUGzhtTLFvgF5SZesLK",
+                    "type": "ARTIST",
+                    "href": "https://api.spotify.com/v1/artists/4NHQUGzhtTLFvgF5SZesLK"
+                },
+                {
+                    "initialPoolSize": 250,
+                    "afterFilteringSize": 120,
+                    "afterRelinkingSize": 118,
+                    "id": "classical",
+                    "type": "GENRE",
+                    "href": null
+                }
+            ],
+            "tracks": [
+                {
+                    "album": {
+                        "album_type": "album",
+                        "artists": [],
+                        "external_urls": {},
+                        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                        "images": [],
+                        "name": "Test album",
+                        "release_date": "2021",
+                        "release_date_precision": "year",
+                        "type": "album"
+                    },
+                    "artists": [],
+                    "disc_number": 1,
+                    "duration_ms": 180000,
+                    "explicit": false,
+                    "external_ids": {},
+                    "external_urls": {},
+                    "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                    "is_local": false,
+                    "name": "Test track",
+                    "popularity": 50,
+                    "preview_url": null,
+                    "track_number": 1,
+                    "type": "track"
+                }
+            ]
+        }"#;
+
+        let recommendations: Recommendations = serde_json::from_str(json).unwrap();
+
+        assert_eq!(recommendations.tracks().len(), 1);
+        assert_eq!(recommendations.tracks()[0].id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+
+        let seeds = recommendations.seeds();
+        assert_eq!(seeds.len(), 2);
+        assert_eq!(seeds[0].seed_type(), RecommendationSeedType::Artist);
+        assert_eq!(seeds[1].seed_type(), RecommendationSeedType::Genre);
+        assert_eq!(seeds[1].id(), "classical");
+        assert_eq!(seeds[1].initial_pool_size(), 250);
+        assert_eq!(seeds[1].after_filtering_size(), 120);
+        assert_eq!(seeds[1].after_relinking_size(), 118);
+    }
+
+    #[test]
+    fn deserialize_recommendations_without_tracks() {
+        let json = r#"{
+            "seeds": [
+                {
+                    "initialPoolSize": 250,
+                    "afterFilteringSize": 0,
+                    "afterRelinkingSize": 0,
+                    "id": "classical",
+                    "type": "GENRE",
+                    "href": null
+                }
+            ],
+            "tracks": null
+        }"#;
+
+        let recommendations: Recommendations = serde_json::from_str(json).unwrap();
+
+        assert!(recommendations.tracks().is_empty());
+
+        let seed = &recommendations.seeds()[0];
+        assert_eq!(seed.initial_pool_size(), 250);
+        assert_eq!(seed.after_filtering_size(), 0);
+        assert_eq!(seed.filtered_out_size(), 250);
+    }
+}
@@ -20,7 +20,7 @@ mod private {
     }
 }
 
-use std::{convert::Infallible, marker::PhantomData};
+use std::marker::PhantomData;
 
 use serde::Deserialize;
 
@@ -48,6 +48,11 @@ pub trait ToTypesString: crate::private::Sealed {
 }
 
 /// First pages of search results from a [search](crate::client::unscoped::UnscopedClient::search).
+///
+/// Each kind of result is returned as its own [Page]. The following pages of results are retrieved with the
+/// [`next_page_async`](Page::next_page_async) or [`next_page_sync`](Page::next_page_sync)-functions in the page, given
+/// the client the search was sent with. Every remaining result may be collected at once with the
+/// [`all_items_async`](Page::all_items_async) or [`all_items_sync`](Page::all_items_sync)-functions.
 #[derive(Debug)]
 pub struct SearchResults {
     pub(crate) inner: SearchResultsObject,
@@ -56,7 +61,7 @@ pub struct SearchResults {
 /// Continuation page of search results from a [search](crate::client::unscoped::UnscopedClient::search) that contains
 /// only tracks.
 ///
-/// This object is retrieved only through requesting the [next page](Page::next_page) from an existing page of results.
+/// This object is retrieved only through the first page of [search results](SearchResults) and the pages after it.
 /// You won't be interacting objects of this type directly.
 #[derive(Debug, Deserialize)]
 #[doc(hidden)]
@@ -67,7 +72,7 @@ pub struct TrackSearchResults {
 /// Continuation page of search results from a [search](crate::client::unscoped::UnscopedClient::search) that contains
 /// only artists.
 ///
-/// This object is retrieved only through requesting the [next page](Page::next_page) from an existing page of results.
+/// This object is retrieved only through the first page of [search results](SearchResults) and the pages after it.
 /// You won't be interacting objects of this type directly.
 #[derive(Debug, Deserialize)]
 #[doc(hidden)]
@@ -78,7 +83,7 @@ pub struct ArtistSearchResults {
 /// Continuation page of search results from a [search](crate::client::unscoped::UnscopedClient::search) that contains
 /// only albums.
 ///
-/// This object is retrieved only through requesting the [next page](Page::next_page) from an existing page of results.
+/// This object is retrieved only through the first page of [search results](SearchResults) and the pages after it.
 /// You won't be interacting objects of this type directly.
 #[derive(Debug, Deserialize)]
 #[doc(hidden)]
@@ -86,11 +91,9 @@ pub struct AlbumSearchResults {
     albums: PageObject<AlbumObject>,
 }
 
-impl TryFrom<SearchResultsObject> for SearchResults {
-    type Error = Infallible;
-
-    fn try_from(value: SearchResultsObject) -> Result<Self, Self::Error> {
-        Ok(Self { inner: value })
+impl From<SearchResultsObject> for SearchResults {
+    fn from(value: SearchResultsObject) -> Self {
+        Self { inner: value }
     }
 }
 
@@ -165,6 +168,22 @@ impl PageInformation<FullTrack> for TrackSearchResults {
     fn next(self) -> Option<String> {
         <PageObject<TrackObject> as PageInformation<FullTrack>>::next(self.tracks)
     }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::previous(self.tracks)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::offset(&self.tracks)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::limit(&self.tracks)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<TrackObject> as PageInformation<FullTrack>>::total(&self.tracks)
+    }
 }
 
 impl PageInformation<FullArtist> for ArtistSearchResults {
@@ -181,6 +200,22 @@ impl PageInformation<FullArtist> for ArtistSearchResults {
     fn next(self) -> Option<String> {
         <PageObject<ArtistObject> as PageInformation<FullArtist>>::next(self.artists)
     }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::previous(self.artists)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::offset(&self.artists)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::limit(&self.artists)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<ArtistObject> as PageInformation<FullArtist>>::total(&self.artists)
+    }
 }
 
 impl PageInformation<FullAlbum> for AlbumSearchResults {
@@ -197,6 +232,22 @@ impl PageInformation<FullAlbum> for AlbumSearchResults {
     fn next(self) -> Option<String> {
         <PageObject<AlbumObject> as PageInformation<FullAlbum>>::next(self.albums)
     }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<AlbumObject> as PageInformation<FullAlbum>>::previous(self.albums)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<FullAlbum>>::offset(&self.albums)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<FullAlbum>>::limit(&self.albums)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<AlbumObject> as PageInformation<FullAlbum>>::total(&self.albums)
+    }
 }
 
 // this is a bit cursed but hey
@@ -0,0 +1,490 @@
+//! Everything related to podcast shows.
+//!
+//! Contains the two different kinds of shows; [FullShow] and [PartialShow].
+//!
+//! - [FullShow]: may contain all possible information about a show, including its episodes. Generally retrieved from
+//!   the [show-function](crate::client::UnscopedClient::show).
+//! - [PartialShow]: contains most information about a show, except its episodes. Generally retrieved from the
+//!   [shows-function](crate::client::UnscopedClient::shows) and as part of a [full episode](super::episode::FullEpisode).
+//!
+//! The show object Spotify returns from the API is not directly available. The two show objects may be serialized to
+//! get almost all of the original API response back. The model strips certain unnecessary or redundant fields from the
+//! response.
+//!
+//! # Show equality
+//!
+//! Two shows are considered equal when their Spotify IDs are the same.
+
+mod private {
+    use std::collections::HashSet;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::model::{
+        id::{Id, ShowId},
+        object_type::{object_type_serialize, TypeShow},
+        show::ShowEpisodes,
+        Copyright, CountryCode, ExternalUrls, Image,
+    };
+
+    pub(super) trait CommonFields {
+        fn common_fields(&self) -> &CommonShowFields;
+    }
+
+    pub(super) trait FullFields {
+        fn full_fields(&self) -> &FullShowFields;
+    }
+
+    /// This struct covers all the possible show responses from Spotify's API. It is converted into either a
+    /// [FullShow](super::FullShow) or a [PartialShow](super::PartialShow), depending on which fields are set.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct ShowObject {
+        /// Fields available in every show
+        #[serde(flatten)]
+        pub(crate) common: CommonShowFields,
+
+        /// Fields only in full shows
+        #[serde(flatten)]
+        pub(crate) full: Option<FullShowFields>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct CommonShowFields {
+        // basic information
+        pub(crate) name: String,
+        pub(crate) publisher: String,
+        pub(crate) description: String,
+        #[serde(default)]
+        pub(crate) html_description: String,
+        pub(crate) media_type: String,
+        pub(crate) total_episodes: u32,
+        pub(crate) explicit: bool,
+        #[serde(default, deserialize_with = "crate::util::null_as_default::deserialize")]
+        pub(crate) is_externally_hosted: bool,
+        #[serde(default)]
+        pub(crate) languages: Vec<String>,
+        #[serde(default)]
+        pub(crate) copyrights: Vec<Copyright>,
+        #[serde(default, deserialize_with = "crate::util::null_as_default::deserialize")]
+        pub(crate) images: Vec<Image>,
+        #[serde(default)]
+        pub(crate) external_urls: ExternalUrls,
+        #[serde(with = "crate::model::id::bare")]
+        pub(crate) id: Id<'static, ShowId>,
+        #[serde(rename = "type", with = "object_type_serialize")]
+        pub(crate) item_type: TypeShow,
+
+        // market availability
+        #[serde(default)]
+        pub(crate) available_markets: HashSet<CountryCode>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub(crate) struct FullShowFields {
+        pub(crate) episodes: ShowEpisodes,
+    }
+}
+
+use std::{collections::HashSet, marker::PhantomData};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+pub(crate) use self::private::ShowObject;
+use self::private::{CommonShowFields, FullShowFields};
+use super::{
+    episode::{EpisodeObject, PartialEpisode},
+    id::{Id, IdTrait, ShowId},
+    page::{Page, PageInformation, PageObject},
+    Copyright, CountryCode, ExternalUrls, Image,
+};
+use crate::error::ConversionError;
+
+/// Functions for retrieving information that is common to every show type.
+pub trait CommonShowInformation: crate::private::Sealed {
+    /// The show's name.
+    fn name(&self) -> &str;
+    /// The show's publisher.
+    fn publisher(&self) -> &str;
+    /// The show's description without HTML tags.
+    fn description(&self) -> &str;
+    /// The show's description, possibly containing HTML tags.
+    fn html_description(&self) -> &str;
+    /// The type of media the show's episodes consist of, for example `audio`.
+    fn media_type(&self) -> &str;
+    /// The total number of episodes in the show.
+    fn total_episodes(&self) -> u32;
+    /// Whether or not the show has explicit content.
+    fn explicit(&self) -> bool;
+    /// Whether or not the show's episodes are hosted outside of Spotify's CDN.
+    fn is_externally_hosted(&self) -> bool;
+    /// The languages used in the show as ISO 639 codes.
+    fn languages(&self) -> &[String];
+    /// The show's copyrights.
+    fn copyrights(&self) -> &[Copyright];
+    /// The cover art for the show in various sizes.
+    fn images(&self) -> &[Image];
+    /// The external URLs for the show.
+    fn external_urls(&self) -> &ExternalUrls;
+    /// The show's Spotify ID.
+    fn id(&self) -> Id<'_, ShowId>;
+    /// The markets the show is available in.
+    fn available_markets(&self) -> &HashSet<CountryCode>;
+}
+
+/// Functions for retrieving information only in full shows.
+pub trait FullShowInformation: crate::private::Sealed {
+    /// The show's episodes.
+    fn episodes(&self) -> Page<ShowEpisodes, PartialEpisode>;
+}
+
+impl<T> CommonShowInformation for T
+where
+    T: private::CommonFields + crate::private::Sealed,
+{
+    fn name(&self) -> &str {
+        &self.common_fields().name
+    }
+
+    fn publisher(&self) -> &str {
+        &self.common_fields().publisher
+    }
+
+    fn description(&self) -> &str {
+        &self.common_fields().description
+    }
+
+    fn html_description(&self) -> &str {
+        &self.common_fields().html_description
+    }
+
+    fn media_type(&self) -> &str {
+        &self.common_fields().media_type
+    }
+
+    fn total_episodes(&self) -> u32 {
+        self.common_fields().total_episodes
+    }
+
+    fn explicit(&self) -> bool {
+        self.common_fields().explicit
+    }
+
+    fn is_externally_hosted(&self) -> bool {
+        self.common_fields().is_externally_hosted
+    }
+
+    fn languages(&self) -> &[String] {
+        &self.common_fields().languages
+    }
+
+    fn copyrights(&self) -> &[Copyright] {
+        &self.common_fields().copyrights
+    }
+
+    fn images(&self) -> &[Image] {
+        &self.common_fields().images
+    }
+
+    fn external_urls(&self) -> &ExternalUrls {
+        &self.common_fields().external_urls
+    }
+
+    fn id(&self) -> Id<'_, ShowId> {
+        self.common_fields().id.as_borrowed()
+    }
+
+    fn available_markets(&self) -> &HashSet<CountryCode> {
+        &self.common_fields().available_markets
+    }
+}
+
+impl<T> FullShowInformation for T
+where
+    T: private::FullFields + crate::private::Sealed,
+{
+    fn episodes(&self) -> Page<ShowEpisodes, PartialEpisode> {
+        Page {
+            inner: self.full_fields().episodes.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// This struct's only purpose is to make serializing more efficient by holding only references to its data. When
+/// attempting to serialize a show object, its fields will be passed as references to this object which is then
+/// serialized. This avoids having to clone the entire show in order to reconstruct a ShowObject.
+#[derive(Serialize)]
+struct ShowObjectRef<'a> {
+    #[serde(flatten)]
+    common: &'a CommonShowFields,
+    #[serde(flatten)]
+    full: Option<&'a FullShowFields>,
+}
+
+/// A full show. Contains [full information](self::FullShowInformation), in addition to all
+/// [common](self::CommonShowInformation) information about a show.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(try_from = "ShowObject")]
+pub struct FullShow {
+    common: CommonShowFields,
+    full: FullShowFields,
+}
+
+/// A partial show. Contains all [common](self::CommonShowInformation) information about a show.
+#[derive(Debug, Clone, Eq, Deserialize)]
+#[serde(from = "ShowObject")]
+pub struct PartialShow {
+    common: CommonShowFields,
+}
+
+/// A page of episodes in a show.
+///
+/// This object is retrieved through the [episodes](FullShowInformation::episodes)-function and the
+/// [show_episodes](crate::client::UnscopedClient::show_episodes)-function. You won't be interacting objects of this
+/// type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct ShowEpisodes {
+    #[serde(flatten)]
+    page: PageObject<EpisodeObject>,
+}
+
+impl PartialEq for FullShow {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq for PartialShow {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<PartialShow> for FullShow {
+    fn eq(&self, other: &PartialShow) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl PartialEq<FullShow> for PartialShow {
+    fn eq(&self, other: &FullShow) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl TryFrom<ShowObject> for FullShow {
+    type Error = ConversionError;
+
+    fn try_from(obj: ShowObject) -> Result<Self, Self::Error> {
+        if let Some(full) = obj.full {
+            Ok(FullShow {
+                common: obj.common,
+                full,
+            })
+        } else {
+            Err(ConversionError::new(
+                "attempt to convert partial show object into full show".into(),
+            ))
+        }
+    }
+}
+
+impl From<ShowObject> for PartialShow {
+    fn from(obj: ShowObject) -> Self {
+        PartialShow { common: obj.common }
+    }
+}
+
+impl From<FullShow> for PartialShow {
+    fn from(full: FullShow) -> Self {
+        PartialShow { common: full.common }
+    }
+}
+
+impl From<FullShow> for ShowObject {
+    fn from(value: FullShow) -> Self {
+        Self {
+            common: value.common,
+            full: Some(value.full),
+        }
+    }
+}
+
+impl From<PartialShow> for ShowObject {
+    fn from(value: PartialShow) -> Self {
+        Self {
+            common: value.common,
+            full: None,
+        }
+    }
+}
+
+impl crate::private::Sealed for FullShow {}
+impl crate::private::Sealed for PartialShow {}
+impl crate::private::Sealed for ShowEpisodes {}
+
+impl private::CommonFields for FullShow {
+    fn common_fields(&self) -> &CommonShowFields {
+        &self.common
+    }
+}
+
+impl private::CommonFields for PartialShow {
+    fn common_fields(&self) -> &CommonShowFields {
+        &self.common
+    }
+}
+
+impl private::FullFields for FullShow {
+    fn full_fields(&self) -> &FullShowFields {
+        &self.full
+    }
+}
+
+impl PageInformation<PartialEpisode> for ShowEpisodes {
+    type Items = Vec<PartialEpisode>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<EpisodeObject> as PageInformation<PartialEpisode>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<EpisodeObject> as PageInformation<PartialEpisode>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<EpisodeObject> as PageInformation<PartialEpisode>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<EpisodeObject> as PageInformation<PartialEpisode>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<EpisodeObject> as PageInformation<PartialEpisode>>::total(&self.page)
+    }
+}
+
+impl From<ShowEpisodes> for Page<ShowEpisodes, PartialEpisode> {
+    fn from(episodes: ShowEpisodes) -> Self {
+        Page {
+            inner: episodes,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl Serialize for FullShow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ShowObjectRef {
+            common: &self.common,
+            full: Some(&self.full),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for PartialShow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ShowObjectRef {
+            common: &self.common,
+            full: None,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::episode::CommonEpisodeInformation;
+
+    const PARTIAL_SHOW: &str = r#"{
+        "available_markets": ["FI", "SE"],
+        "copyrights": [],
+        "description": "A test show.",
+        "html_description": "<p>A test show.</p>",
+        "explicit": false,
+        "external_urls": {
+            "spotify": "https://open.spotify.com/show/38bS44xjbVVZ3No3ByF1dJ"
+        },
+        "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ",
+        "id": "38bS44xjbVVZ3No3ByF1dJ",
+        "images": [],
+        "is_externally_hosted": null,
+        "languages": ["en"],
+        "media_type": "audio",
+        "name": "Test show",
+        "publisher": "Test publisher",
+        "total_episodes": 1,
+        "type": "show",
+        "uri": "spotify:show:38bS44xjbVVZ3No3ByF1dJ"
+    }"#;
+
+    fn full_show() -> serde_json::Value {
+        let mut show: serde_json::Value = serde_json::from_str(PARTIAL_SHOW).unwrap();
+        show["episodes"] = serde_json::json!({
+            "href": "https://api.spotify.com/v1/shows/38bS44xjbVVZ3No3ByF1dJ/episodes?offset=0&limit=50",
+            "items": [{
+                "audio_preview_url": null,
+                "description": "A test episode.",
+                "duration_ms": 1686230,
+                "explicit": false,
+                "external_urls": {},
+                "id": "512ojhOuo1ktJprKbVcKyQ",
+                "images": [],
+                "is_externally_hosted": false,
+                "languages": ["en"],
+                "name": "Test episode",
+                "release_date": "2023-03-01",
+                "release_date_precision": "day",
+                "type": "episode",
+                "uri": "spotify:episode:512ojhOuo1ktJprKbVcKyQ"
+            }],
+            "limit": 50,
+            "next": null,
+            "offset": 0,
+            "previous": null,
+            "total": 1
+        });
+        show
+    }
+
+    #[test]
+    fn deserialize_full_show() {
+        let show: FullShow = serde_json::from_value(full_show()).unwrap();
+
+        assert_eq!(show.name(), "Test show");
+        assert_eq!(show.publisher(), "Test publisher");
+        assert_eq!(show.media_type(), "audio");
+        assert_eq!(show.total_episodes(), 1);
+        assert!(!show.is_externally_hosted());
+
+        let episodes = show.episodes().take_items();
+        assert_eq!(episodes.len(), 1);
+        assert_eq!(episodes[0].name(), "Test episode");
+    }
+
+    #[test]
+    fn partial_show_is_not_full() {
+        let partial: PartialShow = serde_json::from_str(PARTIAL_SHOW).unwrap();
+        assert_eq!(partial.id().as_str(), "38bS44xjbVVZ3No3ByF1dJ");
+
+        assert!(serde_json::from_str::<FullShow>(PARTIAL_SHOW).is_err());
+    }
+}
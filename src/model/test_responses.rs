@@ -0,0 +1,667 @@
+//! Spotify API responses shared by the tests, in the shape Spotify returns them. Not every response is used with every
+//! combination of crate features.
+
+#![allow(dead_code)]
+
+pub(crate) const FULL_TRACK_RESPONSE: &str = r#"{
+    "album": {
+        "album_type": "album",
+        "artists": [
+            {
+                "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                "name": "Band of Horses",
+                "type": "artist",
+                "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+            }
+        ],
+        "available_markets": ["FI", "SE"],
+        "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+        "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+        "images": [
+            { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+        ],
+        "name": "Everything All the Time",
+        "release_date": "2006-03-21",
+        "release_date_precision": "day",
+        "total_tracks": 10,
+        "type": "album",
+        "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+    },
+    "artists": [
+        {
+            "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+            "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+            "id": "0OdUWJ0sBjDrqHygGUXeCF",
+            "name": "Band of Horses",
+            "type": "artist",
+            "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+        }
+    ],
+    "available_markets": ["FI", "SE"],
+    "disc_number": 1,
+    "duration_ms": 275026,
+    "explicit": false,
+    "external_ids": { "isrc": "USSUB0660010" },
+    "external_urls": { "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu" },
+    "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+    "id": "2pDPOMX0kWA7kcPBcDCQBu",
+    "is_local": false,
+    "name": "The Funeral",
+    "popularity": 72,
+    "preview_url": null,
+    "track_number": 9,
+    "type": "track",
+    "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+}"#;
+
+pub(crate) const FULL_ALBUM_RESPONSE: &str = r#"{
+    "album_type": "album",
+    "artists": [
+        {
+            "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+            "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+            "id": "0OdUWJ0sBjDrqHygGUXeCF",
+            "name": "Band of Horses",
+            "type": "artist",
+            "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+        }
+    ],
+    "available_markets": ["FI", "SE"],
+    "copyrights": [
+        { "text": "(C) 2006 Sub Pop Records", "type": "C" },
+        { "text": "(P) 2006 Sub Pop Records", "type": "P" }
+    ],
+    "external_ids": { "upc": "098787069525" },
+    "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+    "genres": [],
+    "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+    "id": "0tDsHtvN9YNuZjlqHvDY2P",
+    "images": [
+        { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+    ],
+    "label": "Sub Pop Records",
+    "name": "Everything All the Time",
+    "popularity": 60,
+    "release_date": "2006-03-21",
+    "release_date_precision": "day",
+    "total_tracks": 1,
+    "tracks": {
+        "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P/tracks?offset=0&limit=50",
+        "items": [
+            {
+                "artists": [
+                    {
+                        "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                        "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                        "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                        "name": "Band of Horses",
+                        "type": "artist",
+                        "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                    }
+                ],
+                "available_markets": ["FI", "SE"],
+                "disc_number": 1,
+                "duration_ms": 275026,
+                "explicit": false,
+                "external_urls": { "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu" },
+                "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+                "id": "2pDPOMX0kWA7kcPBcDCQBu",
+                "is_local": false,
+                "name": "The Funeral",
+                "preview_url": null,
+                "track_number": 1,
+                "type": "track",
+                "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+            }
+        ],
+        "limit": 50,
+        "next": null,
+        "offset": 0,
+        "previous": null,
+        "total": 1
+    },
+    "type": "album",
+    "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+}"#;
+
+pub(crate) const PRIVATE_USER_RESPONSE: &str = r#"{
+    "country": "FI",
+    "display_name": "Test user",
+    "email": "test@example.com",
+    "explicit_content": { "filter_enabled": false, "filter_locked": false },
+    "external_urls": { "spotify": "https://open.spotify.com/user/testuser" },
+    "followers": { "href": null, "total": 12 },
+    "href": "https://api.spotify.com/v1/users/testuser",
+    "id": "testuser",
+    "images": [],
+    "product": "premium",
+    "type": "user",
+    "uri": "spotify:user:testuser"
+}"#;
+
+/// Three tracks from the same album, as returned by the Get Several Tracks endpoint.
+pub(crate) const SEVERAL_TRACKS_RESPONSE: &str = r#"{
+    "tracks": [
+        {
+            "album": {
+                "album_type": "album",
+                "artists": [
+                    {
+                        "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                        "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                        "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                        "name": "Band of Horses",
+                        "type": "artist",
+                        "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                    }
+                ],
+                "available_markets": ["FI", "SE"],
+                "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+                "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                "images": [
+                    { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+                ],
+                "name": "Everything All the Time",
+                "release_date": "2006-03-21",
+                "release_date_precision": "day",
+                "total_tracks": 10,
+                "type": "album",
+                "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "disc_number": 1,
+            "duration_ms": 275026,
+            "explicit": false,
+            "external_ids": { "isrc": "USSUB0660010" },
+            "external_urls": { "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu" },
+            "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+            "id": "2pDPOMX0kWA7kcPBcDCQBu",
+            "is_local": false,
+            "name": "The Funeral",
+            "popularity": 72,
+            "preview_url": null,
+            "track_number": 9,
+            "type": "track",
+            "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+        },
+        {
+            "album": {
+                "album_type": "album",
+                "artists": [
+                    {
+                        "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                        "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                        "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                        "name": "Band of Horses",
+                        "type": "artist",
+                        "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                    }
+                ],
+                "available_markets": ["FI", "SE"],
+                "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+                "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                "images": [
+                    { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+                ],
+                "name": "Everything All the Time",
+                "release_date": "2006-03-21",
+                "release_date_precision": "day",
+                "total_tracks": 10,
+                "type": "album",
+                "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "disc_number": 1,
+            "duration_ms": 236466,
+            "explicit": false,
+            "external_ids": { "isrc": "USSUB0660001" },
+            "external_urls": { "spotify": "https://open.spotify.com/track/0c6xIDDpzE81m2q797ordA" },
+            "href": "https://api.spotify.com/v1/tracks/0c6xIDDpzE81m2q797ordA",
+            "id": "0c6xIDDpzE81m2q797ordA",
+            "is_local": false,
+            "name": "The First Song",
+            "popularity": 48,
+            "preview_url": null,
+            "track_number": 1,
+            "type": "track",
+            "uri": "spotify:track:0c6xIDDpzE81m2q797ordA"
+        },
+        {
+            "album": {
+                "album_type": "album",
+                "artists": [
+                    {
+                        "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                        "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                        "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                        "name": "Band of Horses",
+                        "type": "artist",
+                        "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                    }
+                ],
+                "available_markets": ["FI", "SE"],
+                "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+                "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+                "id": "0tDsHtvN9YNuZjlqHvDY2P",
+                "images": [
+                    { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+                ],
+                "name": "Everything All the Time",
+                "release_date": "2006-03-21",
+                "release_date_precision": "day",
+                "total_tracks": 10,
+                "type": "album",
+                "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+            },
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "disc_number": 1,
+            "duration_ms": 184160,
+            "explicit": false,
+            "external_ids": { "isrc": "USSUB0660002" },
+            "external_urls": { "spotify": "https://open.spotify.com/track/4iV5W9uYEdYUVa79Axb7Rh" },
+            "href": "https://api.spotify.com/v1/tracks/4iV5W9uYEdYUVa79Axb7Rh",
+            "id": "4iV5W9uYEdYUVa79Axb7Rh",
+            "is_local": false,
+            "name": "Wicked Gil",
+            "popularity": 45,
+            "preview_url": null,
+            "track_number": 2,
+            "type": "track",
+            "uri": "spotify:track:4iV5W9uYEdYUVa79Axb7Rh"
+        }
+    ]
+}"#;
+
+/// A track relinked from the track in [FULL_TRACK_RESPONSE], as returned when the track is requested for a market
+/// where the original track isn't available.
+pub(crate) const RELINKED_TRACK_RESPONSE: &str = r#"{
+    "album": {
+        "album_type": "album",
+        "artists": [
+            {
+                "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                "name": "Band of Horses",
+                "type": "artist",
+                "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+            }
+        ],
+        "available_markets": ["FI", "SE"],
+        "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+        "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+        "id": "0tDsHtvN9YNuZjlqHvDY2P",
+        "images": [
+            { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+        ],
+        "name": "Everything All the Time",
+        "release_date": "2006-03-21",
+        "release_date_precision": "day",
+        "total_tracks": 10,
+        "type": "album",
+        "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+    },
+    "artists": [
+        {
+            "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+            "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+            "id": "0OdUWJ0sBjDrqHygGUXeCF",
+            "name": "Band of Horses",
+            "type": "artist",
+            "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+        }
+    ],
+    "available_markets": ["FI", "SE"],
+    "disc_number": 1,
+    "duration_ms": 275026,
+    "explicit": false,
+    "external_ids": { "isrc": "USSUB0660010" },
+    "external_urls": { "spotify": "https://open.spotify.com/track/6kLCHFM39wkFjOuyPGLGeQ" },
+    "href": "https://api.spotify.com/v1/tracks/6kLCHFM39wkFjOuyPGLGeQ",
+    "id": "6kLCHFM39wkFjOuyPGLGeQ",
+    "is_local": false,
+    "is_playable": true,
+    "linked_from": {
+        "external_urls": { "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu" },
+        "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+        "type": "track",
+        "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+    },
+    "name": "The Funeral",
+    "popularity": 72,
+    "preview_url": null,
+    "track_number": 9,
+    "type": "track",
+    "uri": "spotify:track:6kLCHFM39wkFjOuyPGLGeQ"
+}"#;
+
+/// The playback state of a device playing the track in [FULL_TRACK_RESPONSE] from its album.
+pub(crate) const PLAYBACK_STATE_RESPONSE: &str = r#"{
+    "device": {
+        "id": "5fbb3ba6aa454b5534c4ba43a8c7e8e45a63ad0e",
+        "is_active": true,
+        "is_private_session": false,
+        "is_restricted": false,
+        "name": "Living room",
+        "supports_volume": true,
+        "type": "Speaker",
+        "volume_percent": 50
+    },
+    "repeat_state": "off",
+    "shuffle_state": false,
+    "context": {
+        "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+        "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+        "type": "album",
+        "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+    },
+    "timestamp": 1677670000000,
+    "progress_ms": 0,
+    "is_playing": true,
+    "item": {
+        "album": {
+            "album_type": "album",
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+            "images": [
+                { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+            ],
+            "name": "Everything All the Time",
+            "release_date": "2006-03-21",
+            "release_date_precision": "day",
+            "total_tracks": 10,
+            "type": "album",
+            "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+        },
+        "artists": [
+            {
+                "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                "name": "Band of Horses",
+                "type": "artist",
+                "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+            }
+        ],
+        "available_markets": ["FI", "SE"],
+        "disc_number": 1,
+        "duration_ms": 275026,
+        "explicit": false,
+        "external_ids": { "isrc": "USSUB0660010" },
+        "external_urls": { "spotify": "https://open.spotify.com/track/2pDPOMX0kWA7kcPBcDCQBu" },
+        "href": "https://api.spotify.com/v1/tracks/2pDPOMX0kWA7kcPBcDCQBu",
+        "id": "2pDPOMX0kWA7kcPBcDCQBu",
+        "is_local": false,
+        "name": "The Funeral",
+        "popularity": 72,
+        "preview_url": null,
+        "track_number": 9,
+        "type": "track",
+        "uri": "spotify:track:2pDPOMX0kWA7kcPBcDCQBu"
+    },
+    "currently_playing_type": "track",
+    "actions": {
+        "disallows": {
+            "resuming": true
+        }
+    }
+}"#;
+
+/// A page of an artist's albums, with the same album listed twice, a re-release of the album and another album.
+pub(crate) const ARTIST_ALBUMS_RESPONSE: &str = r#"{
+    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF/albums?offset=0&limit=20&include_groups=album",
+    "items": [
+        {
+            "album_group": "album",
+            "album_type": "album",
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "external_urls": { "spotify": "https://open.spotify.com/album/0tDsHtvN9YNuZjlqHvDY2P" },
+            "href": "https://api.spotify.com/v1/albums/0tDsHtvN9YNuZjlqHvDY2P",
+            "id": "0tDsHtvN9YNuZjlqHvDY2P",
+            "images": [
+                { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+            ],
+            "name": "Everything All the Time",
+            "release_date": "2006-03-21",
+            "release_date_precision": "day",
+            "total_tracks": 10,
+            "type": "album",
+            "uri": "spotify:album:0tDsHtvN9YNuZjlqHvDY2P"
+        },
+        {
+            "album_group": "album",
+            "album_type": "album",
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "external_urls": { "spotify": "https://open.spotify.com/album/1tDsHtvN9YNuZjlqHvDY2P" },
+            "href": "https://api.spotify.com/v1/albums/1tDsHtvN9YNuZjlqHvDY2P",
+            "id": "1tDsHtvN9YNuZjlqHvDY2P",
+            "images": [
+                { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+            ],
+            "name": "everything all the time ",
+            "release_date": "2006-03-21",
+            "release_date_precision": "day",
+            "total_tracks": 10,
+            "type": "album",
+            "uri": "spotify:album:1tDsHtvN9YNuZjlqHvDY2P"
+        },
+        {
+            "album_group": "album",
+            "album_type": "album",
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "external_urls": { "spotify": "https://open.spotify.com/album/2tDsHtvN9YNuZjlqHvDY2P" },
+            "href": "https://api.spotify.com/v1/albums/2tDsHtvN9YNuZjlqHvDY2P",
+            "id": "2tDsHtvN9YNuZjlqHvDY2P",
+            "images": [
+                { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+            ],
+            "name": "Everything All the Time",
+            "release_date": "2021-05-21",
+            "release_date_precision": "day",
+            "total_tracks": 10,
+            "type": "album",
+            "uri": "spotify:album:2tDsHtvN9YNuZjlqHvDY2P"
+        },
+        {
+            "album_group": "album",
+            "album_type": "album",
+            "artists": [
+                {
+                    "external_urls": { "spotify": "https://open.spotify.com/artist/0OdUWJ0sBjDrqHygGUXeCF" },
+                    "href": "https://api.spotify.com/v1/artists/0OdUWJ0sBjDrqHygGUXeCF",
+                    "id": "0OdUWJ0sBjDrqHygGUXeCF",
+                    "name": "Band of Horses",
+                    "type": "artist",
+                    "uri": "spotify:artist:0OdUWJ0sBjDrqHygGUXeCF"
+                }
+            ],
+            "available_markets": ["FI", "SE"],
+            "external_urls": { "spotify": "https://open.spotify.com/album/3tDsHtvN9YNuZjlqHvDY2P" },
+            "href": "https://api.spotify.com/v1/albums/3tDsHtvN9YNuZjlqHvDY2P",
+            "id": "3tDsHtvN9YNuZjlqHvDY2P",
+            "images": [
+                { "height": 640, "url": "https://i.scdn.co/image/ab67616d0000b273", "width": 640 }
+            ],
+            "name": "Cease to Begin",
+            "release_date": "2006-03-21",
+            "release_date_precision": "day",
+            "total_tracks": 10,
+            "type": "album",
+            "uri": "spotify:album:3tDsHtvN9YNuZjlqHvDY2P"
+        }
+    ],
+    "limit": 20,
+    "next": null,
+    "offset": 0,
+    "previous": null,
+    "total": 4
+}"#;
+
+/// A page of the current user's playlists; one owned by the user, one the user collaborates in and one the user
+/// follows. The current user's ID is `testuser`.
+pub(crate) const CURRENT_USER_PLAYLISTS_RESPONSE: &str = r#"{
+    "href": "https://api.spotify.com/v1/me/playlists?offset=0&limit=20",
+    "items": [
+        {
+            "collaborative": false,
+            "description": "",
+            "external_urls": { "spotify": "https://open.spotify.com/playlist/37i9dQZF1DWZipvLjDtZYe" },
+            "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DWZipvLjDtZYe",
+            "id": "37i9dQZF1DWZipvLjDtZYe",
+            "images": [],
+            "name": "Private playlist",
+            "owner": {
+                "display_name": "Test user",
+                "external_urls": { "spotify": "https://open.spotify.com/user/testuser" },
+                "href": "https://api.spotify.com/v1/users/testuser",
+                "id": "testuser",
+                "type": "user",
+                "uri": "spotify:user:testuser"
+            },
+            "primary_color": null,
+            "public": false,
+            "snapshot_id": "MTY3NzQ5NTIwMA==",
+            "tracks": {
+                "href": "https://api.spotify.com/v1/playlists/37i9dQZF1DWZipvLjDtZYe/tracks",
+                "total": 10
+            },
+            "type": "playlist",
+            "uri": "spotify:playlist:37i9dQZF1DWZipvLjDtZYe"
+        },
+        {
+            "collaborative": true,
+            "description": "",
+            "external_urls": { "spotify": "https://open.spotify.com/playlist/1XhVM7jWPrGLTiNiAy97Za" },
+            "href": "https://api.spotify.com/v1/playlists/1XhVM7jWPrGLTiNiAy97Za",
+            "id": "1XhVM7jWPrGLTiNiAy97Za",
+            "images": [],
+            "name": "Collaborative playlist",
+            "owner": {
+                "display_name": "Other user",
+                "external_urls": { "spotify": "https://open.spotify.com/user/otheruser" },
+                "href": "https://api.spotify.com/v1/users/otheruser",
+                "id": "otheruser",
+                "type": "user",
+                "uri": "spotify:user:otheruser"
+            },
+            "primary_color": null,
+            "public": false,
+            "snapshot_id": "MTY3NzQ5NTIwMA==",
+            "tracks": {
+                "href": "https://api.spotify.com/v1/playlists/1XhVM7jWPrGLTiNiAy97Za/tracks",
+                "total": 10
+            },
+            "type": "playlist",
+            "uri": "spotify:playlist:1XhVM7jWPrGLTiNiAy97Za"
+        },
+        {
+            "collaborative": false,
+            "description": "",
+            "external_urls": { "spotify": "https://open.spotify.com/playlist/6Fyr1rZPRs4T6zgGQJ5kXE" },
+            "href": "https://api.spotify.com/v1/playlists/6Fyr1rZPRs4T6zgGQJ5kXE",
+            "id": "6Fyr1rZPRs4T6zgGQJ5kXE",
+            "images": [],
+            "name": "Followed playlist",
+            "owner": {
+                "display_name": "Other user",
+                "external_urls": { "spotify": "https://open.spotify.com/user/otheruser" },
+                "href": "https://api.spotify.com/v1/users/otheruser",
+                "id": "otheruser",
+                "type": "user",
+                "uri": "spotify:user:otheruser"
+            },
+            "primary_color": null,
+            "public": true,
+            "snapshot_id": "MTY3NzQ5NTIwMA==",
+            "tracks": {
+                "href": "https://api.spotify.com/v1/playlists/6Fyr1rZPRs4T6zgGQJ5kXE/tracks",
+                "total": 10
+            },
+            "type": "playlist",
+            "uri": "spotify:playlist:6Fyr1rZPRs4T6zgGQJ5kXE"
+        }
+    ],
+    "limit": 20,
+    "next": null,
+    "offset": 0,
+    "previous": null,
+    "total": 3
+}"#;
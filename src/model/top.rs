@@ -0,0 +1,202 @@
+//! Contains the current user's top artists and tracks, retrieved with the
+//! [`top_artists`](crate::client::ScopedClient::top_artists) and
+//! [`top_tracks`](crate::client::ScopedClient::top_tracks) functions in [ScopedClient](crate::client::ScopedClient).
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    artist::FullArtist,
+    page::{Page, PageInformation, PageObject},
+    track::FullTrack,
+};
+
+/// The time frame the user's top items are calculated over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeRange {
+    /// Approximately the last 4 weeks.
+    ShortTerm,
+    /// Approximately the last 6 months.
+    MediumTerm,
+    /// Calculated from several years of data, including all new data as it becomes available.
+    LongTerm,
+}
+
+/// A page of the current user's top artists.
+///
+/// This object is retrieved only through the [top_artists](crate::client::ScopedClient::top_artists)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct TopArtists {
+    #[serde(flatten)]
+    page: PageObject<FullArtist>,
+}
+
+/// A page of the current user's top tracks.
+///
+/// This object is retrieved only through the [top_tracks](crate::client::ScopedClient::top_tracks)-function. You
+/// won't be interacting objects of this type directly.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[doc(hidden)]
+pub struct TopTracks {
+    #[serde(flatten)]
+    page: PageObject<FullTrack>,
+}
+
+impl TimeRange {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeRange::ShortTerm => "short_term",
+            TimeRange::MediumTerm => "medium_term",
+            TimeRange::LongTerm => "long_term",
+        }
+    }
+}
+
+impl fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<TopArtists> for Page<TopArtists, FullArtist> {
+    fn from(artists: TopArtists) -> Self {
+        Page {
+            inner: artists,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl From<TopTracks> for Page<TopTracks, FullTrack> {
+    fn from(tracks: TopTracks) -> Self {
+        Page {
+            inner: tracks,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl crate::private::Sealed for TopArtists {}
+impl crate::private::Sealed for TopTracks {}
+
+impl PageInformation<FullArtist> for TopArtists {
+    type Items = Vec<FullArtist>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<FullArtist> as PageInformation<FullArtist>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<FullArtist> as PageInformation<FullArtist>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<FullArtist> as PageInformation<FullArtist>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<FullArtist> as PageInformation<FullArtist>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<FullArtist> as PageInformation<FullArtist>>::total(&self.page)
+    }
+}
+
+impl PageInformation<FullTrack> for TopTracks {
+    type Items = Vec<FullTrack>;
+
+    fn items(&self) -> Self::Items {
+        self.page.items()
+    }
+
+    fn take_items(self) -> Self::Items {
+        self.page.take_items()
+    }
+
+    fn next(self) -> Option<String> {
+        <PageObject<FullTrack> as PageInformation<FullTrack>>::next(self.page)
+    }
+
+    fn previous(self) -> Option<String> {
+        <PageObject<FullTrack> as PageInformation<FullTrack>>::previous(self.page)
+    }
+
+    fn offset(&self) -> usize {
+        <PageObject<FullTrack> as PageInformation<FullTrack>>::offset(&self.page)
+    }
+
+    fn limit(&self) -> usize {
+        <PageObject<FullTrack> as PageInformation<FullTrack>>::limit(&self.page)
+    }
+
+    fn total(&self) -> usize {
+        <PageObject<FullTrack> as PageInformation<FullTrack>>::total(&self.page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        artist::{CommonArtistInformation, NonLocalArtistInformation},
+        id::IdTrait,
+    };
+
+    #[test]
+    fn time_range_serializes_to_snake_case() {
+        assert_eq!(serde_json::to_string(&TimeRange::ShortTerm).unwrap(), r#""short_term""#);
+        assert_eq!(TimeRange::MediumTerm.to_string(), "medium_term");
+        assert_eq!(TimeRange::LongTerm.as_str(), "long_term");
+    }
+
+    #[test]
+    fn deserialize_top_artists_page() {
+        let json = r#"{
+            "href": "https://api.spotify.com/v1/me/top/artists?offset=0&limit=1",
+            "items": [
+                {
+                    "external_urls": {
+                        "spotify": "https://open.spotify.com/artist/0TnOYISbd1XYRBk9myaseg"
+                    },
+                    "followers": {
+                        "href": null,
+                        "total": 1337
+                    },
+                    "genres": ["dance pop", "pop"],
+                    "href": "https://api.spotify.com/v1/artists/0TnOYISbd1XYRBk9myaseg",
+                    "id": "0TnOYISbd1XYRBk9myaseg",
+                    "images": [],
+                    "name": "Test artist",
+                    "popularity": 80,
+                    "type": "artist",
+                    "uri": "spotify:artist:0TnOYISbd1XYRBk9myaseg"
+                }
+            ],
+            "limit": 1,
+            "next": "https://api.spotify.com/v1/me/top/artists?offset=1&limit=1",
+            "offset": 0,
+            "previous": null,
+            "total": 50
+        }"#;
+
+        let page: Page<TopArtists, FullArtist> = serde_json::from_str::<TopArtists>(json).unwrap().into();
+        let artists = page.take_items();
+
+        assert_eq!(artists.len(), 1);
+        assert_eq!(artists[0].id().as_str(), "0TnOYISbd1XYRBk9myaseg");
+        assert_eq!(artists[0].name(), "Test artist");
+    }
+}
@@ -4,8 +4,10 @@
 //!
 //! - [FullTrack]: may contain all possible information about a track. Generally retrieved from the
 //!   [track-](crate::client::UnscopedClient::track) and [tracks-functions](crate::client::UnscopedClient::tracks).
-//! - [PartialTrack]: contains most information about a track. Generally retrieved as part of a response to, for
-//!   example, an album listing (TODO: make a link to the album endpoint once it exists).
+//! - [PartialTrack]: contains most information about a track, but not the album the track is in. Generally retrieved as
+//!   part of a response to, for example, an album listing (TODO: make a link to the album endpoint once it exists).
+//!   The track's album may be retrieved with the [`album_async`](PartialTrack::album_async) and
+//!   [`album_sync`](PartialTrack::album_sync) functions, which fetch the full track.
 //! - [LocalTrack]: contains only the basic information about a track. Only retrieved through a playlist that contains
 //!   local tracks.
 //!
@@ -115,6 +117,7 @@ mod private {
 
     #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
     pub(crate) struct NonLocalTrackFields {
+        #[serde(with = "crate::model::id::bare")]
         pub(crate) id: Id<'static, TrackId>,
     }
 }
@@ -125,13 +128,17 @@ use serde::{Deserialize, Serialize, Serializer};
 
 pub(crate) use self::private::TrackObject;
 use self::private::{CommonTrackFields, FullTrackFields, NonLocalTrackFields};
+#[cfg(feature = "async")]
+use crate::client::request_builder::AsyncRequestBuilder;
+#[cfg(feature = "sync")]
+use crate::client::request_builder::SyncRequestBuilder;
 use crate::{
     error::ConversionError,
     model::{
         album::PartialAlbum,
         artist::PartialArtist,
         country_code::CountryCode,
-        id::{Id, IdTrait, TrackId},
+        id::{Id, IdTrait, PlayableItem, TrackId},
         ExternalIds, ExternalUrls, Restrictions,
     },
 };
@@ -178,10 +185,12 @@ pub trait FullTrackInformation: crate::private::Sealed {
 
 /// Functions for retrieving information that is available in non-local tracks.
 pub trait NonLocalTrackInformation: crate::private::Sealed {
-    // TODO: tracks should have a method for getting their "original" ID, such that it takes track relinking into
-    // account
     /// The track's Spotify ID.
     fn id(&self) -> Id<'_, TrackId>;
+    /// The track's original Spotify ID, taking [track relinking](crate::model::track#track-equality-and-track-relinking) into account.
+    /// If the track is [linked from](CommonTrackInformation::linked_from) another track, this is the ID of that track.
+    /// Otherwise, this is the track's own [ID](Self::id).
+    fn original_id(&self) -> Id<'_, TrackId>;
 }
 
 /// Trait for comparing tracks by their IDs while taking possible track relinking into account.
@@ -194,13 +203,7 @@ pub trait RelinkedTrackEquality: crate::private::Sealed + CommonTrackInformation
     where
         T: RelinkedTrackEquality,
     {
-        self.linked_from()
-            .map(|linked_track| linked_track.id.as_borrowed())
-            .unwrap_or(self.id())
-            == other
-                .linked_from()
-                .map(|linked_track| linked_track.id.as_borrowed())
-                .unwrap_or(other.id())
+        self.original_id() == other.original_id()
     }
 }
 
@@ -276,11 +279,18 @@ where
 
 impl<T> NonLocalTrackInformation for T
 where
-    T: private::NonLocalFields + crate::private::Sealed,
+    T: private::CommonFields + private::NonLocalFields + crate::private::Sealed,
 {
     fn id(&self) -> Id<'_, TrackId> {
         self.non_local_fields().id.as_borrowed()
     }
+
+    fn original_id(&self) -> Id<'_, TrackId> {
+        match &self.common_fields().linked_from {
+            Some(linked_track) => linked_track.id.as_borrowed(),
+            None => self.id(),
+        }
+    }
 }
 
 impl<T> RelinkedTrackEquality for T where T: CommonTrackInformation + NonLocalTrackInformation {}
@@ -318,6 +328,11 @@ pub struct FullTrack {
 
 /// A partial track. Contains all [common](self::CommonTrackInformation) and [non-local](self::NonLocalTrackInformation)
 /// information about a track.
+///
+/// A partial track doesn't contain the album the track is in. The album is available only through a [FullTrack], which
+/// may be retrieved with the [track-function](crate::client::UnscopedClient::track). As a shortcut, the
+/// [`album_async`](Self::album_async) and [`album_sync`](Self::album_sync) functions fetch the full track and return
+/// its album.
 #[derive(Debug, Clone, Eq, Deserialize)]
 #[serde(try_from = "TrackObject")]
 pub struct PartialTrack {
@@ -338,9 +353,40 @@ pub struct LocalTrack {
 pub struct LinkedTrack {
     #[serde(default)]
     pub external_urls: ExternalUrls,
+    #[serde(with = "crate::model::id::bare")]
     pub id: Id<'static, TrackId>,
 }
 
+impl PartialTrack {
+    /// Return the album this track is in. The album isn't included in partial tracks, so the full track is fetched
+    /// from Spotify with the given client.
+    #[cfg(feature = "async")]
+    pub async fn album_async<C>(&self, client: &C) -> crate::error::Result<PartialAlbum>
+    where
+        C: crate::client::UnscopedClient
+            + crate::client::private::BuildHttpRequestAsync
+            + crate::client::private::AccessTokenExpiryAsync
+            + Send
+            + Sync,
+    {
+        let track = client.track(self.id()).send_async().await?;
+        Ok(track.album().clone())
+    }
+
+    /// Return the album this track is in. The album isn't included in partial tracks, so the full track is fetched
+    /// from Spotify with the given client.
+    #[cfg(feature = "sync")]
+    pub fn album_sync<C>(&self, client: &C) -> crate::error::Result<PartialAlbum>
+    where
+        C: crate::client::UnscopedClient
+            + crate::client::private::BuildHttpRequestSync
+            + crate::client::private::AccessTokenExpirySync,
+    {
+        let track = client.track(self.id()).send_sync()?;
+        Ok(track.album().clone())
+    }
+}
+
 impl PartialEq for FullTrack {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id()
@@ -407,7 +453,7 @@ impl TryFrom<TrackObject> for Track {
 
             (None, None) => Ok(Self::Local(Box::new(LocalTrack { common: obj.common }))),
 
-            (non_local, full) => Err(ConversionError(
+            (non_local, full) => Err(ConversionError::new(
                 format!(
                     "impossible case trying to convert TrackObject into Track: non-local track fields is \
                      {non_local:?} while full track fields is {full:?}"
@@ -443,11 +489,13 @@ impl TryFrom<Track> for FullTrack {
         match track {
             Track::Full(full) => Ok(*full),
 
-            Track::Partial(_) => Err(ConversionError(
+            Track::Partial(_) => Err(ConversionError::new(
                 "attempt to convert partial track into full track".into(),
             )),
 
-            Track::Local(_) => Err(ConversionError("attempt to convert local track into full track".into())),
+            Track::Local(_) => Err(ConversionError::new(
+                "attempt to convert local track into full track".into(),
+            )),
         }
     }
 }
@@ -463,7 +511,7 @@ impl TryFrom<TrackObject> for FullTrack {
                 full,
             }),
 
-            (non_local, full) => Err(ConversionError(
+            (non_local, full) => Err(ConversionError::new(
                 format!(
                     "attempt to convert non-full track object into full track (non-local track fields is \
                      {non_local:?}, full track fields is {full:?})"
@@ -486,7 +534,7 @@ impl TryFrom<Track> for PartialTrack {
 
             Track::Partial(partial) => Ok(*partial),
 
-            Track::Local(_) => Err(ConversionError(
+            Track::Local(_) => Err(ConversionError::new(
                 "attempt to convert local track into partial track".into(),
             )),
         }
@@ -503,7 +551,7 @@ impl TryFrom<TrackObject> for PartialTrack {
                 non_local,
             })
         } else {
-            Err(ConversionError(
+            Err(ConversionError::new(
                 format!(
                     "attempt to convert local track object into partial track (non-local track fields is {:?})",
                     obj.non_local
@@ -560,6 +608,18 @@ impl From<LocalTrack> for TrackObject {
     }
 }
 
+impl<'a> From<&'a FullTrack> for PlayableItem<'a> {
+    fn from(track: &'a FullTrack) -> Self {
+        PlayableItem::Track(track.id())
+    }
+}
+
+impl<'a> From<&'a PartialTrack> for PlayableItem<'a> {
+    fn from(track: &'a PartialTrack) -> Self {
+        PlayableItem::Track(track.id())
+    }
+}
+
 impl crate::private::Sealed for FullTrack {}
 impl crate::private::Sealed for PartialTrack {}
 impl crate::private::Sealed for LocalTrack {}
@@ -657,3 +717,36 @@ impl Serialize for LocalTrack {
 
 // TODO: unit tests for all the various functions here. deserializing, serializing, equality between tracks, conversion
 // between tracks
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        album::{FullAlbum, FullAlbumInformation},
+        id::IdTrait,
+        test_responses::{FULL_ALBUM_RESPONSE, FULL_TRACK_RESPONSE, RELINKED_TRACK_RESPONSE},
+    };
+
+    #[test]
+    fn original_id_takes_relinking_into_account() {
+        let track: FullTrack = serde_json::from_str(FULL_TRACK_RESPONSE).unwrap();
+        let relinked: FullTrack = serde_json::from_str(RELINKED_TRACK_RESPONSE).unwrap();
+
+        assert_eq!(track.original_id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert_eq!(relinked.id().as_str(), "6kLCHFM39wkFjOuyPGLGeQ");
+        assert_eq!(relinked.original_id().as_str(), "2pDPOMX0kWA7kcPBcDCQBu");
+        assert!(relinked.compare_possible_relinked(track));
+    }
+
+    #[test]
+    fn tracks_convert_into_playable_items() {
+        let album: FullAlbum = serde_json::from_str(FULL_ALBUM_RESPONSE).unwrap();
+        let partial_track = &album.tracks().items()[0];
+        let relinked: FullTrack = serde_json::from_str(RELINKED_TRACK_RESPONSE).unwrap();
+
+        let items = [PlayableItem::from(partial_track), PlayableItem::from(&relinked)];
+
+        assert!(matches!(&items[0], PlayableItem::Track(id) if id.as_str() == "2pDPOMX0kWA7kcPBcDCQBu"));
+        assert!(matches!(&items[1], PlayableItem::Track(id) if id.as_str() == "6kLCHFM39wkFjOuyPGLGeQ"));
+    }
+}
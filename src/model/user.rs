@@ -21,7 +21,7 @@
 mod private {
     use serde::{Deserialize, Serialize};
 
-    use super::{ExplicitContent, Followers};
+    use super::{ExplicitContent, Followers, SubscriptionLevel};
     use crate::model::{
         id::{Id, UserId},
         object_type::{object_type_serialize, TypeUser},
@@ -62,7 +62,10 @@ mod private {
         pub(crate) display_name: Option<String>,
         #[serde(default)]
         pub(crate) external_urls: ExternalUrls,
+        // users embedded in other objects (such as a playlist's owner) don't include their followers
+        #[serde(default)]
         pub(crate) followers: Followers,
+        #[serde(with = "crate::model::id::bare")]
         pub(crate) id: Id<'static, UserId>,
         #[serde(default)]
         pub(crate) images: Vec<Image>,
@@ -80,14 +83,13 @@ mod private {
     pub(crate) struct PrivateUserFields {
         pub(crate) country: CountryCode,
         pub(crate) explicit_content: ExplicitContent,
-        // TODO: this should really be an enum, but I don't know what all the variants could be. there's at least
-        // "premium", "free" aka "open", but there's also something like "family" maybe? "duo", "student"? even
-        // more?
-        pub(crate) product: String,
+        pub(crate) product: SubscriptionLevel,
     }
 }
 
-use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use self::private::{CommonUserFields, CurrentUserFields, PrivateUserFields, UserObject};
 use super::{
@@ -96,8 +98,8 @@ use super::{
 };
 use crate::{error::ConversionError, prelude::IdTrait};
 
-/// Information about a user's followers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Information about a user's, an artist's or a playlist's followers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Followers {
     // the API documents a href parameter but says it's always null, so it's not included here
     pub total: u32,
@@ -112,6 +114,94 @@ pub struct ExplicitContent {
     pub filter_locked: bool,
 }
 
+/// A user's Spotify subscription level.
+///
+/// Spotify doesn't document every possible subscription level, so any level not known here is deserialized into
+/// [Unknown](Self::Unknown) with the original value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SubscriptionLevel {
+    Premium,
+    Free,
+    /// The older name for the free subscription level.
+    Open,
+    Duo,
+    Family,
+    Student,
+    Unknown(String),
+}
+
+impl Followers {
+    /// Returns new follower information with the given total number of followers.
+    pub fn new(total: u32) -> Self {
+        Self { total }
+    }
+}
+
+impl SubscriptionLevel {
+    /// Returns the subscription level as it is returned by Spotify.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SubscriptionLevel::Premium => "premium",
+            SubscriptionLevel::Free => "free",
+            SubscriptionLevel::Open => "open",
+            SubscriptionLevel::Duo => "duo",
+            SubscriptionLevel::Family => "family",
+            SubscriptionLevel::Student => "student",
+            SubscriptionLevel::Unknown(level) => level,
+        }
+    }
+
+    /// Returns whether this is a free subscription level.
+    pub fn is_free(&self) -> bool {
+        matches!(self, SubscriptionLevel::Free | SubscriptionLevel::Open)
+    }
+}
+
+impl From<String> for SubscriptionLevel {
+    fn from(level: String) -> Self {
+        match level.as_str() {
+            "premium" => SubscriptionLevel::Premium,
+            "free" => SubscriptionLevel::Free,
+            "open" => SubscriptionLevel::Open,
+            "duo" => SubscriptionLevel::Duo,
+            "family" => SubscriptionLevel::Family,
+            "student" => SubscriptionLevel::Student,
+
+            _ => SubscriptionLevel::Unknown(level),
+        }
+    }
+}
+
+impl From<&str> for SubscriptionLevel {
+    fn from(level: &str) -> Self {
+        Self::from(level.to_owned())
+    }
+}
+
+impl fmt::Display for SubscriptionLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SubscriptionLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
 /// Functions for retrieving information that is common to every user type.
 pub trait CommonUserInformation: crate::private::Sealed {
     /// The user's display name if available.
@@ -139,7 +229,9 @@ pub trait PrivateUserInformation: crate::private::Sealed {
     /// The user's explicit content settings.
     fn explicit_content(&self) -> ExplicitContent;
     /// The user's subscription level.
-    fn product(&self) -> &str;
+    fn product(&self) -> SubscriptionLevel;
+    /// The user's subscription level as it was returned by Spotify.
+    fn product_str(&self) -> &str;
 }
 
 impl<T> CommonUserInformation for T
@@ -188,8 +280,12 @@ where
         self.private_fields().explicit_content
     }
 
-    fn product(&self) -> &str {
-        &self.private_fields().product
+    fn product(&self) -> SubscriptionLevel {
+        self.private_fields().product.clone()
+    }
+
+    fn product_str(&self) -> &str {
+        self.private_fields().product.as_str()
     }
 }
 
@@ -314,7 +410,7 @@ impl TryFrom<UserObject> for User {
 
             (None, None) => Ok(Self::Public(PublicUser { common: obj.common })),
 
-            (current, private) => Err(ConversionError(
+            (current, private) => Err(ConversionError::new(
                 format!(
                     "impossible case trying to convert UserObject into User: current user fields is {current:?} while \
                      private user fields is {private:?}"
@@ -350,11 +446,11 @@ impl TryFrom<User> for PrivateUser {
         match user {
             User::Private(private) => Ok(private),
 
-            User::Current(_) => Err(ConversionError(
+            User::Current(_) => Err(ConversionError::new(
                 "attempt to convert current user into private user".into(),
             )),
 
-            User::Public(_) => Err(ConversionError(
+            User::Public(_) => Err(ConversionError::new(
                 "attempt to convert public user into private user".into(),
             )),
         }
@@ -372,7 +468,7 @@ impl TryFrom<UserObject> for PrivateUser {
                 private,
             }),
 
-            (current, private) => Err(ConversionError(
+            (current, private) => Err(ConversionError::new(
                 format!(
                     "attempt to convert non-private user object into private user (current user fields is \
                      {current:?}, private user fields is {private:?})"
@@ -395,7 +491,7 @@ impl TryFrom<User> for CurrentUser {
 
             User::Current(current) => Ok(current),
 
-            User::Public(_) => Err(ConversionError(
+            User::Public(_) => Err(ConversionError::new(
                 "attempt to convert public user into current user".into(),
             )),
         }
@@ -412,7 +508,7 @@ impl TryFrom<UserObject> for CurrentUser {
                 current,
             })
         } else {
-            Err(ConversionError(
+            Err(ConversionError::new(
                 "attempt to convert public user object into current user".into(),
             ))
         }
@@ -562,3 +658,73 @@ impl Serialize for PublicUser {
 
 // TODO: unit tests for all the various functions here. deserializing, serializing, equality between users, conversion
 // between users
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_public_user() {
+        let json = r#"{
+            "display_name": "Test user",
+            "external_urls": {
+                "spotify": "https://open.spotify.com/user/testuser"
+            },
+            "followers": {
+                "href": null,
+                "total": 42
+            },
+            "href": "https://api.spotify.com/v1/users/testuser",
+            "id": "testuser",
+            "images": [
+                {
+                    "height": 300,
+                    "url": "https://i.scdn.co/image/ab6775700000ee85",
+                    "width": 300
+                }
+            ],
+            "type": "user",
+            "uri": "spotify:user:testuser"
+        }"#;
+
+        let user: PublicUser = serde_json::from_str(json).unwrap();
+
+        assert_eq!(user.id().as_str(), "testuser");
+        assert_eq!(user.display_name(), Some("Test user"));
+        assert_eq!(user.followers().total, 42);
+        assert_eq!(user.images().len(), 1);
+        assert_eq!(
+            user.external_urls().spotify.as_deref(),
+            Some("https://open.spotify.com/user/testuser")
+        );
+    }
+
+    #[test]
+    fn subscription_levels_are_deserialized() {
+        let levels: Vec<SubscriptionLevel> =
+            serde_json::from_str(r#"["premium", "free", "open", "duo", "family", "student", "basic-desktop"]"#)
+                .unwrap();
+
+        assert_eq!(
+            levels,
+            [
+                SubscriptionLevel::Premium,
+                SubscriptionLevel::Free,
+                SubscriptionLevel::Open,
+                SubscriptionLevel::Duo,
+                SubscriptionLevel::Family,
+                SubscriptionLevel::Student,
+                SubscriptionLevel::Unknown(String::from("basic-desktop")),
+            ]
+        );
+        assert!(levels[1].is_free() && levels[2].is_free());
+    }
+
+    #[test]
+    fn unknown_subscription_level_is_serialized_as_is() {
+        let level = SubscriptionLevel::from("basic-desktop");
+
+        assert_eq!(serde_json::to_string(&level).unwrap(), r#""basic-desktop""#);
+        assert_eq!(level.as_str(), "basic-desktop");
+    }
+}
@@ -1,6 +1,8 @@
 //! Contains the [Scope]-enum that represents an OAuth authorization scope and various utilities surrounding it.
 
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::ScopeParseError;
 
 /// Trait for converting an object to a scopes string. This is currently implemented for all iterators of
 /// [Scope's](Scope).
@@ -85,6 +87,50 @@ impl Display for Scope {
     }
 }
 
+impl FromStr for Scope {
+    type Err = ScopeParseError;
+
+    /// Parses a single scope from its string representation, such as `user-read-playback-state`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ugc-image-upload" => Ok(Scope::UgcImageUpload),
+            "user-modify-playback-state" => Ok(Scope::UserModifyPlaybackState),
+            "user-read-playback-state" => Ok(Scope::UserReadPlaybackState),
+            "user-read-currently-playing" => Ok(Scope::UserReadCurrentlyPlaying),
+            "user-follow-modify" => Ok(Scope::UserFollowModify),
+            "user-follow-read" => Ok(Scope::UserFollowRead),
+            "user-read-recently-played" => Ok(Scope::UserReadRecentlyPlayed),
+            "user-read-playback-position" => Ok(Scope::UserReadPlaybackPosition),
+            "user-top-read" => Ok(Scope::UserTopRead),
+            "playlist-read-collaborative" => Ok(Scope::PlaylistReadCollaborative),
+            "playlist-modify-public" => Ok(Scope::PlaylistModifyPublic),
+            "playlist-read-private" => Ok(Scope::PlaylistReadPrivate),
+            "playlist-modify-private" => Ok(Scope::PlaylistModifyPrivate),
+            "app-remote-control" => Ok(Scope::AppRemoteControl),
+            "streaming" => Ok(Scope::Streaming),
+            "user-read-email" => Ok(Scope::UserReadEmail),
+            "user-read-private" => Ok(Scope::UserReadPrivate),
+            "user-library-modify" => Ok(Scope::UserLibraryModify),
+            "user-library-read" => Ok(Scope::UserLibraryRead),
+            other => Err(ScopeParseError(other.to_owned())),
+        }
+    }
+}
+
+/// Parses a space-separated scopes string, such as the one Spotify returns in a token response or one created with
+/// [ToScopesString], into the scopes it contains. Unknown scopes and duplicates are skipped.
+pub fn parse_scopes(scopes: &str) -> Vec<Scope> {
+    let mut parsed = Vec::new();
+
+    for scope in scopes.split_whitespace().filter_map(|scope| scope.parse().ok()) {
+        if !parsed.contains(&scope) {
+            parsed.push(scope);
+        }
+    }
+
+    parsed
+}
+
 impl<I> ToScopesString for I
 where
     I: IntoIterator<Item = Scope>,
@@ -96,3 +142,35 @@ where
             .join(" ")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_scopes, Scope, ToScopesString};
+
+    #[test]
+    fn scopes_string_round_trips() {
+        let scopes = vec![
+            Scope::UserReadPlaybackState,
+            Scope::UserModifyPlaybackState,
+            Scope::UgcImageUpload,
+        ];
+
+        assert_eq!(parse_scopes(&scopes.clone().to_scopes_string()), scopes);
+    }
+
+    #[test]
+    fn parsing_scopes_string_skips_unknown_and_duplicate_scopes() {
+        let scopes = parse_scopes("user-read-email  some-future-scope user-read-email streaming");
+
+        assert_eq!(scopes, vec![Scope::UserReadEmail, Scope::Streaming]);
+    }
+
+    #[test]
+    fn scope_is_parsed_from_str() {
+        assert_eq!("user-top-read".parse::<Scope>().unwrap(), Scope::UserTopRead);
+        assert_eq!(
+            "some-future-scope".parse::<Scope>().unwrap_err().to_string(),
+            "Unknown scope: some-future-scope"
+        );
+    }
+}
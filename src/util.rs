@@ -1,2 +1,5 @@
 pub(crate) mod duration_millis;
+#[cfg(feature = "lyrics")]
+pub(crate) mod duration_millis_string;
 pub(crate) mod maybe_split_once;
+pub(crate) mod null_as_default;
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+#[allow(dead_code)]
+pub(crate) fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&duration.as_millis().to_string())
+}
+
+#[allow(dead_code)]
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let millis = String::deserialize(deserializer)?;
+    millis.parse().map(Duration::from_millis).map_err(D::Error::custom)
+}
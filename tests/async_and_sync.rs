@@ -0,0 +1,168 @@
+//! Tests using asynchronous and synchronous clients side by side, which requires both the `async` and `sync` crate
+//! features:
+//!
+//! ```sh
+//! cargo test --features sync
+//! ```
+//!
+//! The clients with a secret are pointed to a local server which serves the Spotify responses, so the tests don't
+//! require Spotify application credentials.
+
+#![cfg(all(feature = "async", feature = "sync"))]
+
+mod support;
+
+use ferrispot::{
+    client::{AsyncSpotifyClient, SpotifyClientBuilder, SyncSpotifyClient},
+    model::id::Id,
+    prelude::*,
+    scope::Scope,
+};
+use support::{request_line, serve_json_responses};
+
+const TRACK_ID: &str = "11dFghVXANMlKmJXsNCbNl";
+
+const TOKEN_RESPONSE: &str = r#"{"access_token":"access token","token_type":"Bearer","expires_in":3600}"#;
+
+// from https://developer.spotify.com/documentation/web-api/reference/get-track, with the markets trimmed
+const TRACK_RESPONSE: &str = r#"{
+  "album": {
+    "album_type": "album",
+    "artists": [
+      {
+        "external_urls": {
+          "spotify": "https://open.spotify.com/artist/6sFIWsNpZYqfjUpaCgueju"
+        },
+        "href": "https://api.spotify.com/v1/artists/6sFIWsNpZYqfjUpaCgueju",
+        "id": "6sFIWsNpZYqfjUpaCgueju",
+        "name": "Carly Rae Jepsen",
+        "type": "artist",
+        "uri": "spotify:artist:6sFIWsNpZYqfjUpaCgueju"
+      }
+    ],
+    "available_markets": ["FI", "SE", "US"],
+    "external_urls": {
+      "spotify": "https://open.spotify.com/album/0tGPJ0bkWOUmH7MEOR77qc"
+    },
+    "href": "https://api.spotify.com/v1/albums/0tGPJ0bkWOUmH7MEOR77qc",
+    "id": "0tGPJ0bkWOUmH7MEOR77qc",
+    "images": [
+      {
+        "height": 640,
+        "url": "https://i.scdn.co/image/ab67616d0000b2737359994525d219f64872d3b1",
+        "width": 640
+      }
+    ],
+    "name": "Cut To The Feeling",
+    "release_date": "2017-05-26",
+    "release_date_precision": "day",
+    "total_tracks": 1,
+    "type": "album",
+    "uri": "spotify:album:0tGPJ0bkWOUmH7MEOR77qc"
+  },
+  "artists": [
+    {
+      "external_urls": {
+        "spotify": "https://open.spotify.com/artist/6sFIWsNpZYqfjUpaCgueju"
+      },
+      "href": "https://api.spotify.com/v1/artists/6sFIWsNpZYqfjUpaCgueju",
+      "id": "6sFIWsNpZYqfjUpaCgueju",
+      "name": "Carly Rae Jepsen",
+      "type": "artist",
+      "uri": "spotify:artist:6sFIWsNpZYqfjUpaCgueju"
+    }
+  ],
+  "available_markets": ["FI", "SE", "US"],
+  "disc_number": 1,
+  "duration_ms": 207959,
+  "explicit": false,
+  "external_ids": {
+    "isrc": "USUM71703861"
+  },
+  "external_urls": {
+    "spotify": "https://open.spotify.com/track/11dFghVXANMlKmJXsNCbNl"
+  },
+  "href": "https://api.spotify.com/v1/tracks/11dFghVXANMlKmJXsNCbNl",
+  "id": "11dFghVXANMlKmJXsNCbNl",
+  "is_local": false,
+  "name": "Cut To The Feeling",
+  "popularity": 63,
+  "preview_url": "https://p.scdn.co/mp3-preview/3eb16018c2a700240e9dfb8817b6f2d041f15eb1",
+  "track_number": 1,
+  "type": "track",
+  "uri": "spotify:track:11dFghVXANMlKmJXsNCbNl"
+}"#;
+
+#[test]
+fn async_and_sync_clients_from_same_builder() {
+    let builder = SpotifyClientBuilder::new("application client ID");
+    let async_client: AsyncSpotifyClient = builder.clone().build_async();
+    let sync_client: SyncSpotifyClient = builder.build_sync();
+
+    let async_authorize_url = async_client
+        .authorization_code_client_with_pkce("http://localhost/callback")
+        .scopes([Scope::UserReadPlaybackState])
+        .build()
+        .get_authorize_url();
+
+    let sync_authorize_url = sync_client
+        .authorization_code_client_with_pkce("http://localhost/callback")
+        .scopes([Scope::UserReadPlaybackState])
+        .build()
+        .get_authorize_url();
+
+    for url in [async_authorize_url, sync_authorize_url] {
+        assert!(url.starts_with("https://accounts.spotify.com/authorize?"));
+        assert!(url.contains("client_id=application+client+ID"));
+    }
+}
+
+#[test]
+fn async_and_sync_clients_with_secret_in_same_binary() {
+    let (url, requests) = serve_json_responses(&[TOKEN_RESPONSE, TRACK_RESPONSE, TOKEN_RESPONSE, TRACK_RESPONSE]);
+    let builder = SpotifyClientBuilder::new("application client ID")
+        .client_secret("application client secret")
+        .api_base_url(format!("{url}v1"))
+        .accounts_base_url(format!("{url}accounts/"));
+
+    // the blocking client may not be used from within an async runtime, so use it before starting one
+    let sync_client = builder
+        .clone()
+        .build_sync()
+        .expect("failed to build synchronous Spotify client");
+    let sync_track = sync_client
+        .track(Id::from_bare(TRACK_ID).unwrap())
+        .send_sync()
+        .expect("failed to get track synchronously");
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let async_track = runtime.block_on(async {
+        let async_client = builder
+            .build_async()
+            .await
+            .expect("failed to build asynchronous Spotify client");
+
+        async_client
+            .track(Id::from_bare(TRACK_ID).unwrap())
+            .send_async()
+            .await
+            .expect("failed to get track asynchronously")
+    });
+
+    assert_eq!(sync_track.id().as_str(), TRACK_ID);
+    assert_eq!(sync_track, async_track);
+
+    let requests = requests.lock().unwrap();
+    for request in [&requests[0], &requests[2]] {
+        assert!(
+            request_line(request).starts_with("POST /accounts/api/token "),
+            "{request}"
+        );
+    }
+    for request in [&requests[1], &requests[3]] {
+        assert!(
+            request_line(request).starts_with(&format!("GET /v1/tracks/{TRACK_ID}")),
+            "{request}"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! A local HTTP server serving canned responses, shared by the integration tests and the library's unit tests. The
+//! library includes this module in its tests with a `#[path]` attribute.
+
+#![allow(dead_code)]
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// The heads (the request line and the headers) of the requests a server has received, in the order they were
+/// received.
+pub type ReceivedRequests = Arc<Mutex<Vec<String>>>;
+
+/// Serves the raw HTTP responses built from the server's URL to consecutive connections. The URL ends with a slash.
+/// Returns the URL and the requests the server receives.
+pub fn serve_responses<F>(responses: F) -> (String, ReceivedRequests)
+where
+    F: FnOnce(&str) -> Vec<String>,
+{
+    let (listener, url) = bind();
+    let mut responses = responses(&url).into_iter();
+    let requests = serve(listener, responses.len(), move |_| responses.next().unwrap());
+
+    (url, requests)
+}
+
+/// Serves the raw HTTP responses the given function builds from the heads of the received requests to the given number
+/// of connections. This allows serving requests that may arrive in any order. See [serve_responses].
+pub fn serve_responses_by_request<F>(connections: usize, respond: F) -> (String, ReceivedRequests)
+where
+    F: FnMut(&str) -> String + Send + 'static,
+{
+    let (listener, url) = bind();
+    let requests = serve(listener, connections, respond);
+
+    (url, requests)
+}
+
+fn bind() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    (listener, url)
+}
+
+fn serve<F>(listener: TcpListener, connections: usize, mut respond: F) -> ReceivedRequests
+where
+    F: FnMut(&str) -> String + Send + 'static,
+{
+    let requests = ReceivedRequests::default();
+    let received = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for _ in 0..connections {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            // some requests have a body but none of them need to be read
+            let mut head = String::new();
+            while reader.read_line(&mut head).unwrap() > 0 && !head.ends_with("\r\n\r\n") {}
+
+            // record the request before responding so it's visible once the client has the response
+            let response = respond(&head);
+            received.lock().unwrap().push(head);
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    requests
+}
+
+/// Serves the given JSON bodies in successful responses to consecutive connections. See [serve_responses].
+pub fn serve_json_responses(bodies: &[&str]) -> (String, ReceivedRequests) {
+    serve_responses(|_| bodies.iter().map(|body| json_response(body)).collect())
+}
+
+/// Builds a raw successful HTTP response with the given JSON body.
+pub fn json_response(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Returns the request line of a received request's head.
+pub fn request_line(head: &str) -> &str {
+    head.lines().next().unwrap_or_default()
+}